@@ -0,0 +1,80 @@
+use backend_comparison::persistence::save;
+use burn::tensor::{backend::Backend, Int, Tensor};
+use burn_common::{
+    benchmark::{run_benchmark, Benchmark},
+    sync_type::SyncType,
+};
+use derive_new::new;
+
+#[derive(new)]
+struct IntStackBenchmark<B: Backend> {
+    num_tensors: usize,
+    size: usize,
+    // Whether to go through unsqueeze + cat instead of the backend stack operation.
+    with_cat: bool,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for IntStackBenchmark<B> {
+    type Args = Vec<Tensor<B, 1, Int>>;
+
+    fn name(&self) -> String {
+        match self.with_cat {
+            true => "int-stack-cat".into(),
+            false => "int-stack".into(),
+        }
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![vec![self.num_tensors, self.size]]
+    }
+
+    fn execute(&self, args: Self::Args) {
+        if self.with_cat {
+            let tensors = args.into_iter().map(|t| t.unsqueeze_dim(0)).collect();
+            Tensor::<B, 2, Int>::cat(tensors, 0);
+        } else {
+            Tensor::<B, 1, Int>::stack::<2>(args, 0);
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {
+        (0..self.num_tensors)
+            .map(|i| {
+                let start = (i * self.size) as i64;
+                Tensor::arange(start..start + self.size as i64, &self.device)
+            })
+            .collect()
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(
+    device: &B::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    let num_tensors = 10_000;
+    let size = 16;
+
+    let stack = IntStackBenchmark::<B>::new(num_tensors, size, false, device.clone());
+    let stack_with_cat = IntStackBenchmark::<B>::new(num_tensors, size, true, device.clone());
+
+    save::<B>(
+        vec![run_benchmark(stack), run_benchmark(stack_with_cat)],
+        device,
+        feature_name,
+        url,
+        token,
+    )
+    .unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -106,6 +106,8 @@ enum BenchmarkValues {
     ConvTranspose2d,
     #[strum(to_string = "conv2d")]
     Conv2d,
+    #[strum(to_string = "int-stack")]
+    IntStack,
 }
 
 pub fn execute() {
@@ -3,7 +3,7 @@ use crate::{checkpoint::strategy::CheckpointStrategy, tensor::AutodiffTensor, Au
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, IntTensor, IntTensorOps},
-    Device, Distribution, Reader, Shape, TensorData,
+    Device, IntDistribution, Reader, Shape, TensorData,
 };
 
 impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
@@ -67,6 +67,13 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_cat(tensors, dim)
     }
 
+    fn int_stack<const D: usize, const D2: usize>(
+        tensors: Vec<IntTensor<B, D>>,
+        dim: usize,
+    ) -> IntTensor<B, D2> {
+        B::int_stack(tensors, dim)
+    }
+
     fn int_equal<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> BoolTensor<B, D> {
         B::int_equal(lhs, rhs)
     }
@@ -123,6 +130,13 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_remainder(lhs, rhs)
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<B, D>,
         rhs: B::IntElem,
@@ -130,6 +144,113 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_remainder_scalar(lhs, rhs)
     }
 
+    fn int_add_inplace<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_add_inplace(lhs, rhs)
+    }
+
+    fn int_sub_inplace<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_sub_inplace(lhs, rhs)
+    }
+
+    fn int_mul_inplace<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_mul_inplace(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_saturating_add(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_saturating_add_scalar(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_saturating_sub(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_saturating_sub_scalar(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_saturating_mul(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_saturating_mul_scalar(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_add<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+        B::int_checked_add(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_sub<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+        B::int_checked_sub(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_mul<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+        B::int_checked_mul(lhs, rhs)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_pow<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+        overflow: burn_tensor::ops::OverflowMode,
+    ) -> IntTensor<B, D> {
+        B::int_pow(lhs, rhs, overflow)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_isqrt<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_isqrt(tensor)
+    }
+
     fn int_neg<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
         B::int_neg(tensor)
     }
@@ -340,7 +461,7 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
 
     fn int_random<const D: usize>(
         shape: Shape<D>,
-        distribution: Distribution,
+        distribution: IntDistribution,
         device: &Device<Self>,
     ) -> IntTensor<Self, D> {
         B::int_random(shape, distribution, device)
@@ -406,4 +527,78 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
     ) -> IntTensor<Self, D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_and_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_or_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_xor_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_bitwise_not(tensor)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_left_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_left_shift_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_right_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_right_shift_scalar(lhs, rhs)
+    }
 }
@@ -162,4 +162,43 @@ mod tests {
     burn_autodiff::testgen_ad_tanh!();
     burn_autodiff::testgen_ad_transpose!();
     burn_autodiff::testgen_ad_expand!();
+
+    // Narrow int element
+    mod narrow_int {
+        use super::*;
+        use burn_tensor::{Int, Tensor, TensorData};
+
+        type NarrowIntTensor<const D: usize> = Tensor<Candle<f32, u8>, D, Int>;
+
+        #[test]
+        fn argmax_usize_should_index_past_the_elem_range() {
+            // The dimension is longer than what `u8` can index.
+            let mut values = (0..300).map(|i| (i % 100) as u8).collect::<Vec<_>>();
+            values[257] = u8::MAX;
+            let tensor = NarrowIntTensor::<1>::from_data(
+                TensorData::new(values, [300]),
+                &Default::default(),
+            );
+
+            tensor
+                .argmax_usize(0)
+                .into_data()
+                .assert_eq(&TensorData::from([257u64]), true);
+        }
+
+        #[test]
+        fn argmin_usize_should_index_past_the_elem_range() {
+            let mut values = (0..300).map(|i| (i % 100) as u8 + 1).collect::<Vec<_>>();
+            values[281] = 0;
+            let tensor = NarrowIntTensor::<2>::from_data(
+                TensorData::new(values, [1, 300]),
+                &Default::default(),
+            );
+
+            tensor
+                .argmin_usize(1)
+                .into_data()
+                .assert_eq(&TensorData::from([[281u64]]), true);
+        }
+    }
 }
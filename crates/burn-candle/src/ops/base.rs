@@ -64,7 +64,7 @@ pub fn flip<E: CandleElement, const D: usize>(
     axes: &[usize],
 ) -> CandleTensor<E, D> {
     // FIXME: Replace with an appropriate method when Candle provides one.
-    let mut tensor = tensor.tensor;
+    let mut tensor = tensor.tensor.contiguous().unwrap();
     for &axis in axes {
         let indexes = candle_core::Tensor::arange_step(
             tensor.dim(axis).unwrap() as i64 - 1,
@@ -1,6 +1,6 @@
 use burn_tensor::{
     ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
-    Bool, Device, Distribution, ElementConversion, Reader, Shape, TensorData,
+    Bool, Device, ElementConversion, IntDistribution, Reader, Shape, TensorData,
 };
 
 use crate::{
@@ -99,7 +99,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         tensor: IntTensor<Self, D>,
         indices: IntTensor<Self, D>,
     ) -> IntTensor<Self, D> {
-        CandleTensor::new(tensor.tensor.gather(&indices.tensor, dim).unwrap())
+        let tensor = tensor.tensor.contiguous().unwrap();
+        let indices = indices.tensor.contiguous().unwrap();
+        CandleTensor::new(tensor.gather(&indices, dim).unwrap())
     }
 
     fn int_scatter<const D: usize>(
@@ -289,12 +291,49 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         panic!("Not supported by Candle")
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        // The division of two int tensors truncates, unlike the scalar division, so the truncated
+        // remainder is composed from it before being floored.
+        let shape = lhs
+            .tensor
+            .shape()
+            .broadcast_shape_binary_op(rhs.tensor.shape(), "remainder")
+            .unwrap();
+        let lhs = lhs.tensor.broadcast_as(&shape).unwrap();
+        let rhs = rhs.tensor.broadcast_as(&shape).unwrap();
+
+        let quotient = lhs.div(&rhs).unwrap();
+        let remainder = lhs.sub(&quotient.mul(&rhs).unwrap()).unwrap();
+
+        // Only the remainders with a different sign than the divisor are moved by it, which
+        // can't overflow.
+        let different_sign = remainder
+            .lt(0i64)
+            .unwrap()
+            .ne(&rhs.lt(0i64).unwrap())
+            .unwrap();
+        let shift = remainder
+            .ne(0i64)
+            .unwrap()
+            .mul(&different_sign)
+            .unwrap()
+            .to_dtype(I::DTYPE)
+            .unwrap()
+            .mul(&rhs)
+            .unwrap();
+
+        CandleTensor::new(remainder.add(&shift).unwrap())
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntElem<Self>,
     ) -> IntTensor<Self, D> {
-        // Same problem as int_div_scalar.
-        panic!("Not supported by Candle")
+        let rhs = super::candle_utils::fill_like::<I, D>(rhs, &lhs.tensor);
+        Self::int_remainder(lhs, CandleTensor::new(rhs))
     }
 
     fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
@@ -397,35 +436,28 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
 
     fn int_random<const D: usize>(
         shape: Shape<D>,
-        distribution: Distribution,
+        distribution: IntDistribution,
         device: &Device<Self>,
     ) -> IntTensor<Self, D> {
         let shape = &shape.dims;
         let device = &(*device).into();
         match distribution {
-            Distribution::Default => CandleTensor::new(
-                candle_core::Tensor::rand(0.elem::<F>(), 255.elem::<F>(), shape, device)
+            IntDistribution::Uniform { low, high } => CandleTensor::new(
+                candle_core::Tensor::rand(low.elem::<F>(), high.elem::<F>(), shape, device)
+                    .unwrap()
+                    .floor()
                     .unwrap()
                     .to_dtype(I::DTYPE)
                     .unwrap(),
             ),
-            Distribution::Bernoulli(prob) => CandleTensor::new(
+            IntDistribution::Bernoulli { p } => CandleTensor::new(
                 candle_core::Tensor::rand(0.elem::<F>(), 1.elem::<F>(), shape, device)
                     .unwrap()
-                    .to_dtype(I::DTYPE)
-                    .unwrap()
-                    .lt(&super::candle_utils::fill(prob, shape, I::DTYPE, device))
+                    .lt(&super::candle_utils::fill(p, shape, F::DTYPE, device))
                     .unwrap()
                     .to_dtype(I::DTYPE)
                     .unwrap(),
             ),
-            Distribution::Uniform(from, to) => CandleTensor::new(
-                candle_core::Tensor::rand(from.elem::<F>(), to.elem::<F>(), shape, device).unwrap(),
-            ),
-            Distribution::Normal(mean, std) => CandleTensor::new(
-                candle_core::Tensor::randn(mean.elem::<F>(), std.elem::<F>(), shape, device)
-                    .unwrap(),
-            ),
         }
     }
 
@@ -450,4 +482,79 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
     fn int_sign<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
         sign(tensor)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        // Candle doesn't provide bitwise operations on tensors.
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        panic!("Not supported by Candle")
+    }
 }
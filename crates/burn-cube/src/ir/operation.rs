@@ -60,7 +60,9 @@ pub enum Operator {
     Max(BinaryOperator),
     Min(BinaryOperator),
     BitwiseAnd(BinaryOperator),
+    BitwiseOr(BinaryOperator),
     BitwiseXor(BinaryOperator),
+    BitwiseNot(UnaryOperator),
     ShiftLeft(BinaryOperator),
     ShiftRight(BinaryOperator),
     Remainder(BinaryOperator),
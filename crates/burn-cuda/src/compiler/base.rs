@@ -282,7 +282,9 @@ impl CudaCompiler {
             gpu::Operator::Min(op) => Instruction::Min(self.compile_binary(op)),
             gpu::Operator::NotEqual(op) => Instruction::NotEqual(self.compile_binary(op)),
             gpu::Operator::BitwiseAnd(op) => Instruction::BitwiseAnd(self.compile_binary(op)),
+            gpu::Operator::BitwiseOr(op) => Instruction::BitwiseOr(self.compile_binary(op)),
             gpu::Operator::BitwiseXor(op) => Instruction::BitwiseXor(self.compile_binary(op)),
+            gpu::Operator::BitwiseNot(op) => Instruction::BitwiseNot(self.compile_unary(op)),
             gpu::Operator::ShiftLeft(op) => Instruction::ShiftLeft(self.compile_binary(op)),
             gpu::Operator::ShiftRight(op) => Instruction::ShiftRight(self.compile_binary(op)),
             gpu::Operator::Clamp(op) => Instruction::Clamp {
@@ -301,7 +303,7 @@ impl CudaCompiler {
             }),
             gpu::Operator::Floor(op) => Instruction::Floor(self.compile_unary(op)),
             gpu::Operator::Ceil(op) => Instruction::Ceil(self.compile_unary(op)),
-            gpu::Operator::Remainder(_op) => todo!(),
+            gpu::Operator::Remainder(op) => Instruction::Remainder(self.compile_binary(op)),
         }
     }
 
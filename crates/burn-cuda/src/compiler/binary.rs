@@ -244,6 +244,7 @@ operator!(GreaterEqual, ">=");
 operator!(ShiftLeft, "<<");
 operator!(ShiftRight, ">>");
 operator!(BitwiseAnd, "&");
+operator!(BitwiseOr, "|");
 operator!(BitwiseXor, "^");
 operator!(Or, "||");
 operator!(And, "&&");
@@ -254,6 +255,35 @@ function!(Min, "min");
 
 pub struct IndexAssign;
 pub struct Index;
+pub struct Remainder;
+
+impl Binary for Remainder {
+    fn format_scalar<Lhs: Display, Rhs: Display, Out: Display>(
+        f: &mut std::fmt::Formatter<'_>,
+        lhs: Lhs,
+        rhs: Rhs,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result {
+        let rem = match elem {
+            Elem::F32 | Elem::F16 | Elem::BF16 => {
+                format!("{elem}(fmod(float({lhs}), float({rhs})))")
+            }
+            _ => format!("{lhs} % {rhs}"),
+        };
+
+        // The truncated remainder only moves by the divisor when its sign differs, so the
+        // floored remainder can't overflow.
+        f.write_fmt(format_args!(
+            "
+{{
+    {elem} rem = {rem};
+    {out} = (rem != {elem}(0) && ((rem < {elem}(0)) != ({rhs} < {elem}(0)))) ? {elem}(rem + {rhs}) : rem;
+}}
+"
+        ))
+    }
+}
 
 impl Binary for IndexAssign {
     fn format_scalar<Lhs, Rhs, Out>(
@@ -26,6 +26,7 @@ pub enum Instruction {
         var: Variable,
     },
     Modulo(BinaryInstruction),
+    Remainder(BinaryInstruction),
     Add(BinaryInstruction),
     Div(BinaryInstruction),
     Mul(BinaryInstruction),
@@ -72,7 +73,9 @@ pub enum Instruction {
     GreaterEqual(BinaryInstruction),
     Erf(UnaryInstruction),
     BitwiseAnd(BinaryInstruction),
+    BitwiseOr(BinaryInstruction),
     BitwiseXor(BinaryInstruction),
+    BitwiseNot(UnaryInstruction),
     ShiftLeft(BinaryInstruction),
     ShiftRight(BinaryInstruction),
     Abs(UnaryInstruction),
@@ -115,8 +118,11 @@ impl Display for Instruction {
             Instruction::Div(it) => Div::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Sub(it) => Sub::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Modulo(inst) => Modulo::format(f, &inst.lhs, &inst.rhs, &inst.out),
+            Instruction::Remainder(inst) => Remainder::format(f, &inst.lhs, &inst.rhs, &inst.out),
             Instruction::BitwiseAnd(it) => BitwiseAnd::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::BitwiseOr(it) => BitwiseOr::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::BitwiseXor(it) => BitwiseXor::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::BitwiseNot(it) => BitwiseNot::format(f, &it.input, &it.out),
             Instruction::ShiftLeft(it) => ShiftLeft::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::ShiftRight(it) => ShiftRight::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Index(it) => Index::format(f, &it.lhs, &it.rhs, &it.out),
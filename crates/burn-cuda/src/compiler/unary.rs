@@ -187,6 +187,23 @@ impl Unary for Not {
     }
 }
 
+pub struct BitwiseNot;
+
+impl Unary for BitwiseNot {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        _elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        f.write_fmt(format_args!("{out} = ~{input};\n"))
+    }
+}
+
 pub struct Assign;
 
 impl Unary for Assign {
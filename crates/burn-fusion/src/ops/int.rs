@@ -10,7 +10,7 @@ use crate::{
 use burn_tensor::{
     ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
     repr::{self, *},
-    DType, Device, Distribution, Element, ElementConversion, Reader, Shape, TensorData,
+    DType, Device, Element, ElementConversion, IntDistribution, Reader, Shape, TensorData,
 };
 use core::ops::Range;
 use std::marker::PhantomData;
@@ -84,6 +84,10 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         tensor: IntTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> IntTensor<Self, D2> {
+        if tensor.shape == shape.dims {
+            return tensor;
+        }
+
         #[derive(new)]
         struct ReshapeDimsOps<B: FusionBackend, const D1: usize, const D2: usize> {
             desc: ReshapeDescription,
@@ -1015,6 +1019,33 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(RemainderOps, B::int_remainder);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::Remainder(desc.clone())),
+            RemainderOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntElem<Self>,
@@ -1042,228 +1073,594 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
-    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
-        #[derive(new)]
-        struct ZerosOps<B: FusionBackend, const D: usize> {
-            desc: TensorDescription,
-            device: Device<B>,
-        }
-
-        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for ZerosOps<B, D> {
-            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
-                let shape = Shape::from(self.desc.shape.clone());
-                let output = B::int_zeros::<D>(shape, &self.device);
-                handles.register_int_tensor::<B, D>(&self.desc.id, output);
-            }
-        }
-
-        let stream = StreamId::current();
-        let shape: Vec<usize> = shape.dims.into();
-        let client = get_client::<B>(&device.clone());
-        let out = client.tensor_uninitialized(shape, B::IntElem::dtype());
-        let desc = out.to_description_out();
-        client.register(
-            vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::Zeros(desc.clone())),
-            ZerosOps::<B, D>::new(desc, device.clone()),
-        );
-
-        out
-    }
-
-    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
-        #[derive(new)]
-        struct OnesOps<B: FusionBackend, const D: usize> {
-            desc: TensorDescription,
-            device: Device<B>,
-        }
-
-        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for OnesOps<B, D> {
-            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
-                let shape = Shape::from(self.desc.shape.clone());
-                let output = B::int_ones::<D>(shape, &self.device);
-                handles.register_int_tensor::<B, D>(&self.desc.id, output);
-            }
-        }
-
-        let stream = StreamId::current();
-        let shape: Vec<usize> = shape.dims.into();
-        let client = get_client::<B>(&device.clone());
-        let out = client.tensor_uninitialized(shape, B::IntElem::dtype());
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(SaturatingAddOps, B::int_saturating_add);
 
-        let desc = out.to_description_out();
-        client.register(
-            vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::Ones(desc.clone())),
-            OnesOps::<B, D>::new(desc, device.clone()),
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
         );
 
-        out
-    }
-
-    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
-        unary_int_ops!(SumOps, B::int_sum, reduce);
-
-        let stream = tensor.stream;
-        let out = tensor
-            .client
-            .tensor_uninitialized(vec![1], B::IntElem::dtype());
-
-        let desc = UnaryOperationDescription {
-            input: tensor.into_description(),
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
             out: out.to_description_out(),
         };
         out.client.register(
-            vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::Sum(desc.clone())),
-            SumOps::<B, D>::new(desc),
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::SaturatingAdd(desc.clone())),
+            SaturatingAddOps::<B, D>::new(desc),
         );
 
         out
     }
 
-    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
-        scalar_int_ops!(SumDimOps, B::int_sum_dim, usize, noconvert);
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(SaturatingAddScalarOps, B::int_saturating_add_scalar);
 
-        let stream = tensor.stream;
-        let mut shape = tensor.shape.clone();
-        shape[dim] = 1;
-        let out = tensor
+        let stream = lhs.stream;
+        let out = lhs
             .client
-            .tensor_uninitialized(shape, B::IntElem::dtype());
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
 
         let desc = ScalarOperationDescription {
-            lhs: tensor.into_description(),
-            rhs: dim,
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
             out: out.to_description_out(),
         };
         out.client.register(
             vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::SumDim(desc.clone())),
-            SumDimOps::<B, D>::new(desc),
+            OperationDescription::Int(repr::IntOperationDescription::SaturatingAddScalar(
+                desc.clone(),
+            )),
+            SaturatingAddScalarOps::<B, D>::new(desc),
         );
 
         out
     }
 
-    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
-        unary_int_ops!(ProdOps, B::int_prod, reduce);
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(SaturatingSubOps, B::int_saturating_sub);
 
-        let stream = tensor.stream;
-        let out = tensor
-            .client
-            .tensor_uninitialized(vec![1], B::IntElem::dtype());
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
 
-        let desc = UnaryOperationDescription {
-            input: tensor.into_description(),
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
             out: out.to_description_out(),
         };
         out.client.register(
-            vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::Prod(desc.clone())),
-            ProdOps::<B, D>::new(desc),
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::SaturatingSub(desc.clone())),
+            SaturatingSubOps::<B, D>::new(desc),
         );
 
         out
     }
 
-    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
-        scalar_int_ops!(ProdDimOps, B::int_prod_dim, usize, noconvert);
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(SaturatingSubScalarOps, B::int_saturating_sub_scalar);
 
-        let stream = tensor.stream;
-        let mut shape = tensor.shape.clone();
-        shape[dim] = 1;
-        let out = tensor
+        let stream = lhs.stream;
+        let out = lhs
             .client
-            .tensor_uninitialized(shape, B::IntElem::dtype());
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
 
         let desc = ScalarOperationDescription {
-            lhs: tensor.into_description(),
-            rhs: dim,
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
             out: out.to_description_out(),
         };
         out.client.register(
             vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::ProdDim(desc.clone())),
-            ProdDimOps::<B, D>::new(desc),
+            OperationDescription::Int(repr::IntOperationDescription::SaturatingSubScalar(
+                desc.clone(),
+            )),
+            SaturatingSubScalarOps::<B, D>::new(desc),
         );
 
         out
     }
 
-    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
-        unary_int_ops!(MeanOps, B::int_mean, reduce);
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(SaturatingMulOps, B::int_saturating_mul);
 
-        let stream = tensor.stream;
-        let out = tensor
-            .client
-            .tensor_uninitialized(vec![1], B::IntElem::dtype());
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
 
-        let desc = UnaryOperationDescription {
-            input: tensor.into_description(),
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
             out: out.to_description_out(),
         };
         out.client.register(
-            vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::Mean(desc.clone())),
-            MeanOps::<B, D>::new(desc),
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::SaturatingMul(desc.clone())),
+            SaturatingMulOps::<B, D>::new(desc),
         );
 
         out
     }
 
-    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
-        scalar_int_ops!(MeanDimOps, B::int_mean_dim, usize, noconvert);
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(SaturatingMulScalarOps, B::int_saturating_mul_scalar);
 
-        let stream = tensor.stream;
-        let mut shape = tensor.shape.clone();
-        shape[dim] = 1;
-        let out = tensor
+        let stream = lhs.stream;
+        let out = lhs
             .client
-            .tensor_uninitialized(shape, B::IntElem::dtype());
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
 
         let desc = ScalarOperationDescription {
-            lhs: tensor.into_description(),
-            rhs: dim,
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
             out: out.to_description_out(),
         };
         out.client.register(
             vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::MeanDim(desc.clone())),
-            MeanDimOps::<B, D>::new(desc),
+            OperationDescription::Int(repr::IntOperationDescription::SaturatingMulScalar(
+                desc.clone(),
+            )),
+            SaturatingMulScalarOps::<B, D>::new(desc),
         );
 
         out
     }
 
-    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
-        scalar_int_ops!(ArgMaxOps, B::int_argmax, usize, noconvert);
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> (IntTensor<Self, D>, BoolTensor<Self, D>) {
+        #[derive(new)]
+        struct CheckedAddOps<B: FusionBackend, const D: usize> {
+            desc: CheckedOperationDescription,
+            _b: PhantomData<B>,
+        }
 
-        let stream = tensor.stream;
-        let mut shape = tensor.shape.clone();
-        shape[dim] = 1;
-        let out = tensor
-            .client
-            .tensor_uninitialized(shape, B::IntElem::dtype());
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for CheckedAddOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_int_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_int_tensor::<B, D>(&self.desc.rhs);
+                let (output, overflow) = B::int_checked_add(lhs, rhs);
 
-        let desc = ScalarOperationDescription {
-            lhs: tensor.into_description(),
-            rhs: dim,
+                handles.register_int_tensor::<B, D>(&self.desc.out.id, output);
+                handles.register_bool_tensor::<B, D>(&self.desc.out_overflow.id, overflow);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+        let client = lhs.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), B::IntElem::dtype());
+        let out_overflow = client.tensor_uninitialized(shape, DType::Bool);
+
+        let desc = CheckedOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
             out: out.to_description_out(),
+            out_overflow: out_overflow.to_description_out(),
         };
-        out.client.register(
-            vec![stream],
-            OperationDescription::NumericInt(NumericOperationDescription::ArgMax(desc.clone())),
-            ArgMaxOps::<B, D>::new(desc),
+        client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::CheckedAdd(desc.clone())),
+            CheckedAddOps::<B, D>::new(desc),
         );
 
-        out
+        (out, out_overflow)
     }
 
-    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
-        scalar_int_ops!(ArgMinOps, B::int_argmin, usize, noconvert);
-
-        let stream = tensor.stream;
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> (IntTensor<Self, D>, BoolTensor<Self, D>) {
+        #[derive(new)]
+        struct CheckedSubOps<B: FusionBackend, const D: usize> {
+            desc: CheckedOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for CheckedSubOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_int_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_int_tensor::<B, D>(&self.desc.rhs);
+                let (output, overflow) = B::int_checked_sub(lhs, rhs);
+
+                handles.register_int_tensor::<B, D>(&self.desc.out.id, output);
+                handles.register_bool_tensor::<B, D>(&self.desc.out_overflow.id, overflow);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+        let client = lhs.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), B::IntElem::dtype());
+        let out_overflow = client.tensor_uninitialized(shape, DType::Bool);
+
+        let desc = CheckedOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+            out_overflow: out_overflow.to_description_out(),
+        };
+        client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::CheckedSub(desc.clone())),
+            CheckedSubOps::<B, D>::new(desc),
+        );
+
+        (out, out_overflow)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> (IntTensor<Self, D>, BoolTensor<Self, D>) {
+        #[derive(new)]
+        struct CheckedMulOps<B: FusionBackend, const D: usize> {
+            desc: CheckedOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for CheckedMulOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_int_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_int_tensor::<B, D>(&self.desc.rhs);
+                let (output, overflow) = B::int_checked_mul(lhs, rhs);
+
+                handles.register_int_tensor::<B, D>(&self.desc.out.id, output);
+                handles.register_bool_tensor::<B, D>(&self.desc.out_overflow.id, overflow);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let shape = binary_ops_shape(&lhs.shape, &rhs.shape);
+        let client = lhs.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), B::IntElem::dtype());
+        let out_overflow = client.tensor_uninitialized(shape, DType::Bool);
+
+        let desc = CheckedOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+            out_overflow: out_overflow.to_description_out(),
+        };
+        client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::CheckedMul(desc.clone())),
+            CheckedMulOps::<B, D>::new(desc),
+        );
+
+        (out, out_overflow)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_pow<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+        overflow: burn_tensor::ops::OverflowMode,
+    ) -> IntTensor<Self, D> {
+        #[derive(new)]
+        struct PowOps<B: FusionBackend, const D: usize> {
+            desc: IntPowDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for PowOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_int_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_int_tensor::<B, D>(&self.desc.rhs);
+                let output = B::int_pow(lhs, rhs, self.desc.overflow.clone().into());
+
+                handles.register_int_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = IntPowDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            overflow: overflow.into(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::Pow(desc.clone())),
+            PowOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_isqrt<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary_int_ops!(IsqrtOps, B::int_isqrt);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::IntElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::Isqrt(desc.clone())),
+            IsqrtOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        #[derive(new)]
+        struct ZerosOps<B: FusionBackend, const D: usize> {
+            desc: TensorDescription,
+            device: Device<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for ZerosOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let shape = Shape::from(self.desc.shape.clone());
+                let output = B::int_zeros::<D>(shape, &self.device);
+                handles.register_int_tensor::<B, D>(&self.desc.id, output);
+            }
+        }
+
+        let stream = StreamId::current();
+        let shape: Vec<usize> = shape.dims.into();
+        let client = get_client::<B>(&device.clone());
+        let out = client.tensor_uninitialized(shape, B::IntElem::dtype());
+        let desc = out.to_description_out();
+        client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::Zeros(desc.clone())),
+            ZerosOps::<B, D>::new(desc, device.clone()),
+        );
+
+        out
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        #[derive(new)]
+        struct OnesOps<B: FusionBackend, const D: usize> {
+            desc: TensorDescription,
+            device: Device<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for OnesOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let shape = Shape::from(self.desc.shape.clone());
+                let output = B::int_ones::<D>(shape, &self.device);
+                handles.register_int_tensor::<B, D>(&self.desc.id, output);
+            }
+        }
+
+        let stream = StreamId::current();
+        let shape: Vec<usize> = shape.dims.into();
+        let client = get_client::<B>(&device.clone());
+        let out = client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = out.to_description_out();
+        client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::Ones(desc.clone())),
+            OnesOps::<B, D>::new(desc, device.clone()),
+        );
+
+        out
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        unary_int_ops!(SumOps, B::int_sum, reduce);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(vec![1], B::IntElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::Sum(desc.clone())),
+            SumOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(SumDimOps, B::int_sum_dim, usize, noconvert);
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::SumDim(desc.clone())),
+            SumDimOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        unary_int_ops!(ProdOps, B::int_prod, reduce);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(vec![1], B::IntElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::Prod(desc.clone())),
+            ProdOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(ProdDimOps, B::int_prod_dim, usize, noconvert);
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::ProdDim(desc.clone())),
+            ProdDimOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        unary_int_ops!(MeanOps, B::int_mean, reduce);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(vec![1], B::IntElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::Mean(desc.clone())),
+            MeanOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(MeanDimOps, B::int_mean_dim, usize, noconvert);
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::MeanDim(desc.clone())),
+            MeanDimOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(ArgMaxOps, B::int_argmax, usize, noconvert);
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::ArgMax(desc.clone())),
+            ArgMaxOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        scalar_int_ops!(ArgMinOps, B::int_argmin, usize, noconvert);
+
+        let stream = tensor.stream;
         let mut shape = tensor.shape.clone();
         shape[dim] = 1;
         let out = tensor
@@ -1597,12 +1994,12 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
     fn int_random<const D: usize>(
         shape: Shape<D>,
-        distribution: Distribution,
+        distribution: IntDistribution,
         device: &Device<Self>,
     ) -> IntTensor<Self, D> {
         #[derive(new)]
         struct IntRandomOps<B: FusionBackend, const D: usize> {
-            desc: RandomOperationDescription,
+            desc: IntRandomOperationDescription,
             device: Device<B>,
         }
 
@@ -1620,7 +2017,7 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         let client = get_client::<B>(&device.clone());
         let out = client.tensor_uninitialized(shape, B::IntElem::dtype());
 
-        let desc = RandomOperationDescription {
+        let desc = IntRandomOperationDescription {
             out: out.to_description_out(),
             distribution,
         };
@@ -1797,4 +2194,300 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         out
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseAndOps, B::int_bitwise_and);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseAnd(desc.clone())),
+            BitwiseAndOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseOrOps, B::int_bitwise_or);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseOr(desc.clone())),
+            BitwiseOrOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseXorOps, B::int_bitwise_xor);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseXor(desc.clone())),
+            BitwiseXorOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseAndScalarOps, B::int_bitwise_and_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseAndScalar(
+                desc.clone(),
+            )),
+            BitwiseAndScalarOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseOrScalarOps, B::int_bitwise_or_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseOrScalar(desc.clone())),
+            BitwiseOrScalarOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseXorScalarOps, B::int_bitwise_xor_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseXorScalar(
+                desc.clone(),
+            )),
+            BitwiseXorScalarOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary_int_ops!(BitwiseNotOps, B::int_bitwise_not);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::IntElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseNot(desc.clone())),
+            BitwiseNotOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseLeftShiftOps, B::int_bitwise_left_shift);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseLeftShift(
+                desc.clone(),
+            )),
+            BitwiseLeftShiftOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseLeftShiftScalarOps, B::int_bitwise_left_shift_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseLeftShiftScalar(
+                desc.clone(),
+            )),
+            BitwiseLeftShiftScalarOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseRightShiftOps, B::int_bitwise_right_shift);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseRightShift(
+                desc.clone(),
+            )),
+            BitwiseRightShiftOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(
+            BitwiseRightShiftScalarOps,
+            B::int_bitwise_right_shift_scalar
+        );
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseRightShiftScalar(
+                desc.clone(),
+            )),
+            BitwiseRightShiftScalarOps::<B, D>::new(desc),
+        );
+
+        out
+    }
 }
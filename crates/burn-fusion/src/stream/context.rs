@@ -445,6 +445,167 @@ impl RelativeOps for IntOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            IntOperationDescription::BitwiseAnd(desc) => {
+                IntOperationDescription::BitwiseAnd(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseOr(desc) => {
+                IntOperationDescription::BitwiseOr(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseXor(desc) => {
+                IntOperationDescription::BitwiseXor(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseAndScalar(desc) => {
+                IntOperationDescription::BitwiseAndScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseOrScalar(desc) => {
+                IntOperationDescription::BitwiseOrScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseXorScalar(desc) => {
+                IntOperationDescription::BitwiseXorScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseNot(desc) => {
+                IntOperationDescription::BitwiseNot(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseLeftShift(desc) => {
+                IntOperationDescription::BitwiseLeftShift(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseLeftShiftScalar(desc) => {
+                IntOperationDescription::BitwiseLeftShiftScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseRightShift(desc) => {
+                IntOperationDescription::BitwiseRightShift(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseRightShiftScalar(desc) => {
+                IntOperationDescription::BitwiseRightShiftScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::Remainder(desc) => {
+                IntOperationDescription::Remainder(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::SaturatingAdd(desc) => {
+                IntOperationDescription::SaturatingAdd(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::SaturatingAddScalar(desc) => {
+                IntOperationDescription::SaturatingAddScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::SaturatingSub(desc) => {
+                IntOperationDescription::SaturatingSub(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::SaturatingSubScalar(desc) => {
+                IntOperationDescription::SaturatingSubScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::SaturatingMul(desc) => {
+                IntOperationDescription::SaturatingMul(BinaryOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::SaturatingMulScalar(desc) => {
+                IntOperationDescription::SaturatingMulScalar(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: converter.relative_int(&desc.rhs),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::CheckedAdd(desc) => {
+                IntOperationDescription::CheckedAdd(CheckedOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                    out_overflow: desc.out_overflow.to_relative(converter),
+                })
+            }
+            IntOperationDescription::CheckedSub(desc) => {
+                IntOperationDescription::CheckedSub(CheckedOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                    out_overflow: desc.out_overflow.to_relative(converter),
+                })
+            }
+            IntOperationDescription::CheckedMul(desc) => {
+                IntOperationDescription::CheckedMul(CheckedOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                    out_overflow: desc.out_overflow.to_relative(converter),
+                })
+            }
+            IntOperationDescription::Pow(desc) => IntOperationDescription::Pow(IntPowDescription {
+                lhs: desc.lhs.to_relative(converter),
+                rhs: desc.rhs.to_relative(converter),
+                overflow: desc.overflow.clone(),
+                out: desc.out.to_relative(converter),
+            }),
+            IntOperationDescription::Isqrt(desc) => {
+                IntOperationDescription::Isqrt(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -751,7 +912,7 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationDescription<E> {
                 })
             }
             NumericOperationDescription::IntRandom(desc) => {
-                NumericOperationDescription::IntRandom(RandomOperationDescription {
+                NumericOperationDescription::IntRandom(IntRandomOperationDescription {
                     out: desc.out.to_relative(converter),
                     distribution: desc.distribution,
                 })
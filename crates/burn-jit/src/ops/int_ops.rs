@@ -1,10 +1,18 @@
 use super::{expand, numeric, permute};
-use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
-use crate::{kernel, unary, FloatElement, IntElement, JitBackend, JitRuntime};
-use burn_cube::ir::{Elem, Item, Operator, Scope, UnaryOperator, Variable};
+use crate::kernel::prng::{random_bernoulli, random_uniform};
+use crate::{
+    binary, kernel, tensor::JitTensor, unary, FloatElement, IntElement, JitBackend, JitRuntime,
+};
+use burn_cube::cpa;
+use burn_cube::ir::{
+    BinaryOperator, Elem, IntKind, Item, Operator, Scope, UnaryOperator, Variable,
+};
 use burn_cube::Runtime;
-use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
-use burn_tensor::{ops::IntTensorOps, Distribution, ElementConversion, Reader, Shape, TensorData};
+use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor, OverflowMode};
+use burn_tensor::{
+    ops::IntTensorOps, ElementConversion, IntDistribution, Reader, Shape, TensorData,
+};
+use std::marker::PhantomData;
 use std::ops::Range;
 
 impl<R, F, I> IntTensorOps<Self> for JitBackend<R, F, I>
@@ -47,6 +55,21 @@ where
         tensor: IntTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> IntTensor<Self, D2> {
+        if tensor.shape.dims.as_slice() == shape.dims.as_slice() {
+            // Same shape: keep the current layout without launching any kernel.
+            let mut strides = [0; D2];
+            strides.copy_from_slice(&tensor.strides);
+
+            return JitTensor {
+                client: tensor.client,
+                handle: tensor.handle,
+                shape,
+                device: tensor.device,
+                strides,
+                elem: PhantomData,
+            };
+        }
+
         super::reshape(tensor, shape)
     }
 
@@ -241,6 +264,13 @@ where
         numeric::div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::remainder(lhs, rhs)
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntElem<Self>,
@@ -329,21 +359,19 @@ where
 
     fn int_random<const D: usize>(
         shape: Shape<D>,
-        distribution: Distribution,
+        distribution: IntDistribution,
         device: &Device<Self>,
     ) -> IntTensor<Self, D> {
-        let float_tensor = match distribution {
-            Distribution::Default => random_uniform(shape, device, 0.elem::<f32>(), 255.elem()),
-            Distribution::Uniform(low, high) => {
-                random_uniform(shape, device, low.elem(), high.elem())
+        match distribution {
+            IntDistribution::Uniform { low, high } => {
+                // Sampling from zero makes the cast truncate like a floor.
+                let float_tensor = random_uniform(shape, device, 0f32, (high - low) as f32);
+                Self::int_add_scalar(kernel::cast(float_tensor), low.elem())
             }
-            Distribution::Bernoulli(prob) => random_bernoulli(shape, device, prob.elem()),
-            Distribution::Normal(mean, std) => {
-                random_normal(shape, device, mean.elem(), std.elem())
+            IntDistribution::Bernoulli { p } => {
+                kernel::cast(random_bernoulli(shape, device, p as f32))
             }
-        };
-
-        kernel::cast(float_tensor)
+        }
     }
 
     fn int_permute<const D: usize>(
@@ -363,4 +391,719 @@ where
     fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseAnd(BinaryOperator {
+                lhs: scope.read_array(0, elem, position),
+                rhs: scope.read_array(1, elem, position),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseOr(BinaryOperator {
+                lhs: scope.read_array(0, elem, position),
+                rhs: scope.read_array(1, elem, position),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseXor(BinaryOperator {
+                lhs: scope.read_array(0, elem, position),
+                rhs: scope.read_array(1, elem, position),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseAnd(BinaryOperator {
+                lhs: scope.read_array(0, elem, position),
+                rhs: scope.read_scalar(0, elem),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseOr(BinaryOperator {
+                lhs: scope.read_array(0, elem, position),
+                rhs: scope.read_scalar(0, elem),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseXor(BinaryOperator {
+                lhs: scope.read_array(0, elem, position),
+                rhs: scope.read_scalar(0, elem),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseNot(UnaryOperator {
+                input: scope.read_array(0, Item::new(elem), position),
+                out: scope.create_local(elem),
+            }),
+            runtime: R,
+            input: tensor,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                saturating_shift(scope, elem, lhs, rhs, ShiftDirection::Left)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_scalar(0, elem);
+                saturating_shift(scope, elem, lhs, rhs, ShiftDirection::Left)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                saturating_shift(scope, elem, lhs, rhs, ShiftDirection::Right)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_scalar(0, elem);
+                saturating_shift(scope, elem, lhs, rhs, ShiftDirection::Right)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_pow<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+        overflow: OverflowMode,
+    ) -> IntTensor<Self, D> {
+        match overflow {
+            OverflowMode::Wrapping => binary!(
+                operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                    let lhs = scope.read_array(0, elem, position);
+                    let rhs = scope.read_array(1, elem, position);
+                    int_pow(scope, elem, lhs, rhs, false)
+                },
+                runtime: R,
+                input: lhs; rhs,
+                elem: IntElem<Self>
+            ),
+            OverflowMode::Saturating => binary!(
+                operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                    let lhs = scope.read_array(0, elem, position);
+                    let rhs = scope.read_array(1, elem, position);
+                    int_pow(scope, elem, lhs, rhs, true)
+                },
+                runtime: R,
+                input: lhs; rhs,
+                elem: IntElem<Self>
+            ),
+            // Reporting the overflows requires reading the powers back to the host.
+            OverflowMode::Panicking => burn_tensor::pow::<Self, D>(lhs, rhs, overflow),
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_isqrt<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let input = scope.read_array(0, elem, position);
+                isqrt(scope, elem, input)
+            },
+            runtime: R,
+            input: tensor,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                saturating_arith(scope, elem, lhs, rhs, ArithOp::Add)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_scalar(0, elem);
+                saturating_arith(scope, elem, lhs, rhs, ArithOp::Add)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                saturating_arith(scope, elem, lhs, rhs, ArithOp::Sub)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_scalar(0, elem);
+                saturating_arith(scope, elem, lhs, rhs, ArithOp::Sub)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                saturating_arith(scope, elem, lhs, rhs, ArithOp::Mul)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        unary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_scalar(0, elem);
+                saturating_arith(scope, elem, lhs, rhs, ArithOp::Mul)
+            },
+            runtime: R,
+            input: lhs; rhs,
+            elem: IntElem<Self>
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> (IntTensor<Self, D>, BoolTensor<Self, D>) {
+        let overflows = binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                overflow_flag(scope, elem, lhs, rhs, ArithOp::Add)
+            },
+            runtime: R,
+            input: lhs.clone(); rhs.clone(),
+            elem: IntElem<Self>
+        );
+
+        (
+            Self::int_add(lhs, rhs),
+            Self::int_equal_elem(overflows, 1.elem()),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> (IntTensor<Self, D>, BoolTensor<Self, D>) {
+        let overflows = binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                overflow_flag(scope, elem, lhs, rhs, ArithOp::Sub)
+            },
+            runtime: R,
+            input: lhs.clone(); rhs.clone(),
+            elem: IntElem<Self>
+        );
+
+        (
+            Self::int_sub(lhs, rhs),
+            Self::int_equal_elem(overflows, 1.elem()),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> (IntTensor<Self, D>, BoolTensor<Self, D>) {
+        let overflows = binary!(
+            operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+                let lhs = scope.read_array(0, elem, position);
+                let rhs = scope.read_array(1, elem, position);
+                overflow_flag(scope, elem, lhs, rhs, ArithOp::Mul)
+            },
+            runtime: R,
+            input: lhs.clone(); rhs.clone(),
+            elem: IntElem<Self>
+        );
+
+        (
+            Self::int_mul(lhs, rhs),
+            Self::int_equal_elem(overflows, 1.elem()),
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ShiftDirection {
+    Left,
+    Right,
+}
+
+/// Shifts `lhs` by `rhs` bits, shifting every bit out when the shift amount is negative or greater
+/// than or equal to the bit width of `elem`: left shifts give 0 and right shifts give the sign
+/// fill.
+fn saturating_shift(
+    scope: &mut Scope,
+    elem: Elem,
+    lhs: Variable,
+    rhs: Variable,
+    direction: ShiftDirection,
+) -> Operator {
+    let bits = match elem {
+        Elem::Int(IntKind::I64) => 64,
+        _ => 32,
+    };
+    let zero = Variable::ConstantScalar(0.0, elem);
+    let max_shift = Variable::ConstantScalar((bits - 1) as f64, elem);
+
+    let in_range = scope.create_local(Elem::Bool);
+    let below_width = scope.create_local(Elem::Bool);
+    let amount = scope.create_local(Elem::UInt);
+    let shifted = scope.create_local(elem);
+
+    cpa!(scope, in_range = rhs >= zero);
+    cpa!(scope, below_width = rhs <= max_shift);
+    cpa!(scope, in_range = in_range && below_width);
+
+    match direction {
+        ShiftDirection::Left => {
+            cpa!(scope, if(in_range).then(|scope| {
+                cpa!(scope, amount = cast(rhs));
+                cpa!(scope, shifted = lhs << amount);
+            }).else(|scope| {
+                cpa!(scope, shifted = zero);
+            }));
+        }
+        ShiftDirection::Right => {
+            cpa!(scope, if(in_range).then(|scope| {
+                cpa!(scope, amount = cast(rhs));
+            }).else(|scope| {
+                cpa!(scope, amount = cast(max_shift));
+            }));
+            cpa!(scope, shifted = lhs >> amount);
+        }
+    }
+
+    Operator::Assign(UnaryOperator {
+        input: shifted,
+        out: scope.create_local(elem),
+    })
+}
+
+#[derive(Clone, Copy)]
+enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// Computes `lhs op rhs` wrapped around the bounds of `elem`, along with whether the exact
+/// result is above or below these bounds.
+///
+/// The overflow checks only compare against limits derived from the bounds that are themselves in
+/// range, so they don't depend on how the target handles the overflowing operation. The int
+/// elements of the jit backends are 32 bits wide.
+fn overflowing_arith(
+    scope: &mut Scope,
+    elem: Elem,
+    lhs: Variable,
+    rhs: Variable,
+    op: ArithOp,
+) -> (Variable, Variable, Variable) {
+    let zero = Variable::ConstantScalar(0.0, elem);
+    let one = Variable::ConstantScalar(1.0, elem);
+    // The minimum is computed in the kernel since its literal doesn't parse in every language.
+    let max = Variable::ConstantScalar(i32::MAX as f64, elem);
+    let min = scope.create_local(elem);
+    cpa!(scope, min = zero - max);
+    cpa!(scope, min = min - one);
+
+    let result = scope.create_local(elem);
+    let limit = scope.create_local(elem);
+    let above = scope.create_local(Elem::Bool);
+    let below = scope.create_local(Elem::Bool);
+    let no_overflow: Variable = false.into();
+    cpa!(scope, above = no_overflow);
+    cpa!(scope, below = no_overflow);
+
+    match op {
+        ArithOp::Add => {
+            let lhs_positive = scope.create_local(Elem::Bool);
+            cpa!(scope, result = lhs + rhs);
+            cpa!(scope, lhs_positive = lhs >= zero);
+            cpa!(scope, if(lhs_positive).then(|scope| {
+                cpa!(scope, limit = max - lhs);
+                cpa!(scope, above = rhs > limit);
+            }).else(|scope| {
+                cpa!(scope, limit = min - lhs);
+                cpa!(scope, below = rhs < limit);
+            }));
+        }
+        ArithOp::Sub => {
+            let rhs_positive = scope.create_local(Elem::Bool);
+            cpa!(scope, result = lhs - rhs);
+            cpa!(scope, rhs_positive = rhs >= zero);
+            cpa!(scope, if(rhs_positive).then(|scope| {
+                cpa!(scope, limit = min + rhs);
+                cpa!(scope, below = lhs < limit);
+            }).else(|scope| {
+                cpa!(scope, limit = max + rhs);
+                cpa!(scope, above = lhs > limit);
+            }));
+        }
+        ArithOp::Mul => {
+            let lhs_positive = scope.create_local(Elem::Bool);
+            let lhs_negative = scope.create_local(Elem::Bool);
+            let rhs_positive = scope.create_local(Elem::Bool);
+            let rhs_negative = scope.create_local(Elem::Bool);
+            cpa!(scope, result = lhs * rhs);
+            cpa!(scope, lhs_positive = lhs > zero);
+            cpa!(scope, lhs_negative = lhs < zero);
+            cpa!(scope, rhs_positive = rhs > zero);
+            cpa!(scope, rhs_negative = rhs < zero);
+
+            // The divisions truncate towards zero, which keeps the comparisons exact for ints.
+            cpa!(scope, if(lhs_positive).then(|scope| {
+                cpa!(scope, if(rhs_positive).then(|scope| {
+                    cpa!(scope, limit = max / rhs);
+                    cpa!(scope, above = lhs > limit);
+                }));
+                cpa!(scope, if(rhs_negative).then(|scope| {
+                    cpa!(scope, limit = min / lhs);
+                    cpa!(scope, below = rhs < limit);
+                }));
+            }));
+            cpa!(scope, if(lhs_negative).then(|scope| {
+                cpa!(scope, if(rhs_positive).then(|scope| {
+                    cpa!(scope, limit = min / rhs);
+                    cpa!(scope, below = lhs < limit);
+                }));
+                cpa!(scope, if(rhs_negative).then(|scope| {
+                    cpa!(scope, limit = max / lhs);
+                    cpa!(scope, above = rhs < limit);
+                }));
+            }));
+        }
+    }
+
+    (result, above, below)
+}
+
+/// Computes `lhs op rhs`, clamping the results that overflow to the bounds of `elem`.
+fn saturating_arith(
+    scope: &mut Scope,
+    elem: Elem,
+    lhs: Variable,
+    rhs: Variable,
+    op: ArithOp,
+) -> Operator {
+    let (result, above, below) = overflowing_arith(scope, elem, lhs, rhs, op);
+    let zero = Variable::ConstantScalar(0.0, elem);
+    let one = Variable::ConstantScalar(1.0, elem);
+    let max = Variable::ConstantScalar(i32::MAX as f64, elem);
+
+    cpa!(scope, if(above).then(|scope| {
+        cpa!(scope, result = max);
+    }));
+    cpa!(scope, if(below).then(|scope| {
+        cpa!(scope, result = zero - max);
+        cpa!(scope, result = result - one);
+    }));
+
+    Operator::Assign(UnaryOperator {
+        input: result,
+        out: scope.create_local(elem),
+    })
+}
+
+/// Computes whether `lhs op rhs` overflows the bounds of `elem`, as `1` or `0`.
+fn overflow_flag(
+    scope: &mut Scope,
+    elem: Elem,
+    lhs: Variable,
+    rhs: Variable,
+    op: ArithOp,
+) -> Operator {
+    let (_, above, below) = overflowing_arith(scope, elem, lhs, rhs, op);
+    let overflowed = scope.create_local(Elem::Bool);
+    let flag = scope.create_local(elem);
+    let zero = Variable::ConstantScalar(0.0, elem);
+    let one = Variable::ConstantScalar(1.0, elem);
+
+    cpa!(scope, overflowed = above || below);
+    cpa!(scope, if(overflowed).then(|scope| {
+        cpa!(scope, flag = one);
+    }).else(|scope| {
+        cpa!(scope, flag = zero);
+    }));
+
+    Operator::Assign(UnaryOperator {
+        input: flag,
+        out: scope.create_local(elem),
+    })
+}
+
+/// Raises `lhs` to the power of the non-negative `rhs` by repeated squaring, over the 31 bits of
+/// the exponents. The powers that overflow wrap around the bounds of `elem`, or are clamped to
+/// them when `saturate` is set.
+fn int_pow(
+    scope: &mut Scope,
+    elem: Elem,
+    lhs: Variable,
+    rhs: Variable,
+    saturate: bool,
+) -> Operator {
+    let zero = Variable::ConstantScalar(0.0, elem);
+    let one = Variable::ConstantScalar(1.0, elem);
+    let shift = Variable::ConstantScalar(1.0, Elem::UInt);
+    let max = Variable::ConstantScalar(i32::MAX as f64, elem);
+
+    let result = scope.create_local(elem);
+    let square = scope.create_local(elem);
+    let remaining = scope.create_local(elem);
+    let bit = scope.create_local(elem);
+    let odd = scope.create_local(Elem::Bool);
+    let more = scope.create_local(Elem::Bool);
+    let overflowed = scope.create_local(Elem::Bool);
+    let square_overflowed = scope.create_local(Elem::Bool);
+    let no_overflow: Variable = false.into();
+
+    cpa!(scope, result = one);
+    cpa!(scope, square = lhs);
+    cpa!(scope, remaining = rhs);
+    cpa!(scope, overflowed = no_overflow);
+    cpa!(scope, square_overflowed = no_overflow);
+
+    cpa!(
+        scope,
+        range(0u32, 31u32).for_each(|_, scope| {
+            cpa!(scope, bit = remaining & one);
+            cpa!(scope, odd = bit == one);
+            cpa!(scope, if(odd).then(|scope| {
+                let (product, above, below) =
+                    overflowing_arith(scope, elem, result, square, ArithOp::Mul);
+                cpa!(scope, result = product);
+                cpa!(scope, overflowed = overflowed || square_overflowed);
+                cpa!(scope, overflowed = overflowed || above);
+                cpa!(scope, overflowed = overflowed || below);
+            }));
+            cpa!(scope, remaining = remaining >> shift);
+
+            // The base is only squared when a higher bit remains, so an overflow of the squared
+            // base always carries over to the result.
+            cpa!(scope, more = remaining > zero);
+            cpa!(scope, if(more).then(|scope| {
+                let (product, above, below) =
+                    overflowing_arith(scope, elem, square, square, ArithOp::Mul);
+                cpa!(scope, square = product);
+                cpa!(scope, square_overflowed = square_overflowed || above);
+                cpa!(scope, square_overflowed = square_overflowed || below);
+            }));
+        })
+    );
+
+    if saturate {
+        let negative = scope.create_local(Elem::Bool);
+        cpa!(scope, if(overflowed).then(|scope| {
+            cpa!(scope, bit = rhs & one);
+            cpa!(scope, odd = bit == one);
+            cpa!(scope, negative = lhs < zero);
+            cpa!(scope, negative = negative && odd);
+            cpa!(scope, if(negative).then(|scope| {
+                cpa!(scope, result = zero - max);
+                cpa!(scope, result = result - one);
+            }).else(|scope| {
+                cpa!(scope, result = max);
+            }));
+        }));
+    }
+
+    Operator::Assign(UnaryOperator {
+        input: result,
+        out: scope.create_local(elem),
+    })
+}
+
+/// Computes the integer square root of the non-negative `input` digit by digit, from the highest
+/// power of four that fits the 32 bits wide `elem`. Each step only compares and subtracts
+/// integers, so the root is exact without going through floats.
+fn isqrt(scope: &mut Scope, elem: Elem, input: Variable) -> Operator {
+    let zero = Variable::ConstantScalar(0.0, elem);
+    let shift = Variable::ConstantScalar(1.0, Elem::UInt);
+    let shift_bit = Variable::ConstantScalar(2.0, Elem::UInt);
+    let highest_bit = Variable::ConstantScalar((1 << 30) as f64, elem);
+
+    let remainder = scope.create_local(elem);
+    let root = scope.create_local(elem);
+    let bit = scope.create_local(elem);
+    let candidate = scope.create_local(elem);
+    let fits = scope.create_local(Elem::Bool);
+
+    cpa!(scope, remainder = input);
+    cpa!(scope, root = zero);
+    cpa!(scope, bit = highest_bit);
+
+    cpa!(
+        scope,
+        range(0u32, 16u32).for_each(|_, scope| {
+            cpa!(scope, candidate = root + bit);
+            cpa!(scope, fits = remainder >= candidate);
+            cpa!(scope, root = root >> shift);
+            cpa!(scope, if(fits).then(|scope| {
+                cpa!(scope, remainder = remainder - candidate);
+                cpa!(scope, root = root + bit);
+            }));
+            cpa!(scope, bit = bit >> shift_bit);
+        })
+    );
+
+    Operator::Assign(UnaryOperator {
+        input: root,
+        out: scope.create_local(elem),
+    })
 }
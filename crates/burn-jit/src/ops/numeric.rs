@@ -206,6 +206,22 @@ pub fn div_scalar<R: JitRuntime, E: JitElement, const D: usize>(
     )
 }
 
+pub fn remainder<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::Remainder(BinaryOperator {
+            lhs: scope.read_array(0, elem, position),
+            rhs: scope.read_array(1, elem, position),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
 pub fn remainder_scalar<R: JitRuntime, E: JitElement, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: E,
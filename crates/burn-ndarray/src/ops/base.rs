@@ -4,6 +4,7 @@ use burn_tensor::TensorData;
 use core::fmt::Debug;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
+use ndarray::ArcArray;
 use ndarray::Array2;
 use ndarray::IntoDimension;
 use ndarray::SliceInfo;
@@ -71,6 +72,15 @@ where
         tensors: Vec<NdArrayTensor<E, D>>,
         dim: usize,
     ) -> NdArrayTensor<E, D> {
+        let mut tensors = tensors;
+        assert!(
+            !tensors.is_empty(),
+            "Can't concatenate an empty list of tensors"
+        );
+        if tensors.len() == 1 {
+            return tensors.pop().unwrap();
+        }
+
         let arrays: Vec<ndarray::ArrayView<E, IxDyn>> =
             tensors.iter().map(|t| t.array.view()).collect();
         let array = ndarray::concatenate(Axis(dim), &arrays)
@@ -82,6 +92,42 @@ where
         Self::reshape(array.clone(), array.shape())
     }
 
+    pub fn stack<const D: usize, const D2: usize>(
+        tensors: Vec<NdArrayTensor<E, D>>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D2> {
+        let shape = tensors[0].shape();
+        let mut dims = shape.dims.to_vec();
+        dims.insert(dim, tensors.len());
+
+        let slices: Option<Vec<&[E]>> = tensors.iter().map(|t| t.array.as_slice()).collect();
+
+        // Fast path: copy the contiguous blocks of every tensor directly into the output.
+        if let Some(slices) = slices {
+            let block_size: usize = shape.dims[dim..].iter().product();
+            let num_blocks: usize = shape.dims[..dim].iter().product();
+            let mut data = Vec::with_capacity(block_size * num_blocks * slices.len());
+
+            for block in 0..num_blocks {
+                let range = block * block_size..(block + 1) * block_size;
+                for slice in slices.iter() {
+                    data.extend_from_slice(&slice[range.clone()]);
+                }
+            }
+
+            let array = ndarray::ArcArray::from_shape_vec(IxDyn(&dims), data).unwrap();
+            return NdArrayTensor::new(array);
+        }
+
+        let arrays: Vec<ndarray::ArrayView<E, IxDyn>> =
+            tensors.iter().map(|t| t.array.view()).collect();
+        let array = ndarray::stack(Axis(dim), &arrays).unwrap().into_shared();
+
+        // Transform column-major layout into row-major (standard) layout. (fix #1053)
+        let array = NdArrayTensor { array };
+        Self::reshape(array.clone(), array.shape())
+    }
+
     fn to_slice_args<const D1: usize, const D2: usize>(
         ranges: [Range<usize>; D2],
     ) -> [SliceInfoElem; D1] {
@@ -154,7 +200,8 @@ where
             .collect();
         let slice_info =
             SliceInfo::<Vec<SliceInfoElem>, IxDyn, IxDyn>::try_from(slice_items).unwrap();
-        let array = tensor.array.slice(slice_info).into_owned().into_shared();
+        // Negative strides are supported, so the flipped tensor is a view sharing the same data.
+        let array = tensor.array.slice_move(slice_info);
 
         NdArrayTensor::new(array)
     }
@@ -242,6 +289,94 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn bitwise_and<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::BitAnd<Output = E>,
+    {
+        let array = lhs.array & rhs.array;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn bitwise_or<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::BitOr<Output = E>,
+    {
+        let array = lhs.array | rhs.array;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn bitwise_xor<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::BitXor<Output = E>,
+    {
+        let array = lhs.array ^ rhs.array;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn bitwise_and_scalar<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: E,
+    ) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::BitAnd<Output = E>,
+    {
+        let array = lhs.array & rhs;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn bitwise_or_scalar<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: E,
+    ) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::BitOr<Output = E>,
+    {
+        let array = lhs.array | rhs;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn bitwise_xor_scalar<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: E,
+    ) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::BitXor<Output = E>,
+    {
+        let array = lhs.array ^ rhs;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
+    pub fn bitwise_not<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D>
+    where
+        E: core::ops::Not<Output = E>,
+    {
+        let array = !tensor.array;
+        let array = array.into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn recip<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor.array.map(|x| 1.elem::<E>() / *x);
         let array = array.into_shared();
@@ -539,6 +674,27 @@ where
         tensor
     }
 
+    /// Applies the binary operation, writing the result into the buffer of `lhs` when both
+    /// tensors have the same shape. The buffer is only copied if it is shared with another tensor,
+    /// and tensors that need broadcasting go through the `fallback` operation.
+    pub(crate) fn inplace_op<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        op: impl Fn(E, E) -> E,
+        fallback: impl FnOnce(NdArrayTensor<E, D>, NdArrayTensor<E, D>) -> NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        if lhs.array.shape() != rhs.array.shape() {
+            return fallback(lhs, rhs);
+        }
+
+        let mut array = lhs.array;
+        Zip::from(&mut array)
+            .and(&rhs.array)
+            .for_each(|a, &b| *a = op(*a, b));
+
+        NdArrayTensor::new(array)
+    }
+
     pub(crate) fn elementwise_op<const D: usize, OtherE>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<OtherE, D>,
@@ -552,6 +708,31 @@ where
         )
     }
 
+    /// Applies `func` to the elements of `lhs` and `rhs` broadcast to their common shape.
+    pub(crate) fn broadcast_op<const D: usize, O>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        func: impl FnMut(&E, &E) -> O,
+    ) -> ArcArray<O, IxDyn> {
+        let shape = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(&a, &b)| a.max(b))
+            .collect::<Vec<_>>();
+        let lhs = lhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("Tensors should be broadcastable");
+        let rhs = rhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("Tensors should be broadcastable");
+
+        Zip::from(lhs).and(rhs).map_collect(func).into_shared()
+    }
+
     pub(crate) fn elementwise_op_scalar<const D: usize>(
         lhs: NdArrayTensor<E, D>,
         var_name: impl FnMut(E) -> E,
@@ -657,4 +838,20 @@ mod tests {
             expected_array.array.into_iter().collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn should_flip_without_copying_data() {
+        let tensor = NdArrayTensor::<i64, 2>::from_data(TensorData::from([[1, 2, 3], [4, 5, 6]]));
+        let ptr = tensor.array.as_ptr();
+
+        let flipped = NdArrayOps::flip(tensor, &[1]);
+
+        // The first element of the view is the last one of each row in the original storage.
+        assert_eq!(flipped.array.as_ptr(), ptr.wrapping_add(2));
+        assert_eq!(flipped.array.strides(), &[3, -1]);
+        assert_eq!(
+            flipped.array.into_iter().collect::<Vec<_>>(),
+            vec![3, 2, 1, 6, 5, 4],
+        );
+    }
 }
@@ -3,11 +3,13 @@ use alloc::vec;
 use alloc::vec::Vec;
 use burn_common::rand::get_seeded_rng;
 use burn_tensor::ops::IntTensorOps;
-use burn_tensor::{Distribution, Reader};
+#[cfg(not(target_family = "wasm"))]
+use burn_tensor::ops::OverflowMode;
+use burn_tensor::{IntDistribution, Reader};
 
 use burn_tensor::ElementConversion;
 use core::ops::Range;
-use ndarray::IntoDimension;
+use ndarray::{Axis, IntoDimension};
 
 // Current crate
 use crate::element::ExpElement;
@@ -50,6 +52,10 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         tensor: NdArrayTensor<i64, D1>,
         shape: Shape<D2>,
     ) -> NdArrayTensor<i64, D2> {
+        if tensor.array.shape() == shape.dims.as_slice() {
+            return NdArrayTensor::new(tensor.array);
+        }
+
         NdArrayOps::reshape(tensor, shape)
     }
 
@@ -105,6 +111,13 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayOps::cat(tensors, dim)
     }
 
+    fn int_stack<const D: usize, const D2: usize>(
+        tensors: Vec<NdArrayTensor<i64, D>>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D2> {
+        NdArrayOps::stack(tensors, dim)
+    }
+
     fn int_equal<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<i64, D>,
@@ -228,6 +241,141 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::mul_scalar(lhs, rhs)
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new(NdArrayMathOps::broadcast_op(lhs, rhs, |a, b| {
+            a.saturating_add(*b)
+        }))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_add_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.saturating_add(rhs))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new(NdArrayMathOps::broadcast_op(lhs, rhs, |a, b| {
+            a.saturating_sub(*b)
+        }))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_sub_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.saturating_sub(rhs))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new(NdArrayMathOps::broadcast_op(lhs, rhs, |a, b| {
+            a.saturating_mul(*b)
+        }))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_saturating_mul_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.saturating_mul(rhs))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_add<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<bool, D>) {
+        let output = NdArrayMathOps::broadcast_op(lhs, rhs, |a, b| a.overflowing_add(*b));
+
+        (
+            NdArrayTensor::new(output.mapv(|(value, _)| value).into_shared()),
+            NdArrayTensor::new(output.mapv(|(_, overflowed)| overflowed).into_shared()),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_sub<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<bool, D>) {
+        let output = NdArrayMathOps::broadcast_op(lhs, rhs, |a, b| a.overflowing_sub(*b));
+
+        (
+            NdArrayTensor::new(output.mapv(|(value, _)| value).into_shared()),
+            NdArrayTensor::new(output.mapv(|(_, overflowed)| overflowed).into_shared()),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_checked_mul<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<bool, D>) {
+        let output = NdArrayMathOps::broadcast_op(lhs, rhs, |a, b| a.overflowing_mul(*b));
+
+        (
+            NdArrayTensor::new(output.mapv(|(value, _)| value).into_shared()),
+            NdArrayTensor::new(output.mapv(|(_, overflowed)| overflowed).into_shared()),
+        )
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_pow<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+        overflow: OverflowMode,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new(NdArrayMathOps::broadcast_op(lhs, rhs, |&base, &exp| {
+            pow(base, exp, overflow)
+        }))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn int_isqrt<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(tensor, |value: i64| {
+            if value < 0 {
+                panic!("Can't compute the integer square root of negative values, got {value}");
+            }
+            burn_tensor::ops::isqrt_u64(value as u64) as i64
+        })
+    }
+
+    fn int_add_inplace<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::inplace_op(lhs, rhs, |a, b| a + b, NdArrayMathOps::add)
+    }
+
+    fn int_sub_inplace<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::inplace_op(lhs, rhs, |a, b| a - b, NdArrayMathOps::sub)
+    }
+
+    fn int_mul_inplace<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::inplace_op(lhs, rhs, |a, b| a * b, NdArrayMathOps::mul)
+    }
+
     fn int_div<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<i64, D>,
@@ -242,11 +390,20 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new(NdArrayMathOps::broadcast_op(lhs, rhs, |&a, &b| {
+            remainder(a, b)
+        }))
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: i64,
     ) -> NdArrayTensor<i64, D> {
-        NdArrayMathOps::remainder_scalar(lhs, rhs)
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| remainder(a, rhs))
     }
 
     fn int_neg<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
@@ -400,7 +557,7 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
 
     fn int_random<const D: usize>(
         shape: Shape<D>,
-        distribution: Distribution,
+        distribution: IntDistribution,
         device: &NdArrayDevice,
     ) -> NdArrayTensor<i64, D> {
         let mut seed = SEED.lock().unwrap();
@@ -410,14 +567,8 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
             get_seeded_rng()
         };
 
-        let effective_distribution = if distribution == Distribution::Default {
-            Distribution::Uniform(0.0, 255.0) // Assuming UniformInt is the integer variant
-        } else {
-            distribution
-        };
-
         let tensor = Self::int_from_data(
-            TensorData::random::<i64, _, _>(shape, effective_distribution, &mut rng),
+            TensorData::random::<i64, _, _>(shape, distribution.into(), &mut rng),
             device,
         );
         *seed = Some(rng);
@@ -464,10 +615,166 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::sign_op(tensor)
     }
 
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     fn int_expand<const D1: usize, const D2: usize>(
         tensor: burn_tensor::ops::IntTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> burn_tensor::ops::IntTensor<Self, D2> {
         NdArrayOps::expand(tensor, shape)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_and_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_or_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_xor_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::bitwise_not(tensor)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a: &i64, b: &i64| shift_left(*a, *b))
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| shift_left(a, rhs))
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a: &i64, b: &i64| shift_right(*a, *b))
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| shift_right(a, rhs))
+    }
+}
+
+/// Shifts `lhs` to the left by `rhs` bits, giving 0 when the shift amount is out of range.
+fn shift_left(lhs: i64, rhs: i64) -> i64 {
+    match u32::try_from(rhs) {
+        Ok(rhs) if rhs < i64::BITS => lhs << rhs,
+        _ => 0,
+    }
+}
+
+/// Shifts `lhs` to the right by `rhs` bits, giving the sign fill when the shift amount is out of
+/// range.
+fn shift_right(lhs: i64, rhs: i64) -> i64 {
+    match u32::try_from(rhs) {
+        Ok(rhs) if rhs < i64::BITS => lhs >> rhs,
+        _ => lhs >> (i64::BITS - 1),
+    }
+}
+
+/// Raises `base` to the power `exp`, handling the powers that overflow `i64` with the `overflow`
+/// mode.
+#[cfg(not(target_family = "wasm"))]
+fn pow(base: i64, exp: i64, overflow: OverflowMode) -> i64 {
+    if exp < 0 {
+        panic!("Can't raise integers to negative powers, got {exp}");
+    }
+
+    // Exponents that don't fit `u32` only give powers in range for the bases from -1 to 1.
+    let exact = match u32::try_from(exp) {
+        Ok(exp) => base.checked_pow(exp),
+        Err(_) => (-1..=1)
+            .contains(&base)
+            .then(|| base.pow(2 - (exp % 2) as u32)),
+    };
+
+    match (exact, overflow) {
+        (Some(value), _) => value,
+        (None, OverflowMode::Wrapping) => wrapping_pow(base, exp as u64),
+        (None, OverflowMode::Saturating) => match u32::try_from(exp) {
+            Ok(exp) => base.saturating_pow(exp),
+            Err(_) if base < 0 && exp % 2 == 1 => i64::MIN,
+            Err(_) => i64::MAX,
+        },
+        (None, OverflowMode::Panicking) => {
+            panic!("The power {base}^{exp} overflows the int element type")
+        }
+    }
+}
+
+/// Wraps `base^exp` around the bounds of `i64`, splitting the exponents that don't fit `u32`
+/// into `base^(2^32 * high) * base^low`.
+#[cfg(not(target_family = "wasm"))]
+fn wrapping_pow(base: i64, exp: u64) -> i64 {
+    let low = base.wrapping_pow(exp as u32);
+    let high = base
+        .wrapping_pow(1 << 31)
+        .wrapping_pow(2)
+        .wrapping_pow((exp >> 32) as u32);
+
+    high.wrapping_mul(low)
+}
+
+/// Computes the floored remainder, which has the sign of the divisor. The truncated remainder
+/// only moves by the divisor when their signs differ, so it can't overflow.
+fn remainder(lhs: i64, rhs: i64) -> i64 {
+    let remainder = lhs.wrapping_rem(rhs);
+
+    if remainder != 0 && (remainder < 0) != (rhs < 0) {
+        remainder + rhs
+    } else {
+        remainder
+    }
 }
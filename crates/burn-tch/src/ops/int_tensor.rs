@@ -1,6 +1,8 @@
 use std::ops::Range;
 
-use burn_tensor::{backend::Backend, ops::IntTensorOps, Distribution, Reader, Shape, TensorData};
+use burn_tensor::{
+    backend::Backend, ops::IntTensorOps, IntDistribution, Reader, Shape, TensorData,
+};
 
 use crate::{element::TchElement, LibTorch, LibTorchDevice, TchShape, TchTensor};
 
@@ -216,6 +218,19 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchTensor::<i64, D>::new(out.tensor.to_dtype(tch::Kind::Int64, non_blocking, copy))
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_remainder_tensor_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+        )
+    }
+
     fn int_remainder_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
         lhs.unary_ops(
             |tensor| tensor.f_remainder(rhs).unwrap(),
@@ -223,6 +238,106 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         )
     }
 
+    fn int_saturating_add<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let output = lhs.tensor.f_add(&rhs.tensor).unwrap();
+        let overflow = add_overflow(&lhs.tensor, &rhs.tensor, &output);
+        let bound = tch::Tensor::where_scalar(&lhs.tensor.lt(0), i64::MIN, i64::MAX);
+
+        TchTensor::new(bound.where_self(&overflow, &output))
+    }
+
+    fn int_saturating_add_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        // Clamping first keeps the sum in range, which saturates it at the bound.
+        let (min, max) = if rhs < 0 {
+            (i64::MIN - rhs, i64::MAX)
+        } else {
+            (i64::MIN, i64::MAX - rhs)
+        };
+
+        TchTensor::new(lhs.tensor.clamp(min, max).f_add_scalar(rhs).unwrap())
+    }
+
+    fn int_saturating_sub<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let output = lhs.tensor.f_sub(&rhs.tensor).unwrap();
+        let overflow = sub_overflow(&lhs.tensor, &rhs.tensor, &output);
+        let bound = tch::Tensor::where_scalar(&lhs.tensor.lt(0), i64::MIN, i64::MAX);
+
+        TchTensor::new(bound.where_self(&overflow, &output))
+    }
+
+    fn int_saturating_sub_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        // Clamping first keeps the difference in range, which saturates it at the bound.
+        let (min, max) = if rhs < 0 {
+            (i64::MIN, i64::MAX + rhs)
+        } else {
+            (i64::MIN + rhs, i64::MAX)
+        };
+
+        TchTensor::new(lhs.tensor.clamp(min, max).f_sub_scalar(rhs).unwrap())
+    }
+
+    fn int_saturating_mul<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let output = lhs.tensor.f_mul(&rhs.tensor).unwrap();
+        let overflow = mul_overflow(&lhs.tensor, &rhs.tensor, &output);
+        let negative = lhs.tensor.lt(0).logical_xor(&rhs.tensor.lt(0));
+        let bound = tch::Tensor::where_scalar(&negative, i64::MIN, i64::MAX);
+
+        TchTensor::new(bound.where_self(&overflow, &output))
+    }
+
+    fn int_saturating_mul_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        let rhs = TchTensor::new(lhs.tensor.full_like(rhs));
+        Self::int_saturating_mul(lhs, rhs)
+    }
+
+    fn int_checked_add<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> (TchTensor<i64, D>, TchTensor<bool, D>) {
+        let output = lhs.tensor.f_add(&rhs.tensor).unwrap();
+        let overflow = add_overflow(&lhs.tensor, &rhs.tensor, &output);
+
+        (TchTensor::new(output), TchTensor::new(overflow))
+    }
+
+    fn int_checked_sub<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> (TchTensor<i64, D>, TchTensor<bool, D>) {
+        let output = lhs.tensor.f_sub(&rhs.tensor).unwrap();
+        let overflow = sub_overflow(&lhs.tensor, &rhs.tensor, &output);
+
+        (TchTensor::new(output), TchTensor::new(overflow))
+    }
+
+    fn int_checked_mul<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> (TchTensor<i64, D>, TchTensor<bool, D>) {
+        let output = lhs.tensor.f_mul(&rhs.tensor).unwrap();
+        let overflow = mul_overflow(&lhs.tensor, &rhs.tensor, &output);
+
+        (TchTensor::new(output), TchTensor::new(overflow))
+    }
+
     fn int_neg<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
         Self::int_mul_scalar(tensor, -1)
     }
@@ -436,30 +551,17 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
 
     fn int_random<const D: usize>(
         shape: Shape<D>,
-        distribution: Distribution,
+        distribution: IntDistribution,
         device: &LibTorchDevice,
     ) -> TchTensor<i64, D> {
+        let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
         match distribution {
-            Distribution::Default => {
-                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
-                tensor
-                    .mut_ops(|tensor| tensor.uniform_(0.0, 255.0))
-                    .unwrap()
-            }
-            Distribution::Bernoulli(prob) => {
-                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
-                tensor
-                    .mut_ops(|tensor| tensor.f_bernoulli_float_(prob).unwrap())
-                    .unwrap()
-            }
-            Distribution::Uniform(from, to) => {
-                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
-                tensor.mut_ops(|tensor| tensor.uniform_(from, to)).unwrap()
-            }
-            Distribution::Normal(mean, std) => {
-                let mut tensor = TchTensor::<i64, D>::empty(shape, *device);
-                tensor.mut_ops(|tensor| tensor.normal_(mean, std)).unwrap()
-            }
+            IntDistribution::Uniform { low, high } => tensor
+                .mut_ops(|tensor| tensor.random_from_(low, high))
+                .unwrap(),
+            IntDistribution::Bernoulli { p } => tensor
+                .mut_ops(|tensor| tensor.f_bernoulli_float_(p).unwrap())
+                .unwrap(),
         }
     }
 
@@ -516,4 +618,152 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
     ) -> <LibTorch<E> as Backend>::IntTensorPrimitive<D> {
         TchOps::argsort(tensor, dim, descending)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_and_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_and(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_or_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_or(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_xor_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_xor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.f_bitwise_not_().unwrap(),
+            |tensor| tensor.f_bitwise_not().unwrap(),
+        )
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let max_shift = i64::BITS as i64 - 1;
+        let in_range = rhs.tensor.ge(0).logical_and(&rhs.tensor.le(max_shift));
+        let amount = rhs.tensor.clamp(0, max_shift);
+        let tensor = lhs
+            .tensor
+            .bitwise_left_shift(&amount)
+            .where_scalarother(&in_range, 0);
+
+        TchTensor::new(tensor)
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        if (0..i64::BITS as i64).contains(&rhs) {
+            lhs.unary_ops(
+                |mut tensor| tensor.f_bitwise_left_shift_tensor_scalar_(rhs).unwrap(),
+                |tensor| tensor.f_bitwise_left_shift_tensor_scalar(rhs).unwrap(),
+            )
+        } else {
+            TchTensor::new(lhs.tensor.zeros_like())
+        }
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let max_shift = i64::BITS as i64 - 1;
+        let in_range = rhs.tensor.ge(0).logical_and(&rhs.tensor.le(max_shift));
+        let amount = rhs
+            .tensor
+            .clamp(0, max_shift)
+            .where_scalarother(&in_range, max_shift);
+        let tensor = lhs.tensor.bitwise_right_shift(&amount);
+
+        TchTensor::new(tensor)
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        let max_shift = i64::BITS as i64 - 1;
+        let amount = if (0..=max_shift).contains(&rhs) {
+            rhs
+        } else {
+            max_shift
+        };
+
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_right_shift_tensor_scalar_(amount).unwrap(),
+            |tensor| tensor.f_bitwise_right_shift_tensor_scalar(amount).unwrap(),
+        )
+    }
+}
+
+/// Flags the positions where the wrapped sum `output` overflowed, which is where both operands
+/// have the same sign and the sum has the other one.
+fn add_overflow(lhs: &tch::Tensor, rhs: &tch::Tensor, output: &tch::Tensor) -> tch::Tensor {
+    lhs.bitwise_xor_tensor(output)
+        .bitwise_and_tensor(&rhs.bitwise_xor_tensor(output))
+        .lt(0)
+}
+
+/// Flags the positions where the wrapped difference `output` overflowed, which is where the
+/// operands have different signs and the difference doesn't have the sign of `lhs`.
+fn sub_overflow(lhs: &tch::Tensor, rhs: &tch::Tensor, output: &tch::Tensor) -> tch::Tensor {
+    lhs.bitwise_xor_tensor(rhs)
+        .bitwise_and_tensor(&lhs.bitwise_xor_tensor(output))
+        .lt(0)
+}
+
+/// Flags the positions where the wrapped product `output` overflowed, which is where dividing it
+/// by `lhs` doesn't give back `rhs`. Dividing by `-1` could itself overflow, so `-1 * MIN` is
+/// flagged separately.
+fn mul_overflow(lhs: &tch::Tensor, rhs: &tch::Tensor, output: &tch::Tensor) -> tch::Tensor {
+    let divisible = lhs.ne(0).logical_and(&lhs.ne(-1));
+    let divisor = lhs.where_scalarother(&divisible, 1);
+    let wrapped = output.divide_tensor_mode(&divisor, "trunc").ne_tensor(rhs);
+
+    divisible
+        .logical_and(&wrapped)
+        .logical_or(&lhs.eq(-1).logical_and(&rhs.eq(i64::MIN)))
 }
@@ -2,9 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::ops::Range;
 
 use crate::{
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions},
+    ops::{ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, OverflowMode},
     repr::tensor::TensorDescription,
-    Distribution, Element,
+    Distribution, Element, IntDistribution,
 };
 
 /// Describe all tensor operations possible.
@@ -423,7 +423,7 @@ pub enum NumericOperationDescription<E> {
     /// Operation corresponding to:
     ///
     /// Int => [random](crate::ops::IntTensorOps::int_random).
-    IntRandom(RandomOperationDescription),
+    IntRandom(IntRandomOperationDescription),
     /// Operation corresponding to:
     ///
     /// Float => [powf](crate::ops::FloatTensorOps::float_powf).
@@ -436,6 +436,57 @@ pub enum NumericOperationDescription<E> {
 pub enum IntOperationDescription {
     /// Operation corresponding to [into float](crate::ops::IntTensorOps::int_into_float).
     IntoFloat(UnaryOperationDescription),
+    /// Operation corresponding to [bitwise and](crate::ops::IntTensorOps::int_bitwise_and).
+    BitwiseAnd(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise or](crate::ops::IntTensorOps::int_bitwise_or).
+    BitwiseOr(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise xor](crate::ops::IntTensorOps::int_bitwise_xor).
+    BitwiseXor(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise and scalar](crate::ops::IntTensorOps::int_bitwise_and_scalar).
+    BitwiseAndScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise or scalar](crate::ops::IntTensorOps::int_bitwise_or_scalar).
+    BitwiseOrScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise xor scalar](crate::ops::IntTensorOps::int_bitwise_xor_scalar).
+    BitwiseXorScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise not](crate::ops::IntTensorOps::int_bitwise_not).
+    BitwiseNot(UnaryOperationDescription),
+    /// Operation corresponding to [bitwise left shift](crate::ops::IntTensorOps::int_bitwise_left_shift).
+    BitwiseLeftShift(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise left shift scalar](crate::ops::IntTensorOps::int_bitwise_left_shift_scalar).
+    BitwiseLeftShiftScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise right shift](crate::ops::IntTensorOps::int_bitwise_right_shift).
+    BitwiseRightShift(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [bitwise right shift scalar](crate::ops::IntTensorOps::int_bitwise_right_shift_scalar).
+    BitwiseRightShiftScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [remainder](crate::ops::IntTensorOps::int_remainder).
+    Remainder(BinaryOperationDescription),
+    /// Operation corresponding to [saturating add](crate::ops::IntTensorOps::int_saturating_add).
+    SaturatingAdd(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [saturating add scalar](crate::ops::IntTensorOps::int_saturating_add_scalar).
+    SaturatingAddScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [saturating sub](crate::ops::IntTensorOps::int_saturating_sub).
+    SaturatingSub(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [saturating sub scalar](crate::ops::IntTensorOps::int_saturating_sub_scalar).
+    SaturatingSubScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [saturating mul](crate::ops::IntTensorOps::int_saturating_mul).
+    SaturatingMul(BinaryOperationDescription),
+    /// Operation corresponding to
+    /// [saturating mul scalar](crate::ops::IntTensorOps::int_saturating_mul_scalar).
+    SaturatingMulScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [checked add](crate::ops::IntTensorOps::int_checked_add).
+    CheckedAdd(CheckedOperationDescription),
+    /// Operation corresponding to [checked sub](crate::ops::IntTensorOps::int_checked_sub).
+    CheckedSub(CheckedOperationDescription),
+    /// Operation corresponding to [checked mul](crate::ops::IntTensorOps::int_checked_mul).
+    CheckedMul(CheckedOperationDescription),
+    /// Operation corresponding to [pow](crate::ops::IntTensorOps::int_pow).
+    Pow(IntPowDescription),
+    /// Operation corresponding to [isqrt](crate::ops::IntTensorOps::int_isqrt).
+    Isqrt(UnaryOperationDescription),
 }
 
 /// Operation description specific to a bool tensor.
@@ -502,6 +553,13 @@ pub struct RandomOperationDescription {
     pub distribution: Distribution,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct IntRandomOperationDescription {
+    pub out: TensorDescription,
+    pub distribution: IntDistribution,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct ReshapeDescription {
@@ -647,6 +705,52 @@ pub struct ReduceDimWithIndicesDescription {
     pub out_indices: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct CheckedOperationDescription {
+    pub lhs: TensorDescription,
+    pub rhs: TensorDescription,
+    pub out: TensorDescription,
+    pub out_overflow: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum OverflowModeDescription {
+    Wrapping,
+    Saturating,
+    Panicking,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct IntPowDescription {
+    pub lhs: TensorDescription,
+    pub rhs: TensorDescription,
+    pub overflow: OverflowModeDescription,
+    pub out: TensorDescription,
+}
+
+impl From<OverflowModeDescription> for OverflowMode {
+    fn from(val: OverflowModeDescription) -> Self {
+        match val {
+            OverflowModeDescription::Wrapping => Self::Wrapping,
+            OverflowModeDescription::Saturating => Self::Saturating,
+            OverflowModeDescription::Panicking => Self::Panicking,
+        }
+    }
+}
+
+impl From<OverflowMode> for OverflowModeDescription {
+    fn from(val: OverflowMode) -> Self {
+        match val {
+            OverflowMode::Wrapping => Self::Wrapping,
+            OverflowMode::Saturating => Self::Saturating,
+            OverflowMode::Panicking => Self::Panicking,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct EmbeddingDescription {
@@ -1271,6 +1375,39 @@ impl IntOperationDescription {
     fn nodes(&self) -> Vec<&TensorDescription> {
         match self {
             IntOperationDescription::IntoFloat(desc) => vec![&desc.input, &desc.out],
+            IntOperationDescription::BitwiseAnd(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::BitwiseOr(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::BitwiseXor(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::BitwiseAndScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseOrScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseXorScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseNot(desc) => vec![&desc.input, &desc.out],
+            IntOperationDescription::BitwiseLeftShift(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            IntOperationDescription::BitwiseLeftShiftScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::BitwiseRightShift(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            IntOperationDescription::BitwiseRightShiftScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::Remainder(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::SaturatingAdd(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::SaturatingAddScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::SaturatingSub(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::SaturatingSubScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::SaturatingMul(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::SaturatingMulScalar(desc) => vec![&desc.lhs, &desc.out],
+            IntOperationDescription::CheckedAdd(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out, &desc.out_overflow]
+            }
+            IntOperationDescription::CheckedSub(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out, &desc.out_overflow]
+            }
+            IntOperationDescription::CheckedMul(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out, &desc.out_overflow]
+            }
+            IntOperationDescription::Pow(desc) => vec![&desc.lhs, &desc.rhs, &desc.out],
+            IntOperationDescription::Isqrt(desc) => vec![&desc.input, &desc.out],
         }
     }
 }
@@ -1387,6 +1524,17 @@ impl core::hash::Hash for RandomOperationDescription {
     }
 }
 
+impl core::hash::Hash for IntRandomOperationDescription {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.out.hash(state);
+
+        match self.distribution {
+            IntDistribution::Uniform { .. } => 1u8.hash(state),
+            IntDistribution::Bernoulli { .. } => 2u8.hash(state),
+        }
+    }
+}
+
 impl<E> core::hash::Hash for ScalarOperationDescription<E> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.lhs.hash(state);
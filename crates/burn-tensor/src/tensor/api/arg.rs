@@ -0,0 +1,113 @@
+use crate::{
+    backend::Backend,
+    ops::{IntElem, IntTensor, WideIndices},
+    Element,
+};
+use alloc::vec::Vec;
+
+/// Computes the indices of the maximum elements of the input `tensor` along a given dimension,
+/// as wide indices that can address dimensions longer than the int element type can index.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The dimension to get the maximum indices along.
+/// * `chunk_size` - The length of the chunks reduced with [int_argmax](crate::ops::IntTensorOps::int_argmax),
+///   at most the positive range of the int element type.
+///
+/// # Returns
+///
+/// The wide indices, of the same shape as the input tensor except for `dim` which has a size
+/// of 1. Ties are broken by returning the first index.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn argmax_usize<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+    chunk_size: usize,
+) -> WideIndices<B, D> {
+    arg_chunked::<B, D>(tensor, dim, chunk_size, B::int_argmax, B::int_max_dim)
+}
+
+/// Computes the indices of the minimum elements of the input `tensor` along a given dimension,
+/// as wide indices that can address dimensions longer than the int element type can index.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The dimension to get the minimum indices along.
+/// * `chunk_size` - The length of the chunks reduced with [int_argmin](crate::ops::IntTensorOps::int_argmin),
+///   at most the positive range of the int element type.
+///
+/// # Returns
+///
+/// The wide indices, of the same shape as the input tensor except for `dim` which has a size
+/// of 1. Ties are broken by returning the first index.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn argmin_usize<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+    chunk_size: usize,
+) -> WideIndices<B, D> {
+    arg_chunked::<B, D>(tensor, dim, chunk_size, B::int_argmin, B::int_min_dim)
+}
+
+/// Returns the largest chunk of a dimension whose indices fit the int element type of the backend.
+pub(crate) fn wide_index_chunk_size<B: Backend>() -> usize {
+    let max = IntElem::<B>::dtype()
+        .int_bounds()
+        .map_or(i64::MAX, |(_, max)| max);
+
+    usize::try_from(max).unwrap_or(usize::MAX)
+}
+
+/// Reduces the chunks of `chunk_size` elements along `dim` with `arg`, then picks the best chunk
+/// among the values selected by `reduce`.
+///
+/// The values of each chunk are reduced rather than gathered, since the chunks are narrowed views
+/// that not every backend can gather from.
+fn arg_chunked<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+    chunk_size: usize,
+    arg: fn(IntTensor<B, D>, usize) -> IntTensor<B, D>,
+    reduce: fn(IntTensor<B, D>, usize) -> IntTensor<B, D>,
+) -> WideIndices<B, D> {
+    let size = B::int_shape(&tensor).dims[dim];
+
+    if size <= chunk_size {
+        let offset = arg(tensor, dim);
+        let chunk = B::int_zeros(B::int_shape(&offset), &B::int_device(&offset));
+        return WideIndices::new(chunk, offset, chunk_size);
+    }
+
+    assert!(
+        size.div_ceil(chunk_size) <= chunk_size,
+        "Can't index a dimension of {size} elements with chunks of {chunk_size} elements"
+    );
+
+    let mut values = Vec::with_capacity(size.div_ceil(chunk_size));
+    let mut offsets = Vec::with_capacity(size.div_ceil(chunk_size));
+
+    for start in (0..size).step_by(chunk_size) {
+        let slice = B::int_narrow(tensor.clone(), dim, start, chunk_size.min(size - start));
+        offsets.push(arg(slice.clone(), dim));
+        values.push(reduce(slice, dim));
+    }
+
+    let chunk = arg(B::int_cat(values, dim), dim);
+    let offset = B::int_gather(dim, B::int_cat(offsets, dim), chunk.clone());
+
+    WideIndices::new(chunk, offset, chunk_size)
+}
@@ -20,6 +20,7 @@ use serde::{Serialize, Serializer};
 use crate::check::TensorCheck;
 use crate::tensor::api::chunk::chunk;
 use crate::tensor::api::narrow::narrow;
+use crate::tensor::ops::cat::take_single_cat_input;
 use crate::Element;
 use crate::{backend::Backend, check, Bool, Float, Int, Shape, TensorData, TensorKind};
 
@@ -124,7 +125,12 @@ where
     /// # Returns
     ///
     /// The transposed tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 2 dimensions.
     pub fn transpose(self) -> Tensor<B, D, K> {
+        check!(TensorCheck::transpose::<D>());
         Tensor::new(K::transpose(self.primitive))
     }
 
@@ -204,6 +210,10 @@ where
             &destination_dims
         ));
 
+        if let ([src], [dst]) = (source_dims.as_slice(), destination_dims.as_slice()) {
+            return Tensor::new(K::movedim(self.primitive, *src, *dst));
+        }
+
         let mut m = [-1; D];
         for (&d, &s) in destination_dims.iter().zip(source_dims.iter()) {
             m[d] = s as isize;
@@ -293,19 +303,7 @@ where
     pub fn flatten<const D2: usize>(self, start_dim: usize, end_dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::flatten::<D, D2>(start_dim, end_dim));
 
-        let current_dims = self.shape().dims;
-        let mut new_dims: [usize; D2] = [0; D2];
-        let mut flatten_dims = 1;
-
-        for i in current_dims[start_dim..=end_dim].iter() {
-            flatten_dims *= i;
-        }
-
-        new_dims[..start_dim].copy_from_slice(&current_dims[..start_dim]);
-        new_dims[start_dim] = flatten_dims;
-        new_dims[start_dim + 1..].copy_from_slice(&current_dims[end_dim + 1..]);
-
-        Tensor::new(K::reshape::<D, D2>(self.primitive, new_dims.into()))
+        Tensor::new(K::flatten::<D, D2>(self.primitive, start_dim, end_dim))
     }
 
     /// Squeeze the tensor along the given dimension, removing the specified dimension
@@ -344,13 +342,7 @@ where
     pub fn squeeze<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::squeeze::<D2>(dim, &self.shape().dims));
 
-        let current_dims = self.shape().dims;
-        let mut new_dims: [usize; D2] = [0; D2];
-
-        new_dims[..dim].copy_from_slice(&current_dims[..dim]);
-        new_dims[dim..].copy_from_slice(&current_dims[dim + 1..]);
-
-        Tensor::new(K::reshape::<D, D2>(self.primitive, new_dims.into()))
+        Tensor::new(K::squeeze::<D, D2>(self.primitive, dim))
     }
 
     /// Removes specified dimensions of size 1 from a tensor's shape. This function takes a tensor and
@@ -492,20 +484,7 @@ where
     pub fn unsqueeze_dim<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::unsqueeze_dim::<{ D }>(dim));
 
-        let mut dims = [1; D2];
-        let shape = self.shape();
-
-        dims[0..dim].copy_from_slice(&shape.dims[0..dim]);
-
-        if dim < D {
-            dims[dim] = 1;
-            dims[(dim + 1)..].copy_from_slice(&shape.dims[dim..]);
-        } else {
-            dims[dim] = 1;
-        }
-
-        let shape = Shape::new(dims);
-        self.reshape(shape)
+        Tensor::new(K::unsqueeze_dim::<D, D2>(self.primitive, dim))
     }
 
     /// Creates a new tensor with added dimensions of size one inserted at the specified indices.
@@ -710,6 +689,9 @@ where
     }
 
     /// Repeat the tensor along the given dimension.
+    ///
+    /// The whole tensor is concatenated `times` times along `dim`, like PyTorch's `repeat` along
+    /// a single axis, so a dimension of size `s` ends up with a size of `s * times`.
     pub fn repeat(self, dim: usize, times: usize) -> Self {
         Self::new(K::repeat(self.primitive, dim, times))
     }
@@ -736,12 +718,27 @@ where
 
     /// Concatenates all tensors into a new one along the given dimension.
     ///
+    /// Tensors with a length of zero along `dim` are allowed and contribute nothing to the
+    /// output, but their other dimensions must still match.
+    ///
     /// # Panics
     ///
-    /// If all tensors don't have the same shape.
+    /// If all tensors don't have the same shape, except along the given dimension.
     pub fn cat(tensors: Vec<Self>, dim: usize) -> Self {
         check!(TensorCheck::cat(&tensors, dim));
 
+        // Skip the zero-length tensors, keeping one if they are all empty.
+        let mut tensors = tensors;
+        if tensors.iter().any(|tensor| tensor.dims()[dim] > 0) {
+            tensors.retain(|tensor| tensor.dims()[dim] > 0);
+        } else {
+            tensors.truncate(1);
+        }
+
+        if let Some(tensor) = take_single_cat_input(&mut tensors) {
+            return tensor;
+        }
+
         Self::new(K::cat(
             tensors.into_iter().map(|vector| vector.primitive).collect(),
             dim,
@@ -756,8 +753,10 @@ where
     /// Given dimension is not with range of 0..D2
     pub fn stack<const D2: usize>(tensors: Vec<Tensor<B, D, K>>, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::stack(&tensors, dim));
-        let tensors = tensors.into_iter().map(|t| t.unsqueeze_dim(dim)).collect();
-        Tensor::<B, D2, K>::cat(tensors, dim)
+        Tensor::new(K::stack(
+            tensors.into_iter().map(|tensor| tensor.primitive).collect(),
+            dim,
+        ))
     }
 
     /// Iterate over slices of tensors alongside a given dimension.
@@ -794,7 +793,8 @@ where
     /// May return less chunks than requested if the tensor size is not divisible by the number of chunks.
     ///
     /// When the given dimension is evenly divisible by the number of chunks, the chunks will be of equal size.
-    /// Otherwise all chunks will be of equal size except for the last one.
+    /// Otherwise all chunks have a size of `ceil(size / chunks)` except for the last one, which holds the
+    /// remainder, like `torch.chunk`.
     ///
     /// # Panics
     ///
@@ -1215,6 +1215,18 @@ impl<B: Backend, const D: usize> core::ops::BitXor<T> for Tensor<B, D> {
     }
 }
 
+/// Returns the shape with a new dimension of size one inserted at `dim`.
+pub(crate) fn unsqueeze_shape<const D: usize, const D2: usize>(
+    shape: &Shape<D>,
+    dim: usize,
+) -> Shape<D2> {
+    let mut dims = [1; D2];
+    dims[0..dim].copy_from_slice(&shape.dims[0..dim]);
+    dims[(dim + 1)..].copy_from_slice(&shape.dims[dim..]);
+
+    Shape::new(dims)
+}
+
 /// Trait that list all operations that can be applied on all tensors.
 ///
 /// # Warnings
@@ -1289,6 +1301,105 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         shape: Shape<D2>,
     ) -> Self::Primitive<D2>;
 
+    /// Flattens the dimensions from `start_dim` to `end_dim` inclusive into a single dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `start_dim` - The first dimension to flatten.
+    /// * `end_dim` - The last dimension to flatten.
+    ///
+    /// # Returns
+    ///
+    /// The flattened tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For flattening a tensor, users should prefer the [Tensor::flatten](Tensor::flatten) function,
+    /// which is more high-level and designed for public use.
+    fn flatten<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        start_dim: usize,
+        end_dim: usize,
+    ) -> Self::Primitive<D2> {
+        let current_dims = Self::shape(&tensor).dims;
+        let mut new_dims: [usize; D2] = [0; D2];
+
+        new_dims[..start_dim].copy_from_slice(&current_dims[..start_dim]);
+        new_dims[start_dim] = current_dims[start_dim..=end_dim].iter().product();
+        new_dims[start_dim + 1..].copy_from_slice(&current_dims[end_dim + 1..]);
+
+        Self::reshape(tensor, new_dims.into())
+    }
+
+    /// Removes the dimension `dim` of size one.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to remove.
+    ///
+    /// # Returns
+    ///
+    /// The squeezed tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For squeezing a tensor, users should prefer the [Tensor::squeeze](Tensor::squeeze) function,
+    /// which is more high-level and designed for public use.
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        let current_dims = Self::shape(&tensor).dims;
+        let mut new_dims: [usize; D2] = [0; D2];
+
+        new_dims[..dim].copy_from_slice(&current_dims[..dim]);
+        new_dims[dim..].copy_from_slice(&current_dims[dim + 1..]);
+
+        Self::reshape(tensor, new_dims.into())
+    }
+
+    /// Inserts a dimension of size one at `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The position of the new dimension in the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The unsqueezed tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For unsqueezing a tensor, users should prefer the [Tensor::unsqueeze_dim](Tensor::unsqueeze_dim)
+    /// function, which is more high-level and designed for public use.
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        let current_dims = Self::shape(&tensor).dims;
+        let mut new_dims: [usize; D2] = [1; D2];
+
+        new_dims[..dim].copy_from_slice(&current_dims[..dim]);
+        new_dims[dim + 1..=D1].copy_from_slice(&current_dims[dim..]);
+
+        Self::reshape(tensor, new_dims.into())
+    }
+
     /// Transposes a tensor.
     ///
     /// # Arguments
@@ -1329,6 +1440,37 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// The tensor with the dimensions permuted.
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D>;
 
+    /// Moves the dimension `src` to the position `dst`, shifting the dimensions in between.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `src` - The dimension to move.
+    /// * `dst` - The position of the moved dimension in the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimension moved.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For moving dimensions, users should prefer the [Tensor::movedim](Tensor::movedim) function,
+    /// which is more high-level and designed for public use.
+    fn movedim<const D: usize>(
+        tensor: Self::Primitive<D>,
+        src: usize,
+        dst: usize,
+    ) -> Self::Primitive<D> {
+        let mut axes: Vec<usize> = (0..D).filter(|&axis| axis != src).collect();
+        axes.insert(dst, src);
+
+        Self::permute(tensor, axes.try_into().unwrap())
+    }
+
     /// Flips the tensor along the given axes.
     ///
     /// # Arguments
@@ -1523,6 +1665,40 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D>;
 
+    /// Concatenates the given tensors along a new dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `vectors` - The tensors to stack.
+    /// * `dim` - The new dimension along which the tensors will be stacked.
+    ///
+    /// # Returns
+    ///
+    /// The stacked tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For stacking tensors, users should prefer the [Tensor::stack](Tensor::stack) function,
+    /// which is more high-level and designed for public use.
+    fn stack<const D: usize, const D2: usize>(
+        vectors: Vec<Self::Primitive<D>>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        let vectors = vectors
+            .into_iter()
+            .map(|tensor| {
+                let shape = Self::shape(&tensor);
+                Self::reshape(tensor, unsqueeze_shape::<D, D2>(&shape, dim))
+            })
+            .collect();
+
+        Self::cat(vectors, dim)
+    }
+
     /// Equates the given tensors.
     ///
     /// # Arguments
@@ -1880,6 +2056,13 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_cat(vectors, dim)
     }
 
+    fn stack<const D: usize, const D2: usize>(
+        vectors: Vec<Self::Primitive<D>>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::int_stack(vectors, dim)
+    }
+
     fn any<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
         Tensor::new(B::int_any(tensor))
     }
@@ -1900,6 +2083,36 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_permute(tensor, axes)
     }
 
+    fn flatten<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        start_dim: usize,
+        end_dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::int_flatten(tensor, start_dim, end_dim)
+    }
+
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::int_squeeze(tensor, dim)
+    }
+
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::int_unsqueeze(tensor, dim)
+    }
+
+    fn movedim<const D: usize>(
+        tensor: Self::Primitive<D>,
+        src: usize,
+        dst: usize,
+    ) -> Self::Primitive<D> {
+        B::int_movedim(tensor, src, dst)
+    }
+
     fn expand<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
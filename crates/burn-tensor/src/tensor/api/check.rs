@@ -1,4 +1,4 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, tensor::api::diagonal_len, BasicOps, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -319,6 +319,17 @@ impl TensorCheck {
             );
         }
 
+        if start_dim <= end_dim && D2 > D1 - (end_dim - start_dim) {
+            check = check.register(
+                "Flatten",
+                TensorError::new(format!(
+                    "The destination dimension ({D2}) must match the number of dimensions left \
+                     after flattening ({})",
+                    D1 - (end_dim - start_dim)
+                )),
+            );
+        }
+
         check
     }
 
@@ -443,10 +454,188 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn one_hot_rank<const D1: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D1 + 1 {
+            check = check.register(
+                "One Hot",
+                TensorError::new("The one hot tensor must have one more dimension than the indices.")
+                    .details(format!(
+                        "Can't create a one hot tensor of ({D2}) dimensions from indices of ({D1}) dimensions."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn one_hot_indices(
+        has_negative: bool,
+        has_out_of_range: bool,
+        num_classes: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if has_negative {
+            check = check.register(
+                "One Hot",
+                TensorError::new("Can't create a one hot tensor from negative indices."),
+            );
+        }
+
+        if has_out_of_range {
+            check = check.register(
+                "One Hot",
+                TensorError::new(format!(
+                    "Can't create a one hot tensor with indices greater or equal to the number of classes ({num_classes})",
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn transpose<const D: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                "Transpose",
+                TensorError::new(
+                    "The transpose operation requires a tensor with at least 2 dimensions",
+                )
+                .details(format!(
+                    "The last two dimensions are swapped, but the tensor has ({D}) dimensions."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn dot(lhs: &Shape<1>, rhs: &Shape<1>) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs.dims[0] != rhs.dims[0] {
+            check = check.register(
+                "Dot",
+                TensorError::new("The vectors must have the same length").details(format!(
+                    "Lhs has a length of {}, but rhs has a length of {}.",
+                    lhs.dims[0], rhs.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn roll_dims<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if shifts.len() != dims.len() {
+            check = check.register(
+                "Roll Dims",
+                TensorError::new("There should be one shift per rolled dimension").details(
+                    format!(
+                        "{} shifts were provided for {} dimensions.",
+                        shifts.len(),
+                        dims.len()
+                    ),
+                ),
+            );
+        }
+
+        for (i, &dim) in dims.iter().enumerate() {
+            if dim >= D {
+                check = check.register(
+                    "Roll Dims",
+                    TensorError::new(
+                        "The roll dimensions must be smaller than the tensor dimension",
+                    )
+                    .details(format!("Roll dim ({dim}) on tensor with ({D}) dimensions.")),
+                );
+            } else if dims[..i].contains(&dim) {
+                check = check.register(
+                    "Roll Dims",
+                    TensorError::new("The roll dimensions must be distinct")
+                        .details(format!("Dim ({dim}) is rolled more than once.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn roll_batched(shape: &Shape<2>, shape_shifts: &Shape<1>, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= 2 {
+            check = check.register(
+                "Roll Batched",
+                TensorError::new("The roll dimension must be smaller than the tensor dimension")
+                    .details(format!("Roll dim ({dim}) on tensor with (2) dimensions.")),
+            );
+        } else if shape.dims[1 - dim] != shape_shifts.dims[0] {
+            check = check.register(
+                "Roll Batched",
+                TensorError::new("There should be one shift per batch entry").details(format!(
+                    "The batch dimension ({}) has size {}, but {} shifts were provided.",
+                    1 - dim,
+                    shape.dims[1 - dim],
+                    shape_shifts.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn select_ranges<const D: usize>(
+        shape: &Shape<D>,
+        dim: usize,
+        ranges: &[Range<usize>],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            return check.register(
+                "Select Ranges",
+                TensorError::new("The select dimension must be smaller than the tensor dimension")
+                    .details(format!(
+                        "Select dim ({dim}) on tensor with ({D}) dimensions."
+                    )),
+            );
+        }
+
+        if ranges.is_empty() {
+            check = check.register(
+                "Select Ranges",
+                TensorError::new("Can't select an empty list of ranges."),
+            );
+        }
+
+        for range in ranges {
+            if range.start > range.end || range.end > shape.dims[dim] {
+                check = check.register(
+                    "Select Ranges",
+                    TensorError::new("The provided range is invalid for the select dimension.")
+                        .details(format!(
+                            "The range ({}..{}) must be increasing and within the size ({}) of \
+                             dimension {dim}.",
+                            range.start, range.end, shape.dims[dim]
+                        )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim1 > D || dim2 > D {
+        if dim1 >= D || dim2 >= D {
             check = check.register(
                 "Swap Dims",
                 TensorError::new("The swap dimensions must be smaller than the tensor dimension")
@@ -797,6 +986,126 @@ impl TensorCheck {
         Self::check_gather_scatter_indices(Self::Ok, "Gather", dim, shape, shape_indices)
     }
 
+    pub(crate) fn diagonal_scatter<const D: usize, const D2: usize>(
+        shape: &Shape<D>,
+        shape_src: &Shape<D2>,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self {
+        let ops = "Diagonal scatter";
+        let mut check = Self::Ok;
+
+        if dim1 >= D || dim2 >= D || dim1 == dim2 {
+            return check.register(
+                ops,
+                TensorError::new("The diagonal dimensions must be distinct and smaller than the tensor dimension.")
+                    .details(format!("Diagonal dims ({dim1}, {dim2}) on tensor with ({D}) dimensions.")),
+            );
+        }
+
+        let mut expected = (0..D)
+            .filter(|&d| d != dim1 && d != dim2)
+            .map(|d| shape.dims[d])
+            .collect::<Vec<_>>();
+        expected.push(diagonal_len(shape.dims[dim1], shape.dims[dim2], offset));
+
+        if expected.as_slice() != shape_src.dims.as_slice() {
+            check = check.register(
+                ops,
+                TensorError::new("The source tensor shape doesn't match the diagonal shape.")
+                    .details(format!(
+                    "The diagonal with offset ({offset}) of dims ({dim1}, {dim2}) has the shape \
+                         {:?}, but the source tensor shape is {:?}.",
+                    expected, shape_src.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn gather_masked<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+        shape_valid: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::gather::<D>(dim, shape, shape_indices);
+
+        if shape_indices != shape_valid {
+            check = check.register(
+                "Gather masked",
+                TensorError::new("The validity mask should have the same shape as the indices.")
+                    .details(format!(
+                        "The index tensor shape is {:?}, the validity mask shape is {:?}.",
+                        shape_indices.dims, shape_valid.dims,
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn gather_broadcast<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+    ) -> Self {
+        Self::broadcast_indices("Gather broadcast", dim, shape, shape_indices)
+    }
+
+    pub(crate) fn take_along_dim<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+    ) -> Self {
+        Self::broadcast_indices("Take along dim", dim, shape, shape_indices)
+    }
+
+    fn broadcast_indices<const D: usize>(
+        ops: &str,
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't index a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        for i in 0..D {
+            if i == dim {
+                continue;
+            }
+
+            let tensor_dim_i = shape.dims[i];
+            let indices_dim_i = shape_indices.dims[i];
+
+            if tensor_dim_i != indices_dim_i && indices_dim_i != 1 {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The index tensor can't be broadcasted to the tensor shape.".to_string(),
+                    )
+                    .details(format!(
+                        "Incompatible size at dimension {i}: {indices_dim_i} can't be \
+                         broadcasted to {tensor_dim_i}. Tensor shape {:?}, indices shape {:?}.",
+                        shape.dims, shape_indices.dims,
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn scatter<const D: usize>(
         dim: usize,
         shape: &Shape<D>,
@@ -815,8 +1124,8 @@ impl TensorCheck {
                         .to_string(),
                 )
                 .details(format!(
-                    "The shape differs: {:?} != {:?}",
-                    shape_indices.dims, shape_value.dims
+                    "The shape differs: {:?} != {:?}. Tensor shape {:?}.",
+                    shape_indices.dims, shape_value.dims, shape.dims
                 )),
             );
         }
@@ -832,6 +1141,39 @@ impl TensorCheck {
         Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
     }
 
+    pub(crate) fn select_assign_values<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        num_indices: usize,
+        shape_value: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            return check;
+        }
+
+        let mut shape_expected = shape.clone();
+        shape_expected.dims[dim] = num_indices;
+
+        if &shape_expected != shape_value {
+            check = check.register(
+                "select_assign",
+                TensorError::new(
+                    "The value tensor shape should be the same as the tensor shape, with the \
+                     selected dimension replaced by the number of indices.",
+                )
+                .details(format!(
+                    "Expected value shape {:?}, got {:?}. Tensor shape {:?}, {} indices on \
+                     dimension {}.",
+                    shape_expected.dims, shape_value.dims, shape.dims, num_indices, dim
+                )),
+            );
+        }
+
+        check
+    }
+
     fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
         if dim > D {
             check = check.register(
@@ -939,6 +1281,242 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn sum_grouped(shape: &Shape<1>, group_size: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if group_size == 0 || !shape.dims[0].is_multiple_of(group_size) {
+            check = check.register(
+                "Sum Grouped",
+                TensorError::new("The tensor length must be divisible by the group size.").details(
+                    format!(
+                        "Can't split a tensor of length ({}) into groups of size ({group_size}).",
+                        shape.dims[0]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantile(num_elements: usize, q: f32) -> Self {
+        let mut check = Self::Ok;
+
+        if num_elements == 0 {
+            check = check.register(
+                "Quantile",
+                TensorError::new("Can't compute the quantile of an empty tensor."),
+            );
+        }
+
+        if !(0.0..=1.0).contains(&q) {
+            check = check.register(
+                "Quantile",
+                TensorError::new("The quantile must be in the range [0, 1].")
+                    .details(format!("The provided quantile is ({q}).")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn clamp_tensor<const D: usize>(
+        shape: &Shape<D>,
+        shape_min: &Shape<D>,
+        shape_max: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (name, shape_bound) in [("min", shape_min), ("max", shape_max)] {
+            let broadcastable = shape
+                .dims
+                .iter()
+                .zip(shape_bound.dims.iter())
+                .all(|(size, size_bound)| size == size_bound || *size_bound == 1);
+
+            if !broadcastable {
+                check = check.register(
+                    "Clamp Tensor",
+                    TensorError::new(format!(
+                        "The {name} tensor can't be broadcast to the shape of the tensor."
+                    ))
+                    .details(format!(
+                        "The {name} tensor has the shape {:?}, while the tensor has the shape {:?}.",
+                        shape_bound.dims, shape.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn remainder_divisor(has_zero: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if has_zero {
+            check = check.register(
+                "Remainder",
+                TensorError::new("Can't compute the remainder of a division by zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn isqrt_values(has_negative: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if has_negative {
+            check = check.register(
+                "Isqrt",
+                TensorError::new("Can't compute the integer square root of negative values."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pow_exponents(has_negative: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if has_negative {
+            check = check.register(
+                "Pow",
+                TensorError::new("Can't raise integers to negative powers."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn topk<const D: usize>(shape: &Shape<D>, k: usize, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                "TopK",
+                TensorError::new(format!(
+                    "Can't select the top elements of a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        } else if k > shape.dims[dim] {
+            check = check.register(
+                "TopK",
+                TensorError::new(
+                    "The number of selected elements must not exceed the dimension size.",
+                )
+                .details(format!(
+                    "Can't select ({k}) elements on axis ({dim}) of size ({}).",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn masked_select<const D: usize>(shape: &Shape<D>, shape_mask: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "Masked select",
+                TensorError::new("The mask should have the same shape as the tensor.").details(
+                    format!(
+                        "The tensor shape is {:?}, the mask shape is {:?}.",
+                        shape.dims, shape_mask.dims,
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn where_cond<const D: usize>(
+        shape_condition: &Shape<D>,
+        shape_true: &Shape<D>,
+        shape_false: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_condition != shape_true || shape_condition != shape_false {
+            check = check.register(
+                "Where",
+                TensorError::new("The condition and both branches should have the same shape.")
+                    .details(format!(
+                        "The condition shape is {:?}, the true branch shape is {:?}, the false \
+                         branch shape is {:?}.",
+                        shape_condition.dims, shape_true.dims, shape_false.dims,
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn split_sizes<const D: usize>(
+        shape: &Shape<D>,
+        split_sizes: &[usize],
+        dim: usize,
+    ) -> Self {
+        let mut check = Self::dim_ops::<D>("Split", dim);
+
+        if dim < D {
+            let total: usize = split_sizes.iter().sum();
+
+            if total != shape.dims[dim] {
+                check = check.register(
+                    "Split",
+                    TensorError::new("The split sizes must sum to the size of the dimension.")
+                        .details(format!(
+                        "The split sizes {split_sizes:?} sum to ({total}), but dimension ({dim}) \
+                             has a size of ({}).",
+                        shape.dims[dim]
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn diff<const D: usize>(shape: &Shape<D>, dim: usize, n: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Diff", dim);
+
+        if dim < D && n > shape.dims[dim] {
+            check = check.register(
+                "Diff",
+                TensorError::new(
+                    "The order of the difference can't exceed the size of the dimension.",
+                )
+                .details(format!(
+                    "Can't compute the difference of order ({n}) along dimension ({dim}) of \
+                         size ({}).",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn tile<const D: usize>(reps: &[usize; D]) -> Self {
+        let mut check = Self::Ok;
+
+        if reps.contains(&0) {
+            check = check.register(
+                "Tile",
+                TensorError::new("The number of repetitions must be positive.").details(format!(
+                    "Can't tile a tensor with the repetitions {reps:?}."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn sort_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
 
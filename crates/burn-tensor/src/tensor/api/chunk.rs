@@ -12,7 +12,10 @@ use alloc::vec::Vec;
 ///
 /// # Returns
 ///
-/// A vectors of tensors
+/// A vectors of tensors, where every chunk has a size of `ceil(size / chunks)` except the last one,
+/// which holds the remainder. Like `torch.chunk`, fewer chunks than requested are returned when
+/// the size can't be split that way, e.g. a size of 6 split into 4 chunks gives sizes `[2, 2, 2]`,
+/// and a size smaller than `chunks` gives one chunk per element.
 ///
 /// # Remarks
 ///
@@ -26,44 +29,16 @@ pub fn chunk<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
     dim: usize,
 ) -> Vec<K::Primitive<D>> {
     let size = K::shape(&tensor).dims[dim];
-    if size < chunks {
-        return (0..size)
-            .map(|i| narrow::<B, D, K>(tensor.clone(), dim, i, 1))
-            .collect();
+    if size == 0 {
+        return Vec::new();
     }
 
-    let mut tensors = Vec::with_capacity(chunks);
-    let mut sum_chunk_size = 0;
-    if size % chunks == 0 {
-        let chunk_size = size / chunks;
-        for _ in 0..chunks {
-            tensors.push(narrow::<B, D, K>(
-                tensor.clone(),
-                dim,
-                sum_chunk_size,
-                chunk_size,
-            ));
-            sum_chunk_size += chunk_size;
-        }
-    } else {
-        let chunk_size = (size / chunks) + 1; // assumes not divisible
-        for _ in 0..chunks - 1 {
-            tensors.push(narrow::<B, D, K>(
-                tensor.clone(),
-                dim,
-                sum_chunk_size,
-                chunk_size,
-            ));
-            sum_chunk_size += chunk_size;
-        }
-        let remainder = size % chunk_size;
-        tensors.push(narrow::<B, D, K>(
-            tensor.clone(),
-            dim,
-            sum_chunk_size,
-            remainder,
-        ));
-    }
-
-    tensors
+    let chunk_size = size.div_ceil(chunks);
+    (0..size)
+        .step_by(chunk_size)
+        .map(|start| {
+            let length = chunk_size.min(size - start);
+            narrow::<B, D, K>(tensor.clone(), dim, start, length)
+        })
+        .collect()
 }
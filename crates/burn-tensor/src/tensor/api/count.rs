@@ -0,0 +1,321 @@
+use crate::{
+    backend::Backend,
+    ops::{IntElem, IntTensor},
+    ElementConversion, TensorData,
+};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{for_each_lane, into_int_data};
+
+/// Computes the most frequent value of the input `tensor` and the number of times it appears.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// A tuple of single element tensors containing the most frequent value and its count. When
+/// several values appear the same number of times, the smallest one is returned.
+///
+/// # Panics
+///
+/// If the tensor is empty.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn mode_flat<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+) -> (IntTensor<B, 1>, IntTensor<B, 1>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    let mut counts = BTreeMap::<i64, usize>::new();
+    for value in data.iter::<i64>() {
+        *counts.entry(value).or_default() += 1;
+    }
+
+    // The values are visited in ascending order, so only a strictly greater count replaces the
+    // current mode and ties are resolved to the smallest value.
+    let (value, count) = counts
+        .into_iter()
+        .fold(
+            None,
+            |mode: Option<(i64, usize)>, (value, count)| match mode {
+                Some((_, max_count)) if count <= max_count => mode,
+                _ => Some((value, count)),
+            },
+        )
+        .expect("Can't compute the mode of an empty tensor");
+
+    (
+        B::int_from_data(
+            TensorData::new(vec![value.elem::<IntElem<B>>()], [1]),
+            &device,
+        ),
+        B::int_from_data(
+            TensorData::new(vec![(count as i64).elem::<IntElem<B>>()], [1]),
+            &device,
+        ),
+    )
+}
+
+/// Computes the most frequent value of the input `tensor` along a given dimension, and the index
+/// of its first occurrence.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the mode.
+///
+/// # Returns
+///
+/// A tuple of tensors with the same shape as the input tensor, except along `dim` where the size
+/// is 1, containing the most frequent values and their indices. When several values appear the
+/// same number of times, the smallest one is returned.
+///
+/// # Panics
+///
+/// If the size of `dim` is zero.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn mode_dim<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+) -> (IntTensor<B, D>, IntTensor<B, D>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let values = data.iter::<i64>().collect::<Vec<_>>();
+
+    let mut shape = data.shape.clone();
+    assert!(
+        shape[dim] > 0,
+        "Can't compute the mode along a dimension of size 0"
+    );
+    shape[dim] = 1;
+
+    let num_lanes = shape.iter().product();
+    let mut modes = Vec::with_capacity(num_lanes);
+    let mut indices = Vec::with_capacity(num_lanes);
+    let mut lane_values = Vec::new();
+
+    for_each_lane(&data.shape, dim, |lane| {
+        // Sorting the (value, index) pairs groups equal values in runs that start at their first
+        // occurrence, and visits the runs in ascending order so that ties keep the smallest value.
+        lane_values.clear();
+        lane_values.extend(lane.iter().enumerate().map(|(i, &flat)| (values[flat], i)));
+        lane_values.sort_unstable();
+
+        let mut mode = lane_values[0];
+        let mut max_count = 0;
+        for run in lane_values.chunk_by(|a, b| a.0 == b.0) {
+            if run.len() > max_count {
+                max_count = run.len();
+                mode = run[0];
+            }
+        }
+
+        modes.push(mode.0);
+        indices.push(mode.1 as i64);
+    });
+
+    (
+        B::int_from_data(into_int_data::<B>(modes, shape.clone()), &device),
+        B::int_from_data(into_int_data::<B>(indices, shape), &device),
+    )
+}
+
+/// Counts the number of occurrences of each value of the input `tensor`, with at most
+/// `maxlength` bins.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor of non-negative values.
+/// * `maxlength` - The maximum number of bins.
+/// * `clamp` - Whether the values greater or equal to `maxlength` are counted in the last bin
+///   instead of being dropped.
+///
+/// # Returns
+///
+/// A tensor of `min(max + 1, maxlength)` bins, where `max` is the maximum value of the tensor.
+///
+/// # Panics
+///
+/// If the tensor contains negative values or if `maxlength` is zero.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn bincount_capped<B: Backend>(
+    tensor: IntTensor<B, 1>,
+    maxlength: usize,
+    clamp: bool,
+) -> IntTensor<B, 1> {
+    assert!(
+        maxlength > 0,
+        "The maximum number of bins should be positive"
+    );
+
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let values = data.iter::<i64>().collect::<Vec<_>>();
+
+    if let Some(value) = values.iter().find(|&&value| value < 0) {
+        panic!("Can't count the occurrences of negative values, got {value}");
+    }
+
+    let max = values
+        .iter()
+        .max()
+        .map(|&max| max as usize + 1)
+        .unwrap_or(0);
+    let num_bins = max.min(maxlength);
+
+    let mut bins = vec![0i64; num_bins];
+    for value in values {
+        let value = value as usize;
+        if value < num_bins {
+            bins[value] += 1;
+        } else if clamp {
+            bins[num_bins - 1] += 1;
+        }
+    }
+
+    let bins = bins
+        .into_iter()
+        .map(|count| count.elem::<IntElem<B>>())
+        .collect::<Vec<_>>();
+
+    B::int_from_data(TensorData::new(bins, [num_bins]), &device)
+}
+
+/// Counts the number of occurrences of each value of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor of non-negative values.
+/// * `num_bins` - The number of bins, or `None` to use `max + 1` bins, where `max` is the
+///   maximum value of the tensor.
+///
+/// # Returns
+///
+/// A tensor of `num_bins` bins, where bin `i` contains the number of occurrences of the value `i`.
+///
+/// # Panics
+///
+/// If the tensor contains negative values or if `num_bins` is smaller than `max + 1`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn bincount<B: Backend>(tensor: IntTensor<B, 1>, num_bins: Option<usize>) -> IntTensor<B, 1> {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let values = data.iter::<i64>().collect::<Vec<_>>();
+
+    if let Some(value) = values.iter().find(|&&value| value < 0) {
+        panic!("Can't count the occurrences of negative values, got {value}");
+    }
+
+    let max = values
+        .iter()
+        .max()
+        .map(|&max| max as usize + 1)
+        .unwrap_or(0);
+    let num_bins = match num_bins {
+        Some(num_bins) => {
+            assert!(
+                num_bins >= max,
+                "The number of bins ({num_bins}) should be at least the maximum value plus one ({max})"
+            );
+            num_bins
+        }
+        None => max,
+    };
+
+    let mut bins = vec![0i64; num_bins];
+    for value in values {
+        bins[value as usize] += 1;
+    }
+
+    let bins = bins
+        .into_iter()
+        .map(|count| count.elem::<IntElem<B>>())
+        .collect::<Vec<_>>();
+
+    B::int_from_data(TensorData::new(bins, [num_bins]), &device)
+}
+
+/// Computes the distinct values of the input `tensor` and the number of times each one appears.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `sorted` - Whether the distinct values are returned in ascending order instead of the order
+///   of their first appearance.
+///
+/// # Returns
+///
+/// A tuple of 1D tensors containing the distinct values and their counts.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn unique_with_counts<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    sorted: bool,
+) -> (IntTensor<B, 1>, IntTensor<B, 1>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    // Maps each distinct value to its position in the order of first appearance.
+    let mut positions = BTreeMap::<i64, usize>::new();
+    let mut uniques = Vec::<(i64, i64)>::new();
+    for value in data.iter::<i64>() {
+        let position = *positions.entry(value).or_insert_with(|| {
+            uniques.push((value, 0));
+            uniques.len() - 1
+        });
+        uniques[position].1 += 1;
+    }
+
+    if sorted {
+        uniques.sort_unstable_by_key(|&(value, _)| value);
+    }
+
+    let num_uniques = uniques.len();
+    let (values, counts): (Vec<_>, Vec<_>) = uniques
+        .into_iter()
+        .map(|(value, count)| (value.elem::<IntElem<B>>(), count.elem::<IntElem<B>>()))
+        .unzip();
+
+    (
+        B::int_from_data(TensorData::new(values, [num_uniques]), &device),
+        B::int_from_data(TensorData::new(counts, [num_uniques]), &device),
+    )
+}
@@ -0,0 +1,98 @@
+use crate::{backend::Backend, ops::IntTensor, Device};
+use alloc::vec::Vec;
+
+use super::into_int_data;
+
+/// Writes the values of `src` onto the diagonal of the input `tensor` with the given `offset`,
+/// over the dimensions `dim1` and `dim2`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `src` - The diagonal values, with the shape of the input tensor without `dim1` and `dim2`,
+///   followed by the length of the diagonal.
+/// * `offset` - The offset of the diagonal, above the main diagonal when positive and below it
+///   when negative.
+/// * `dim1` - The first dimension of the diagonal.
+/// * `dim2` - The second dimension of the diagonal.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, with the diagonal replaced by `src`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn diagonal_scatter<B: Backend, const D: usize, const D2: usize>(
+    tensor: IntTensor<B, D>,
+    src: IntTensor<B, D2>,
+    offset: i64,
+    dim1: usize,
+    dim2: usize,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let data_src = B::int_into_data(src).read();
+
+    let mut values = data.iter::<i64>().collect::<Vec<_>>();
+    let shape = data.shape;
+    let shape_src = data_src.shape.clone();
+
+    let mut strides = [1; D];
+    for d in (0..D - 1).rev() {
+        strides[d] = strides[d + 1] * shape[d + 1];
+    }
+    // The other dimensions of the tensor, in the order of the leading dimensions of `src`.
+    let others = (0..D)
+        .filter(|&d| d != dim1 && d != dim2)
+        .collect::<Vec<_>>();
+    let start1 = (-offset).max(0) as usize;
+    let start2 = offset.max(0) as usize;
+
+    for (position, value) in data_src.iter::<i64>().enumerate() {
+        let mut rest = position;
+        let k = rest % shape_src[D2 - 1];
+        rest /= shape_src[D2 - 1];
+
+        let mut target = (k + start1) * strides[dim1] + (k + start2) * strides[dim2];
+        for (i, &d) in others.iter().enumerate().rev() {
+            target += (rest % shape_src[i]) * strides[d];
+            rest /= shape_src[i];
+        }
+
+        values[target] = value;
+    }
+
+    B::int_from_data(into_int_data::<B>(values, shape), &device)
+}
+
+/// Returns the length of the diagonal with the given `offset` of a matrix of `rows` by `cols`.
+pub(crate) fn diagonal_len(rows: usize, cols: usize, offset: i64) -> usize {
+    match offset >= 0 {
+        true => cols.saturating_sub(offset as usize).min(rows),
+        false => rows
+            .saturating_sub(offset.unsigned_abs() as usize)
+            .min(cols),
+    }
+}
+
+/// Returns the flat indices of the first `len` elements of the diagonal with the given `offset` of
+/// a row-major matrix with `cols` columns.
+pub(crate) fn diagonal_flat_indices<B: Backend>(
+    cols: usize,
+    offset: i64,
+    len: usize,
+    device: &Device<B>,
+) -> IntTensor<B, 1> {
+    let start = match offset >= 0 {
+        true => offset as usize,
+        false => offset.unsigned_abs() as usize * cols,
+    };
+    let end = start + len * (cols + 1);
+
+    B::int_arange_step(start as i64..end as i64, cols + 1, device)
+}
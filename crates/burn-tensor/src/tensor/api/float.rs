@@ -266,6 +266,23 @@ where
             .div_scalar(n as f32 - correction_factor as f32)
     }
 
+    /// Draw `num_samples` indices along the last dimension, with probabilities proportional to
+    /// the non-negative weights of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of indices to draw for each entry of the other dimensions.
+    /// * `replacement` - Whether an index can be drawn several times.
+    ///
+    /// # Panics
+    ///
+    /// If the weights are negative, if they sum to zero, or if more samples than non-zero weights
+    /// are drawn without replacement.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        Tensor::new(B::int_multinomial(self.primitive, num_samples, replacement))
+    }
+
     /// Sort the elements by value in ascending order along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -0,0 +1,92 @@
+use crate::{backend::Backend, ops::IntTensor};
+use alloc::vec::Vec;
+
+use super::integer::broadcast_into_data;
+use super::into_int_data;
+
+/// Computes the element-wise greatest common divisor of the `lhs` and `rhs` tensors.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side tensor, broadcastable with `lhs`.
+///
+/// # Returns
+///
+/// A tensor with the broadcast shape of the inputs containing the non-negative greatest common
+/// divisors, where the greatest common divisor of two zeros is zero.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn gcd<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+) -> IntTensor<B, D> {
+    map_pairs::<B, D>(lhs, rhs, |a, b| gcd_u64(a, b) as i64)
+}
+
+/// Computes the element-wise least common multiple of the `lhs` and `rhs` tensors.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side tensor, broadcastable with `lhs`.
+///
+/// # Returns
+///
+/// A tensor with the broadcast shape of the inputs containing the non-negative least common
+/// multiples, where the least common multiple with a zero operand is zero.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn lcm<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+) -> IntTensor<B, D> {
+    map_pairs::<B, D>(lhs, rhs, |a, b| {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            (a / gcd_u64(a, b)).wrapping_mul(b) as i64
+        }
+    })
+}
+
+/// Broadcasts both tensors to a common shape and applies `func` to the absolute values of each
+/// pair of elements.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn map_pairs<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    func: impl Fn(u64, u64) -> i64,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&lhs);
+    let (lhs, rhs) = broadcast_into_data::<B, D>(lhs, rhs);
+
+    let values = lhs
+        .iter::<i64>()
+        .zip(rhs.iter::<i64>())
+        .map(|(a, b)| func(a.unsigned_abs(), b.unsigned_abs()))
+        .collect::<Vec<_>>();
+
+    B::int_from_data(into_int_data::<B>(values, lhs.shape), &device)
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
@@ -1,59 +1,2219 @@
-use crate::{backend::Backend, Float, Int, Shape, Tensor, TensorData};
+use crate::{
+    backend::Backend, check, check::TensorCheck, Bool, ElementConversion, Float, Int,
+    IntDistribution, Shape, Tensor, TensorData,
+};
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::ops::IntTensorDiff;
+
+use crate::ops::{ArangeError, ClampError, WideIndices};
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::ops::{OverflowError, OverflowMode, ReduceKind, ScanOp};
+use alloc::vec::Vec;
 use core::ops::Range;
 
-#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
-use crate::{argsort, check, check::TensorCheck, sort, sort_with_indices};
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+use crate::{argsort, sort, sort_with_indices};
+
+impl<B> Tensor<B, 1, Int>
+where
+    B: Backend,
+{
+    /// Builds a square matrix with the values of the tensor on the diagonal with the given
+    /// `offset`, and zeros elsewhere.
+    ///
+    /// The matrix has size `n + |offset|`, where `n` is the number of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2], &device);
+    ///     println!("{}", tensor.diag_embed(-1)); // [[0, 0, 0], [1, 0, 0], [0, 2, 0]]
+    /// }
+    /// ```
+    pub fn diag_embed(self, offset: i64) -> Tensor<B, 2, Int> {
+        Tensor::new(B::int_diag_embed(self.primitive, offset))
+    }
+
+    /// Computes the dot product with the `other` vector, returned as a tensor with a single
+    /// element.
+    ///
+    /// # Panics
+    ///
+    /// If the vectors don't have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, 2, 3], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([4, 5, 6], &device);
+    ///     println!("{}", lhs.dot(rhs)); // [32]
+    /// }
+    /// ```
+    pub fn dot(self, other: Self) -> Self {
+        check!(TensorCheck::dot(&self.shape(), &other.shape()));
+        Tensor::new(B::int_dot(self.primitive, other.primitive))
+    }
+
+    /// Computes the outer product with the `other` vector.
+    ///
+    /// The element at `[i, j]` of the output is `self[i] * other[j]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, 2], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 4, 5], &device);
+    ///     println!("{}", lhs.outer(rhs)); // [[3, 4, 5], [6, 8, 10]]
+    /// }
+    /// ```
+    pub fn outer(self, other: Self) -> Tensor<B, 2, Int> {
+        Tensor::new(B::int_outer(self.primitive, other.primitive))
+    }
+
+    /// Returns a new integer tensor on the specified device.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values to generate.
+    /// * `device` - The device to create the tensor on.
+    pub fn arange(range: Range<i64>, device: &B::Device) -> Self {
+        Tensor::new(B::int_arange(range, device))
+    }
+
+    /// Returns a new integer tensor on the specified device.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values to generate.
+    /// * `step` - The step between each value.
+    pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
+        Tensor::new(B::int_arange_step(range, step, device))
+    }
+
+    /// Returns a new integer tensor on the specified device, or an error if the arguments are
+    /// invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values to generate.
+    /// * `step` - The step between each value, which must not be zero.
+    /// * `reject_empty` - Whether a range producing no value (e.g. `5..0`) is an error instead of
+    ///   an empty tensor.
+    /// * `device` - The device to create the tensor on.
+    pub fn arange_step_checked(
+        range: Range<i64>,
+        step: usize,
+        reject_empty: bool,
+        device: &B::Device,
+    ) -> Result<Self, ArangeError> {
+        B::int_arange_step_checked(range, step, reject_empty, device).map(Tensor::new)
+    }
+
+    /// Returns a new integer tensor on the specified device, with a signed step.
+    ///
+    /// A negative step generates a descending sequence from `range.start` down to `range.end`,
+    /// which is excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values to generate.
+    /// * `step` - The step between each value.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Panics
+    ///
+    /// If the step is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::arange_step_signed(10..0, -2, &device);
+    ///     println!("{}", tensor); // [10, 8, 6, 4, 2]
+    /// }
+    /// ```
+    pub fn arange_step_signed(range: Range<i64>, step: i64, device: &B::Device) -> Self {
+        Tensor::new(B::int_arange_step_signed(range, step, device))
+    }
+
+    /// Creates a 1D tensor from a slice of values of the int element type of the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{ElementConversion, Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let values = [1.elem::<B::IntElem>(), 2.elem(), 3.elem()];
+    ///     let tensor = Tensor::<B, 1, Int>::from_slice(&values, &device);
+    ///     println!("{}", tensor); // [1, 2, 3]
+    /// }
+    /// ```
+    pub fn from_slice(values: &[B::IntElem], device: &B::Device) -> Self {
+        Tensor::new(B::int_from_slice(values, device))
+    }
+
+    /// Returns a random permutation of the integers from `0` to `n - 1`.
+    ///
+    /// The permutation is drawn from the random number generator of the backend, so it is
+    /// reproducible after seeding the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let indices = Tensor::<B, 1, Int>::randperm(5, &device);
+    ///     println!("{}", indices); // e.g. [3, 0, 4, 1, 2]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn randperm(n: usize, device: &B::Device) -> Self {
+        Tensor::new(B::int_randperm(n, device))
+    }
+
+    /// Counts the number of occurrences of each value.
+    ///
+    /// The output has `num_bins` bins, or `max + 1` bins when `num_bins` is `None`, and bin `i`
+    /// contains the number of occurrences of the value `i`.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains negative values or if `num_bins` is smaller than `max + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let labels = Tensor::<B, 1, Int>::from_ints([0, 1, 1, 3], &device);
+    ///     let counts = labels.bincount(None);
+    ///     println!("{}", counts); // [1, 2, 0, 1]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn bincount(self, num_bins: Option<usize>) -> Self {
+        Tensor::new(B::int_bincount(self.primitive, num_bins))
+    }
+
+    /// Counts the number of occurrences of each value, with at most `maxlength` bins.
+    ///
+    /// The values greater or equal to `maxlength` are counted in the last bin when `clamp` is
+    /// `true`, and dropped otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains negative values or if `maxlength` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let labels = Tensor::<B, 1, Int>::from_ints([0, 1, 1, 1000000], &device);
+    ///     let counts = labels.bincount_capped(3, false);
+    ///     println!("{}", counts); // [1, 2, 0]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn bincount_capped(self, maxlength: usize, clamp: bool) -> Self {
+        Tensor::new(B::int_bincount_capped(self.primitive, maxlength, clamp))
+    }
+
+    /// Sums the consecutive groups of `group_size` elements of the tensor.
+    ///
+    /// This is equivalent to reshaping the tensor into `[N / group_size, group_size]` and
+    /// summing along the last dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor length is not divisible by `group_size`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3, 4, 5, 6], &device);
+    ///     let sums = tensor.sum_grouped(2);
+    ///     println!("{}", sums); // [3, 7, 11]
+    /// }
+    /// ```
+    pub fn sum_grouped(self, group_size: usize) -> Self {
+        check!(TensorCheck::sum_grouped(&self.shape(), group_size));
+
+        Tensor::new(B::int_sum_grouped(self.primitive, group_size))
+    }
+
+    /// Finds the indices where the elements of `values` should be inserted to keep the tensor
+    /// sorted.
+    ///
+    /// With `right` set to `false`, the index of the first suitable position is returned, such
+    /// that `self[i - 1] < value <= self[i]`. With `right` set to `true`, the index of the last
+    /// suitable position is returned, such that `self[i - 1] <= value < self[i]`.
+    ///
+    /// The tensor must be sorted in ascending order, otherwise the result is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let boundaries = Tensor::<B, 1, Int>::from_ints([1, 3, 5], &device);
+    ///     let values = Tensor::<B, 1, Int>::from_ints([0, 3, 6], &device);
+    ///     let buckets = boundaries.searchsorted(values, false);
+    ///     println!("{}", buckets); // [0, 1, 3]
+    /// }
+    /// ```
+    pub fn searchsorted<const D2: usize>(
+        self,
+        values: Tensor<B, D2, Int>,
+        right: bool,
+    ) -> Tensor<B, D2, Int> {
+        Tensor::new(B::int_searchsorted(self.primitive, values.primitive, right))
+    }
+}
+
+impl<B> Tensor<B, 2, Int>
+where
+    B: Backend,
+{
+    /// Creates a rectangular identity matrix with `rows` rows and `cols` columns.
+    ///
+    /// The element at `[i, j]` is one if `i == j` and zero otherwise. Use
+    /// [eye](Tensor::eye) for square matrices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::eye_rect(2, 3, &device);
+    ///     println!("{}", tensor); // [[1, 0, 0], [0, 1, 0]]
+    /// }
+    /// ```
+    pub fn eye_rect(rows: usize, cols: usize, device: &B::Device) -> Self {
+        Tensor::new(B::int_eye_rect(rows, cols, device))
+    }
+
+    /// Returns the diagonal with the given `offset` as a 1D tensor.
+    ///
+    /// The diagonal is above the main diagonal when `offset` is positive and below it when
+    /// negative. The result is empty when the offset lies outside the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6], [7, 8, 9]], &device);
+    ///     println!("{}", tensor.clone().diagonal(0)); // [1, 5, 9]
+    ///     println!("{}", tensor.diagonal(1)); // [2, 6]
+    /// }
+    /// ```
+    pub fn diagonal(self, offset: i64) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_diagonal(self.primitive, offset))
+    }
+
+    /// Returns the sum of the main diagonal as a tensor with a single element.
+    ///
+    /// For rectangular matrices, the main diagonal has the length of the smallest dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2], [3, 4]], &device);
+    ///     println!("{}", tensor.trace()); // [5]
+    /// }
+    /// ```
+    pub fn trace(self) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_trace(self.primitive))
+    }
+
+    /// Computes the Kronecker product with the `other` matrix.
+    ///
+    /// For matrices of shapes `[m, n]` and `[p, q]`, the output has the shape `[m * p, n * q]` and
+    /// is made of the blocks `self[i, j] * other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 2, Int>::from_ints([[1, 2]], &device);
+    ///     let rhs = Tensor::<B, 2, Int>::from_ints([[0, 1], [1, 0]], &device);
+    ///     println!("{}", lhs.kron(rhs)); // [[0, 1, 0, 2], [1, 0, 2, 0]]
+    /// }
+    /// ```
+    pub fn kron(self, other: Self) -> Self {
+        Tensor::new(B::int_kron(self.primitive, other.primitive))
+    }
+
+    /// Rolls each entry of the batch along the given dimension by its own shift amount.
+    ///
+    /// The batch dimension is the dimension other than `dim`, so with `dim = 1` each row `i` is
+    /// rolled within itself by `shifts[i]`. Positive shifts move the elements towards higher
+    /// indices, and the elements that go past the end wrap around to the beginning.
+    ///
+    /// # Panics
+    ///
+    /// If `dim` is not 0 or 1, or if the number of shifts doesn't match the batch size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+    ///     let shifts = Tensor::<B, 1, Int>::from_ints([1, -1], &device);
+    ///     let output = tensor.roll_batched(shifts, 1);
+    ///     println!("{}", output); // [[3, 1, 2], [5, 6, 4]]
+    /// }
+    /// ```
+    pub fn roll_batched(self, shifts: Tensor<B, 1, Int>, dim: usize) -> Self {
+        check!(TensorCheck::roll_batched(
+            &self.shape(),
+            &shifts.shape(),
+            dim
+        ));
+
+        Tensor::new(B::int_roll_batched(self.primitive, shifts.primitive, dim))
+    }
+}
+
+impl<const D: usize, B> Tensor<B, D, Int>
+where
+    B: Backend,
+{
+    /// Create a tensor from integers (i32), placing it on a given device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Int};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let _x: Tensor<B, 1, Int> = Tensor::from_ints([1, 2], &device);
+    ///     let _y: Tensor<B, 2, Int> = Tensor::from_ints([[1, 2], [3, 4]], &device);
+    /// }
+    /// ```
+    pub fn from_ints<A: Into<TensorData>>(ints: A, device: &B::Device) -> Self {
+        Self::from_data(ints.into().convert::<i32>(), device)
+    }
+
+    /// Create a random int tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, IntDistribution, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let distribution = IntDistribution::Uniform { low: -2, high: 3 };
+    ///     let tensor = Tensor::<B, 2, Int>::random_ints([2, 3], distribution, &device);
+    ///     println!("{}", tensor); // Values in [-2, 3)
+    /// }
+    /// ```
+    pub fn random_ints<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: IntDistribution,
+        device: &B::Device,
+    ) -> Self {
+        Self::new(B::int_random(shape.into(), distribution, device))
+    }
+
+    /// Returns a new tensor with the same shape and device as the current tensor and the data
+    /// casted to Float.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let int_tensor = Tensor::<B, 1, Int>::arange(0..5, &device);
+    ///     let float_tensor = int_tensor.float();
+    /// }
+    /// ```
+    pub fn float(self) -> Tensor<B, D, Float> {
+        Tensor::new(B::int_into_float(self.primitive))
+    }
+
+    /// Generates a cartesian grid for the given tensor shape on the specified device.
+    /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape specifying the dimensions of the tensor.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `D2` is not equal to `D+1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///    use burn_tensor::Int;
+    ///    use burn_tensor::{backend::Backend, Shape, Tensor};
+    ///    fn example<B: Backend>() {
+    ///        let device = Default::default();
+    ///        let result: Tensor<B, 3, _> = Tensor::<B, 2, Int>::cartesian_grid([2, 3], &device);
+    ///        println!("{}", result);
+    ///    }
+    /// ```
+    pub fn cartesian_grid<S: Into<Shape<D>>, const D2: usize>(
+        shape: S,
+        device: &B::Device,
+    ) -> Tensor<B, D2, Int> {
+        Tensor::new(B::int_cartesian_grid::<S, D, D2>(shape, device))
+    }
+
+    /// Gather tensor elements corresponding to the given indices from the specified dim,
+    /// broadcasting the indices against the tensor.
+    ///
+    /// This is similar to [gather](Tensor::gather), except that every dimension of the index
+    /// tensor other than `dim` can also have a size of 1, in which case the indices are repeated
+    /// along that dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the index tensor can't be broadcasted to the shape of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[2, 0]], &device);
+    ///     let output = tensor.gather_broadcast(1, indices);
+    ///     println!("{}", output); // [[3, 1], [6, 4]]
+    /// }
+    /// ```
+    pub fn gather_broadcast(self, dim: usize, indices: Tensor<B, D, Int>) -> Self {
+        check!(TensorCheck::gather_broadcast::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape()
+        ));
+
+        Tensor::new(B::int_gather_broadcast(
+            dim,
+            self.primitive,
+            indices.primitive,
+        ))
+    }
+
+    /// Takes the elements at the given indices along the given dimension, like PyTorch's
+    /// `take_along_dim`.
+    ///
+    /// Every dimension of the index tensor other than `dim` must have either the same size as the
+    /// tensor or a size of 1, in which case the indices are broadcasted along that dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the index tensor can't be broadcasted to the shape of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[2]], &device);
+    ///     let output = tensor.take_along_dim(indices, 1);
+    ///     println!("{}", output); // [[3], [6]]
+    /// }
+    /// ```
+    pub fn take_along_dim(self, indices: Tensor<B, D, Int>, dim: usize) -> Self {
+        check!(TensorCheck::take_along_dim::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape()
+        ));
+
+        Tensor::new(B::int_take_along_dim(
+            self.primitive,
+            indices.primitive,
+            dim,
+        ))
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension, saturating at `max`
+    /// instead of wrapping around.
+    ///
+    /// This is useful to accumulate capped counters, e.g. with `max = 255` for `u8` counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([200, 50, 10, 1], &device);
+    ///     let output = tensor.cumsum_saturating(0, 255);
+    ///     println!("{}", output); // [200, 250, 255, 255]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn cumsum_saturating<E: ElementConversion>(self, dim: usize, max: E) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumsumSaturating", dim));
+        Tensor::new(B::int_cumsum_saturating(self.primitive, dim, max.elem()))
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3, 4], &device);
+    ///     let output = tensor.cumsum(0);
+    ///     println!("{}", output); // [1, 3, 6, 10]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Cumsum", dim));
+        Tensor::new(B::int_cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given dimension, along with
+    /// the index of that maximum.
+    ///
+    /// When the maximum is reached several times, the index of the last occurrence is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 2, 5, 4], &device);
+    ///     let (values, indices) = tensor.cummax(0);
+    ///     println!("{} {}", values, indices); // [1, 3, 3, 5, 5] [0, 1, 1, 3, 3]
+    /// }
+    /// ```
+    pub fn cummax(self, dim: usize) -> (Self, Self) {
+        check!(TensorCheck::aggregate_dim::<D>("Cummax", dim));
+
+        let (values, indices) = B::int_cummax(self.primitive, dim);
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Computes the cumulative minimum of the elements along the given dimension, along with
+    /// the index of that minimum.
+    ///
+    /// When the minimum is reached several times, the index of the last occurrence is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 2, 5, 4], &device);
+    ///     let (values, indices) = tensor.cummin(0);
+    ///     println!("{} {}", values, indices); // [1, 1, 1, 1, 1] [0, 0, 0, 0, 0]
+    /// }
+    /// ```
+    pub fn cummin(self, dim: usize) -> (Self, Self) {
+        check!(TensorCheck::aggregate_dim::<D>("Cummin", dim));
+
+        let (values, indices) = B::int_cummin(self.primitive, dim);
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Returns the `k` largest or smallest elements along the given dimension.
+    ///
+    /// Unlike [topk](Tensor::topk), equal elements are ordered by lowest index, so the result is
+    /// deterministic.
+    ///
+    /// # Panics
+    ///
+    /// If `k` exceeds the size of `dim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([3, 1, 4, 1, 5], &device);
+    ///     let output = tensor.topk_stable(2, 0, false);
+    ///     println!("{}", output); // [1, 1]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk_stable(self, k: usize, dim: usize, largest: bool) -> Self {
+        check!(TensorCheck::topk::<D>(&self.shape(), k, dim));
+        Tensor::new(B::int_topk(self.primitive, k, dim, largest))
+    }
+
+    /// Returns the `k` largest or smallest elements along the given dimension, with their indices.
+    ///
+    /// Unlike [topk_with_indices](Tensor::topk_with_indices), equal elements are ordered by lowest
+    /// index, so the result is deterministic.
+    ///
+    /// # Panics
+    ///
+    /// If `k` exceeds the size of `dim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([3, 1, 4, 1, 5], &device);
+    ///     let (values, indices) = tensor.topk_stable_with_indices(2, 0, false);
+    ///     println!("{}", values); // [1, 1]
+    ///     println!("{}", indices); // [1, 3]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk_stable_with_indices(self, k: usize, dim: usize, largest: bool) -> (Self, Self) {
+        check!(TensorCheck::topk::<D>(&self.shape(), k, dim));
+        let (values, indices) = B::int_topk_with_indices(self.primitive, k, dim, largest);
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Encodes the class indices as one hot vectors, appending a trailing dimension of size
+    /// `num_classes` that is `1` at the index of each element and `0` elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` is not `D + 1`, or if an index is negative or greater or equal to `num_classes`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let labels = Tensor::<B, 1, Int>::from_ints([0, 2], &device);
+    ///     let output: Tensor<B, 2, Int> = labels.one_hot(3);
+    ///     println!("{}", output); // [[1, 0, 0], [0, 0, 1]]
+    /// }
+    /// ```
+    pub fn one_hot<const D2: usize>(self, num_classes: usize) -> Tensor<B, D2, Int> {
+        check!(TensorCheck::one_hot_rank::<D, D2>());
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        check!(TensorCheck::one_hot_indices(
+            self.clone().lower_elem(0).any().into_scalar(),
+            self.clone()
+                .greater_equal_elem(num_classes as i64)
+                .any()
+                .into_scalar(),
+            num_classes
+        ));
+
+        Tensor::new(B::int_one_hot(self.primitive, num_classes))
+    }
+
+    /// Counts the non-zero elements of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([0, 3, 0, 7], &device);
+    ///     let count = tensor.count_nonzero();
+    ///     println!("{}", count); // [2]
+    /// }
+    /// ```
+    pub fn count_nonzero(self) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_count_nonzero(self.primitive))
+    }
+
+    /// Counts the non-zero elements of the tensor along the given dimension, which is kept with a
+    /// size of 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[0, 3, 0], [1, 2, 3]], &device);
+    ///     let counts = tensor.count_nonzero_dim(1);
+    ///     println!("{}", counts); // [[1], [3]]
+    /// }
+    /// ```
+    pub fn count_nonzero_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CountNonzero", dim));
+        Tensor::new(B::int_count_nonzero_dim(self.primitive, dim))
+    }
+
+    /// Tiles the tensor by repeating it `reps[i]` times along each dimension `i`, like
+    /// `numpy.tile`.
+    ///
+    /// # Panics
+    ///
+    /// If a number of repetitions is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2]], &device);
+    ///     let output = tensor.tile([2, 2]);
+    ///     println!("{}", output); // [[1, 2, 1, 2], [1, 2, 1, 2]]
+    /// }
+    /// ```
+    pub fn tile(self, reps: [usize; D]) -> Self {
+        check!(TensorCheck::tile::<D>(&reps));
+        Tensor::new(B::int_tile(self.primitive, reps))
+    }
+
+    /// Splits the tensor along the given dimension into sections of the given sizes.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds, or if the sizes don't sum to the size of the dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::arange(0..5, &device);
+    ///     let sections = tensor.split(&[2, 3], 0);
+    ///     println!("{} {}", sections[0], sections[1]); // [0, 1] [2, 3, 4]
+    /// }
+    /// ```
+    pub fn split(self, split_sizes: &[usize], dim: usize) -> Vec<Self> {
+        check!(TensorCheck::split_sizes::<D>(
+            &self.shape(),
+            split_sizes,
+            dim
+        ));
+
+        B::int_split(self.primitive, split_sizes, dim)
+            .into_iter()
+            .map(Tensor::new)
+            .collect()
+    }
+
+    /// Computes the element-wise greatest common divisor with the `other` tensor.
+    ///
+    /// The signs are ignored, so the result is never negative, and the greatest common divisor
+    /// of two zeros is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([12, -9, 0], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([18, 6, 0], &device);
+    ///     println!("{}", lhs.gcd(rhs)); // [6, 3, 0]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn gcd(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Gcd", &self, &other));
+        Tensor::new(B::int_gcd(self.primitive, other.primitive))
+    }
+
+    /// Computes the element-wise least common multiple with the `other` tensor.
+    ///
+    /// The signs are ignored, so the result is never negative, and the least common multiple with
+    /// a zero operand is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([4, -3, 0], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([6, 5, 7], &device);
+    ///     println!("{}", lhs.lcm(rhs)); // [12, 15, 0]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn lcm(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Lcm", &self, &other));
+        Tensor::new(B::int_lcm(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise addition with the `other` tensor, letting the backend reuse the
+    /// storage of this tensor for the result.
+    ///
+    /// The result is the same as [add](Tensor::add). Storage is only reused when the backend
+    /// supports it and the buffer isn't shared with another tensor, so this is a hint rather than
+    /// a guarantee.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let mut histogram = Tensor::<B, 1, Int>::zeros([3], &device);
+    ///     for _ in 0..4 {
+    ///         let delta = Tensor::<B, 1, Int>::from_ints([1, 0, 2], &device);
+    ///         histogram = histogram.add_inplace(delta);
+    ///     }
+    ///     println!("{}", histogram); // [4, 0, 8]
+    /// }
+    /// ```
+    pub fn add_inplace(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Add", &self, &other));
+        Tensor::new(B::int_add_inplace(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise subtraction with the `other` tensor, letting the backend reuse the
+    /// storage of this tensor for the result.
+    ///
+    /// The result is the same as [sub](Tensor::sub), see [add_inplace](Tensor::add_inplace).
+    pub fn sub_inplace(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Sub", &self, &other));
+        Tensor::new(B::int_sub_inplace(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise multiplication with the `other` tensor, letting the backend reuse the
+    /// storage of this tensor for the result.
+    ///
+    /// The result is the same as [mul](Tensor::mul), see [add_inplace](Tensor::add_inplace).
+    pub fn mul_inplace(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Mul", &self, &other));
+        Tensor::new(B::int_mul_inplace(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise addition with the `other` tensor, saturating at the bounds of the int
+    /// element type instead of wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, -2], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 4], &device);
+    ///     println!("{}", lhs.saturating_add(rhs)); // [4, 2]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn saturating_add(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("SaturatingAdd", &self, &other));
+        Tensor::new(B::int_saturating_add(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise addition with a scalar, saturating at the bounds of the int element
+    /// type instead of wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, -2], &device);
+    ///     println!("{}", tensor.saturating_add_scalar(2)); // [3, 0]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn saturating_add_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_saturating_add_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element-wise subtraction with the `other` tensor, saturating at the bounds of the
+    /// int element type instead of wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, -2], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 4], &device);
+    ///     println!("{}", lhs.saturating_sub(rhs)); // [-2, -6]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("SaturatingSub", &self, &other));
+        Tensor::new(B::int_saturating_sub(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise subtraction with a scalar, saturating at the bounds of the int element
+    /// type instead of wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, -2], &device);
+    ///     println!("{}", tensor.saturating_sub_scalar(2)); // [-1, -4]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn saturating_sub_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_saturating_sub_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element-wise multiplication with the `other` tensor, saturating at the bounds of the
+    /// int element type instead of wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, -2], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 4], &device);
+    ///     println!("{}", lhs.saturating_mul(rhs)); // [3, -8]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn saturating_mul(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("SaturatingMul", &self, &other));
+        Tensor::new(B::int_saturating_mul(self.primitive, other.primitive))
+    }
+
+    /// Applies element-wise multiplication with a scalar, saturating at the bounds of the int
+    /// element type instead of wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, -2], &device);
+    ///     println!("{}", tensor.saturating_mul_scalar(2)); // [2, -4]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn saturating_mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_saturating_mul_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element-wise addition with the `other` tensor, reporting where the result overflows
+    /// the int element type.
+    ///
+    /// Returns the results wrapped around the bounds of the int element type, along with a
+    /// boolean tensor that is `true` where the exact result doesn't fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, i32::MAX], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 1], &device);
+    ///     let (output, overflowed) = lhs.checked_add(rhs);
+    ///     println!("{} {}", output, overflowed);
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn checked_add(self, other: Self) -> (Self, Tensor<B, D, Bool>) {
+        check!(TensorCheck::binary_ops_ew("CheckedAdd", &self, &other));
+
+        let (output, overflowed) = B::int_checked_add(self.primitive, other.primitive);
+        (Tensor::new(output), Tensor::new(overflowed))
+    }
+
+    /// Applies element-wise subtraction with the `other` tensor, reporting where the result
+    /// overflows the int element type.
+    ///
+    /// Returns the results wrapped around the bounds of the int element type, along with a
+    /// boolean tensor that is `true` where the exact result doesn't fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([1, i32::MIN], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 1], &device);
+    ///     let (output, overflowed) = lhs.checked_sub(rhs);
+    ///     println!("{} {}", output, overflowed);
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn checked_sub(self, other: Self) -> (Self, Tensor<B, D, Bool>) {
+        check!(TensorCheck::binary_ops_ew("CheckedSub", &self, &other));
+
+        let (output, overflowed) = B::int_checked_sub(self.primitive, other.primitive);
+        (Tensor::new(output), Tensor::new(overflowed))
+    }
+
+    /// Applies element-wise multiplication with the `other` tensor, reporting where the result
+    /// overflows the int element type.
+    ///
+    /// Returns the results wrapped around the bounds of the int element type, along with a
+    /// boolean tensor that is `true` where the exact result doesn't fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let lhs = Tensor::<B, 1, Int>::from_ints([2, i32::MAX], &device);
+    ///     let rhs = Tensor::<B, 1, Int>::from_ints([3, 2], &device);
+    ///     let (output, overflowed) = lhs.checked_mul(rhs);
+    ///     println!("{} {}", output, overflowed);
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn checked_mul(self, other: Self) -> (Self, Tensor<B, D, Bool>) {
+        check!(TensorCheck::binary_ops_ew("CheckedMul", &self, &other));
+
+        let (output, overflowed) = B::int_checked_mul(self.primitive, other.primitive);
+        (Tensor::new(output), Tensor::new(overflowed))
+    }
+
+    /// Raises the elements to the power of the elements of the `exponents` tensor, with integer
+    /// arithmetic.
+    ///
+    /// Unlike [powi](Tensor::powi), the power doesn't go through floats, so it stays exact for
+    /// large results. The powers that don't fit in the int element type are handled according to
+    /// the `overflow` mode.
+    ///
+    /// # Panics
+    ///
+    /// If a power overflows with [OverflowMode::Panicking], or if the exponents contain negative
+    /// values. Finding the negative exponents reads a reduction of `exponents` back from the
+    /// device, so this synchronizes with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::ops::OverflowMode;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let bases = Tensor::<B, 1, Int>::from_ints([2, -3, 10], &device);
+    ///     let exponents = Tensor::<B, 1, Int>::from_ints([10, 3, 0], &device);
+    ///     let output = bases.pow(exponents, OverflowMode::Saturating);
+    ///     println!("{}", output); // [1024, -27, 1]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn pow(self, exponents: Self, overflow: OverflowMode) -> Self {
+        check!(TensorCheck::binary_ops_ew("Pow", &self, &exponents));
+        check!(TensorCheck::pow_exponents(
+            exponents.clone().lower_elem(0).any().into_scalar()
+        ));
+
+        Tensor::new(B::int_pow(self.primitive, exponents.primitive, overflow))
+    }
+
+    /// Computes the element-wise integer square root, rounded down.
+    ///
+    /// The root is computed with integer arithmetic, so it is exact even for values too large to
+    /// be represented exactly as floats.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains negative values. Finding them reads a reduction of the tensor back
+    /// from the device, so this synchronizes with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([0, 8, 9, 10], &device);
+    ///     println!("{}", tensor.isqrt()); // [0, 2, 3, 3]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn isqrt(self) -> Self {
+        check!(TensorCheck::isqrt_values(
+            self.clone().lower_elem(0).any().into_scalar()
+        ));
+
+        Tensor::new(B::int_isqrt(self.primitive))
+    }
+
+    /// Computes the element-wise base 2 integer logarithm, rounded down.
+    ///
+    /// This is the number of bits needed to represent each value, minus one.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains values that are not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3, 4, 8], &device);
+    ///     println!("{}", tensor.ilog2()); // [0, 1, 1, 2, 3]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn ilog2(self) -> Self {
+        Tensor::new(B::int_ilog2(self.primitive))
+    }
+
+    /// Computes the element-wise integer logarithm in the given `base`, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor contains values that are not positive, or if `base` is smaller than 2.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 9, 10, 1000], &device);
+    ///     println!("{}", tensor.ilog(10)); // [0, 0, 1, 3]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn ilog<E: ElementConversion>(self, base: E) -> Self {
+        Tensor::new(B::int_ilog(self.primitive, base.elem()))
+    }
+
+    /// Rolls the elements along the given dimension by `shifts` positions.
+    ///
+    /// Positive shifts move the elements towards higher indices and negative shifts towards lower
+    /// indices. The elements that go past one end wrap around to the other end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3, 4, 5], &device);
+    ///     let output = tensor.roll(2, 0);
+    ///     println!("{}", output); // [4, 5, 1, 2, 3]
+    /// }
+    /// ```
+    pub fn roll(self, shifts: i64, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Roll", dim));
+        Tensor::new(B::int_roll(self.primitive, shifts, dim))
+    }
+
+    /// Rolls the elements along several dimensions at once, shifting them by `shifts[i]`
+    /// positions along `dims[i]`.
+    ///
+    /// # Panics
+    ///
+    /// If `shifts` and `dims` don't have the same length, or if the dimensions are out of bounds
+    /// or repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+    ///     let output = tensor.roll_dims(&[1, -1], &[0, 1]);
+    ///     println!("{}", output); // [[5, 6, 4], [2, 3, 1]]
+    /// }
+    /// ```
+    pub fn roll_dims(self, shifts: &[i64], dims: &[usize]) -> Self {
+        check!(TensorCheck::roll_dims::<D>(shifts, dims));
+        Tensor::new(B::int_roll_dims(self.primitive, shifts, dims))
+    }
+
+    /// Computes the `n`-th order discrete difference along the given dimension.
+    ///
+    /// The first order difference is `output[i] = tensor[i + 1] - tensor[i]`, and higher orders
+    /// are computed by applying it repeatedly, so the size of `dim` shrinks by `n`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is greater than the size of `dim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let offsets = Tensor::<B, 1, Int>::from_ints([0, 3, 4, 9], &device);
+    ///     let lengths = offsets.diff(0, 1);
+    ///     println!("{}", lengths); // [3, 1, 5]
+    /// }
+    /// ```
+    pub fn diff(self, dim: usize, n: usize) -> Self {
+        check!(TensorCheck::diff(&self.shape(), dim, n));
+        Tensor::new(B::int_diff(self.primitive, dim, n))
+    }
+
+    /// Transposes the tensor by swapping the two given dimensions.
+    ///
+    /// This is equivalent to [swap_dims](Tensor::swap_dims), but reads more clearly when
+    /// transposing batched matrices along explicit dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If one of the dimensions is out of bounds.
+    pub fn transpose_dims(self, dim0: usize, dim1: usize) -> Self {
+        check!(TensorCheck::swap_dims::<D>(dim0, dim1));
+        Tensor::new(B::int_transpose_dims(self.primitive, dim0, dim1))
+    }
+
+    /// Compute the indices of the non-zero elements, with one index tensor per dimension.
+    ///
+    /// This is the tuple form of `tensor.not_equal_elem(0).argwhere()`: entry `j` of the `i`-th
+    /// tensor is the index in dimension `i` of the `j`-th non-zero element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[0, 3], [4, 0]], &device);
+    ///     let [rows, cols] = tensor.nonzero_tuple();
+    ///     println!("{} {}", rows, cols); // [0, 1] [1, 0]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn nonzero_tuple(self) -> [Tensor<B, 1, Int>; D] {
+        B::int_nonzero_tuple(self.primitive).map(Tensor::new)
+    }
+
+    /// Selects the elements of `on_true` where the condition is true and the elements of
+    /// `on_false` elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// If the condition and both tensors don't have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let a = Tensor::<B, 1, Int>::from_ints([1, 2, 3], &device);
+    ///     let b = Tensor::<B, 1, Int>::from_ints([4, 5, 6], &device);
+    ///     let condition = a.clone().greater_elem(1);
+    ///     let output = Tensor::where_cond(condition, a, b);
+    ///     println!("{}", output); // [4, 2, 3]
+    /// }
+    /// ```
+    pub fn where_cond(condition: Tensor<B, D, Bool>, on_true: Self, on_false: Self) -> Self {
+        check!(TensorCheck::where_cond::<D>(
+            &condition.shape(),
+            &on_true.shape(),
+            &on_false.shape()
+        ));
+
+        Tensor::new(B::int_where(
+            condition.primitive,
+            on_true.primitive,
+            on_false.primitive,
+        ))
+    }
+
+    /// Extracts the elements where the mask is true into a 1D tensor, in row-major order.
+    ///
+    /// The size of the output depends on the mask, so this forces a synchronization with the
+    /// device.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Int, Tensor, TensorData};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2], [3, 4]], &device);
+    ///     let mask = Tensor::<B, 2, Bool>::from_bool(
+    ///         TensorData::from([[true, false], [false, true]]),
+    ///         &device,
+    ///     );
+    ///     let output = tensor.masked_select(mask);
+    ///     println!("{}", output); // [1, 4]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, Int> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+        Tensor::new(B::int_masked_select(self.primitive, mask.primitive))
+    }
+
+    /// Compute the coordinates of the non-zero elements, as a `[N, D]` tensor where `N` is the
+    /// number of non-zero elements, in row-major order.
+    ///
+    /// The size of the output depends on the data, so this forces a synchronization with the
+    /// device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[0, 3], [4, 0]], &device);
+    ///     let coordinates = tensor.nonzero();
+    ///     println!("{}", coordinates); // [[0, 1], [1, 0]]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn nonzero(self) -> Tensor<B, 2, Int> {
+        Tensor::new(B::int_nonzero(self.primitive))
+    }
+
+    /// Compute the row-major flat indices of the non-zero elements.
+    ///
+    /// The size of the output depends on the data, so this forces a synchronization with the
+    /// device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[0, 3], [4, 0]], &device);
+    ///     let indices = tensor.nonzero_flat();
+    ///     println!("{}", indices); // [1, 2]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn nonzero_flat(self) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_nonzero_flat(self.primitive))
+    }
+
+    /// Clamp the tensor between the given min and max values, returning an error if `min` is
+    /// greater than `max`.
+    ///
+    /// Unlike [clamp](Tensor::clamp), inverted bounds are reported instead of silently producing
+    /// a tensor filled with `min`.
+    pub fn clamp_checked<E: ElementConversion>(self, min: E, max: E) -> Result<Self, ClampError> {
+        B::int_clamp_checked(self.primitive, min.elem(), max.elem()).map(Tensor::new)
+    }
+
+    /// Clamp the tensor between the element-wise min and max values.
+    ///
+    /// The `min` and `max` tensors are broadcast to the shape of the tensor, so a bound can be
+    /// given per channel for instance.
+    ///
+    /// # Panics
+    ///
+    /// If `min` or `max` can't be broadcast to the shape of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[-5, 0, 5], [10, -10, 3]], &device);
+    ///     let min = Tensor::<B, 2, Int>::from_ints([[-1, 0, 1]], &device);
+    ///     let max = Tensor::<B, 2, Int>::from_ints([[1, 2, 3]], &device);
+    ///     let output = tensor.clamp_tensor(min, max);
+    ///     println!("{}", output); // [[-1, 0, 3], [1, 0, 3]]
+    /// }
+    /// ```
+    pub fn clamp_tensor(self, min: Self, max: Self) -> Self {
+        check!(TensorCheck::clamp_tensor(
+            &self.shape(),
+            &min.shape(),
+            &max.shape()
+        ));
+
+        Tensor::new(B::int_clamp_tensor(
+            self.primitive,
+            min.primitive,
+            max.primitive,
+        ))
+    }
+
+    /// Fill the selected slices along the given dimension corresponding to the given indices
+    /// with a scalar value.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i], j, k] = value; // dim = 0`
+    /// `input[i, indices[j], k] = value; // dim = 1`
+    /// `input[i, j, indices[k]] = value; // dim = 2`
+    ///
+    /// Unlike [select_assign](Tensor::select_assign), the selected elements are overwritten and
+    /// no value tensor needs to be created.
+    pub fn select_fill<E: ElementConversion>(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        value: E,
+    ) -> Self {
+        check!(TensorCheck::select_assign::<D>(dim));
+        Tensor::new(B::int_select_fill(
+            self.primitive,
+            dim,
+            indices.primitive,
+            value.elem(),
+        ))
+    }
+
+    /// Assign the selected elements along the given dimension corresponding to the given indices
+    /// from the value tensor to the original tensor using sum reduction, where negative indices
+    /// count from the end of the dimension.
+    ///
+    /// Example using a 3D tensor with a dimension of size `n`:
+    ///
+    /// `input[indices[i] (+ n if negative), j, k] += values[i, j, k]; // dim = 0`
+    ///
+    /// # Panics
+    ///
+    /// If an index is out of bounds after resolving the negative indices.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn select_assign_wrapped(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self,
+    ) -> Self {
+        check!(TensorCheck::select_assign::<D>(dim));
+        check!(TensorCheck::select_assign_values::<D>(
+            dim,
+            &self.shape(),
+            indices.dims()[0],
+            &values.shape()
+        ));
+
+        Tensor::new(B::int_select_assign_wrapped(
+            self.primitive,
+            dim,
+            indices.primitive,
+            values.primitive,
+        ))
+    }
+
+    /// Adds the slices of `source` into the tensor at the given indices along the given dimension,
+    /// like `torch.index_add`.
+    ///
+    /// `output[indices[i], j] += source[i, j]; // dim = 0`
+    ///
+    /// The slices whose indices are repeated are all added, so they accumulate instead of
+    /// overwriting each other.
+    ///
+    /// # Panics
+    ///
+    /// If `source` doesn't have the shape of the tensor with `dim` replaced by the number of
+    /// indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([0, 0, 0], &device);
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([2, 0, 2], &device);
+    ///     let source = Tensor::<B, 1, Int>::from_ints([1, 2, 3], &device);
+    ///     let output = tensor.index_add(0, indices, source);
+    ///     println!("{}", output); // [2, 0, 4]
+    /// }
+    /// ```
+    pub fn index_add(self, dim: usize, indices: Tensor<B, 1, Int>, source: Self) -> Self {
+        check!(TensorCheck::select_assign::<D>(dim));
+        check!(TensorCheck::select_assign_values::<D>(
+            dim,
+            &self.shape(),
+            indices.dims()[0],
+            &source.shape()
+        ));
+
+        Tensor::new(B::int_index_add(
+            self.primitive,
+            dim,
+            indices.primitive,
+            source.primitive,
+        ))
+    }
+
+    /// Computes the product of all elements, returning an error instead of a wrapped value when
+    /// the product doesn't fit in the int element type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let dims = Tensor::<B, 1, Int>::from_ints([2, 3, 4], &device);
+    ///     let num_elems = dims.prod_checked().expect("The tensor size should not overflow");
+    ///     println!("{}", num_elems); // [24]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn prod_checked(self) -> Result<Tensor<B, 1, Int>, OverflowError> {
+        B::int_prod_checked(self.primitive).map(Tensor::new)
+    }
+
+    /// Aggregates all elements with the mean operation, rounding to the nearest integer.
+    ///
+    /// Unlike [mean](Tensor::mean), which truncates toward zero, halves are rounded away from
+    /// zero, so the mean of `[1, 2]` is `2` and the mean of `[-1, -2]` is `-2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2], &device);
+    ///     println!("{}", tensor.clone().mean()); // [1]
+    ///     println!("{}", tensor.mean_round()); // [2]
+    /// }
+    /// ```
+    pub fn mean_round(self) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_mean_round(self.primitive))
+    }
+
+    /// Aggregates all elements along the given dimension with the mean operation, rounding to the
+    /// nearest integer.
+    ///
+    /// Halves are rounded away from zero, see [mean_round](Tensor::mean_round).
+    pub fn mean_dim_round(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Mean", dim));
+        Tensor::new(B::int_mean_dim_round(self.primitive, dim))
+    }
+
+    /// Calculates the variance along the given dimension as a float tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2, 3, 4]], &device);
+    ///     println!("{}", tensor.clone().var(1)); // [[1.6667]]
+    ///     println!("{}", tensor.var_bias(1)); // [[1.25]]
+    /// }
+    /// ```
+    pub fn var(self, dim: usize) -> Tensor<B, D, Float> {
+        check!(TensorCheck::aggregate_dim::<D>("Var", dim));
+        Tensor::new(B::int_var(self.primitive, dim, true))
+    }
+
+    /// Calculates the variance along the given dimension as a float tensor, without applying the
+    /// Bessel's correction.
+    pub fn var_bias(self, dim: usize) -> Tensor<B, D, Float> {
+        check!(TensorCheck::aggregate_dim::<D>("Var", dim));
+        Tensor::new(B::int_var(self.primitive, dim, false))
+    }
+
+    /// Calculates the standard deviation along the given dimension as a float tensor.
+    pub fn std(self, dim: usize) -> Tensor<B, D, Float> {
+        check!(TensorCheck::aggregate_dim::<D>("Std", dim));
+        Tensor::new(B::int_std(self.primitive, dim, true))
+    }
+
+    /// Calculates the standard deviation along the given dimension as a float tensor, without
+    /// applying the Bessel's correction.
+    pub fn std_bias(self, dim: usize) -> Tensor<B, D, Float> {
+        check!(TensorCheck::aggregate_dim::<D>("Std", dim));
+        Tensor::new(B::int_std(self.primitive, dim, false))
+    }
+
+    /// Reverses the elements inside a window of the tensor.
+    ///
+    /// The window is given by one `(range, reversed)` pair per dimension, where only the
+    /// dimensions flagged as `reversed` are flipped. The elements outside of the window are left
+    /// unchanged, and dimensions without a range are fully included in the window.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::arange(0..6, &device);
+    ///     let output = tensor.slice_reverse([(1..4, true)]);
+    ///     println!("{}", output); // [0, 3, 2, 1, 4, 5]
+    /// }
+    /// ```
+    pub fn slice_reverse<const D2: usize>(self, ranges: [(Range<usize>, bool); D2]) -> Self {
+        check!(TensorCheck::slice::<D, D2>(
+            &self.shape(),
+            &ranges.clone().map(|(range, _)| range)
+        ));
+
+        Tensor::new(B::int_slice_reverse(self.primitive, ranges))
+    }
+
+    /// Computes a cumulative scan along the given dimension.
+    ///
+    /// See [int_scan_config](crate::ops::IntTensorOps::int_scan_config) for the behavior of each
+    /// combination of `exclusive` and `reverse`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::ops::ScanOp;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3], &device);
+    ///     let output = tensor.scan(0, ScanOp::Sum, true, true);
+    ///     println!("{}", output); // [5, 3, 0]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn scan(self, dim: usize, op: ScanOp, exclusive: bool, reverse: bool) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Scan", dim));
+        Tensor::new(B::int_scan_config(
+            self.primitive,
+            dim,
+            op,
+            exclusive,
+            reverse,
+        ))
+    }
+
+    /// Returns the most frequent value over all the elements of the tensor, along with the number
+    /// of times it appears.
+    ///
+    /// When several values are the most frequent, the smallest one is returned.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[3, 1], [1, 3]], &device);
+    ///     let (value, count) = tensor.mode_flat();
+    ///     println!("{} {}", value, count); // [1] [2]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn mode_flat(self) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        let (value, count) = B::int_mode_flat(self.primitive);
+        (Tensor::new(value), Tensor::new(count))
+    }
+
+    /// Returns the most frequent value along the given dimension, along with the index of its
+    /// first occurrence.
+    ///
+    /// The reduced dimension is kept with a size of 1. When several values are the most frequent,
+    /// the smallest one is returned.
+    ///
+    /// # Panics
+    ///
+    /// If the size of `dim` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[3, 1, 3], [2, 1, 1]], &device);
+    ///     let (values, indices) = tensor.mode_dim(1);
+    ///     println!("{} {}", values, indices); // [[3], [1]] [[0], [1]]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn mode_dim(self, dim: usize) -> (Self, Self) {
+        check!(TensorCheck::aggregate_dim::<D>("Mode", dim));
+
+        let (values, indices) = B::int_mode_dim(self.primitive, dim);
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Returns the distinct elements of the tensor, in ascending order when `sorted` is `true` and
+    /// in the order of their first appearance otherwise.
+    ///
+    /// The size of the output depends on the data, so this reads the tensor and synchronizes with
+    /// the device unless the backend provides its own implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([3, 1, 1, 2, 3], &device);
+    ///     let output = tensor.unique(true);
+    ///     println!("{}", output); // [1, 2, 3]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique(self, sorted: bool) -> Tensor<B, 1, Int> {
+        Tensor::new(B::int_unique(self.primitive, sorted))
+    }
+
+    /// Returns the distinct elements of the tensor along with the number of times each one appears.
+    ///
+    /// See [unique](Tensor::unique) for the ordering of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([3, 1, 1, 2, 3], &device);
+    ///     let (values, counts) = tensor.unique_with_counts(true);
+    ///     println!("{} {}", values, counts); // [1, 2, 3] [2, 1, 2]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique_with_counts(self, sorted: bool) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        let (values, counts) = B::int_unique_with_counts(self.primitive, sorted);
+        (Tensor::new(values), Tensor::new(counts))
+    }
+
+    /// Replaces the elements that don't exceed the `threshold` with the `replacement` value.
+    ///
+    /// The elements equal to the threshold are kept when `inclusive` is `true` (`>=`), and
+    /// replaced otherwise (`>`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 3, 5, 7], &device);
+    ///     let output = tensor.threshold(3, 0, false);
+    ///     println!("{}", output); // [0, 0, 5, 7]
+    /// }
+    /// ```
+    pub fn threshold<E: ElementConversion>(
+        self,
+        threshold: E,
+        replacement: E,
+        inclusive: bool,
+    ) -> Self {
+        Tensor::new(B::int_threshold(
+            self.primitive,
+            threshold.elem(),
+            replacement.elem(),
+            inclusive,
+        ))
+    }
+
+    /// Pads the tensor by wrapping around each dimension, taking the padded values from the
+    /// opposite edge.
+    ///
+    /// `pads` contains the number of elements `(before, after)` to add for each dimension. Pads
+    /// larger than the dimension wrap around multiple times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3], &device);
+    ///     let padded = tensor.pad_circular([(1, 1)]);
+    ///     println!("{}", padded); // [3, 1, 2, 3, 1]
+    /// }
+    /// ```
+    pub fn pad_circular(self, pads: [(usize, usize); D]) -> Self {
+        Tensor::new(B::int_pad_circular(self.primitive, pads))
+    }
+
+    /// Pads the tensor with the constant `value`.
+    ///
+    /// `padding` contains the number of elements `(before, after)` to add for each dimension.
+    /// Unlike [pad](Tensor::pad), every dimension can be padded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 2], [3, 4]], &device);
+    ///     let padded = tensor.pad_constant([(1, 0), (0, 1)], 0);
+    ///     println!("{}", padded); // [[0, 0, 0], [1, 2, 0], [3, 4, 0]]
+    /// }
+    /// ```
+    pub fn pad_constant<E: ElementConversion>(
+        self,
+        padding: [(usize, usize); D],
+        value: E,
+    ) -> Self {
+        Tensor::new(B::int_pad(self.primitive, padding, value.elem()))
+    }
+
+    /// Selects the given contiguous ranges along the dimension `dim` and concatenates them.
+    ///
+    /// This is equivalent to [select](Tensor::select) with the indices of all the ranges, without
+    /// having to create an index tensor.
+    ///
+    /// # Panics
+    ///
+    /// If `ranges` is empty or if a range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::arange(0..10, &device);
+    ///     let output = tensor.select_ranges(0, &[0..3, 5..8]);
+    ///     println!("{}", output); // [0, 1, 2, 5, 6, 7]
+    /// }
+    /// ```
+    pub fn select_ranges(self, dim: usize, ranges: &[Range<usize>]) -> Self {
+        check!(TensorCheck::select_ranges(&self.shape(), dim, ranges));
+
+        Tensor::new(B::int_select_ranges(self.primitive, dim, ranges))
+    }
 
-impl<B> Tensor<B, 1, Int>
-where
-    B: Backend,
-{
-    /// Returns a new integer tensor on the specified device.
+    /// Applies element wise equal comparison and returns an int tensor containing `1` where
+    /// the comparison holds and `0` elsewhere.
+    pub fn equal_elem_int<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_equal_elem_int(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise not equal comparison and returns an int tensor containing `1` where
+    /// the comparison holds and `0` elsewhere.
+    pub fn not_equal_elem_int<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_not_equal_elem_int(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise greater comparison and returns an int tensor containing `1` where
+    /// the comparison holds and `0` elsewhere.
+    pub fn greater_elem_int<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_greater_elem_int(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise greater-equal comparison and returns an int tensor containing `1` where
+    /// the comparison holds and `0` elsewhere.
+    pub fn greater_equal_elem_int<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_greater_equal_elem_int(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise lower comparison and returns an int tensor containing `1` where
+    /// the comparison holds and `0` elsewhere.
+    pub fn lower_elem_int<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_lower_elem_int(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise lower-equal comparison and returns an int tensor containing `1` where
+    /// the comparison holds and `0` elsewhere.
+    pub fn lower_equal_elem_int<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_lower_equal_elem_int(self.primitive, other.elem()))
+    }
+
+    /// Gather the elements corresponding to the given indices along the specified dimension,
+    /// placing the `default` value where `valid` is false.
     ///
-    /// # Arguments
+    /// The indices at the invalid positions are never read, so a padding index of any value can
+    /// be used there.
     ///
-    /// * `range` - The range of values to generate.
-    /// * `device` - The device to create the tensor on.
-    pub fn arange(range: Range<i64>, device: &B::Device) -> Self {
-        Tensor::new(B::int_arange(range, device))
+    /// # Panics
+    ///
+    /// If `valid` doesn't have the same shape as the indices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([5, 6, 7], &device);
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([2, -1, 0], &device);
+    ///     let valid = Tensor::<B, 1, Bool>::from_bool([true, false, true].into(), &device);
+    ///     let output = tensor.gather_masked(0, indices, valid, 0);
+    ///     println!("{}", output); // [7, 0, 5]
+    /// }
+    /// ```
+    pub fn gather_masked<E: ElementConversion>(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        valid: Tensor<B, D, Bool>,
+        default: E,
+    ) -> Self {
+        check!(TensorCheck::gather_masked::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &valid.shape()
+        ));
+
+        Tensor::new(B::int_gather_masked(
+            dim,
+            self.primitive,
+            indices.primitive,
+            valid.primitive,
+            default.elem(),
+        ))
     }
 
-    /// Returns a new integer tensor on the specified device.
+    /// Applies the argmax function along the given dimension, returning wide indices.
     ///
-    /// # Arguments
+    /// Unlike [argmax](Tensor::argmax), the indices don't have to fit the int element type of
+    /// the backend, so dimensions longer than its positive range can be reduced. The indices
+    /// stay on the device until read with [into_data](WideIndices::into_data).
     ///
-    /// * `range` - The range of values to generate.
-    /// * `step` - The step between each value.
-    pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
-        Tensor::new(B::int_arange_step(range, step, device))
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[1, 5, 3], [4, 2, 4]], &device);
+    ///     let indices = tensor.argmax_usize(1);
+    ///     println!("{:?}", indices.into_data().to_vec::<u64>().unwrap()); // [1, 0]
+    /// }
+    /// ```
+    pub fn argmax_usize(self, dim: usize) -> WideIndices<B, D> {
+        check!(TensorCheck::aggregate_dim::<D>("Argmax", dim));
+
+        B::int_argmax_usize(self.primitive, dim)
     }
-}
 
-impl<const D: usize, B> Tensor<B, D, Int>
-where
-    B: Backend,
-{
-    /// Create a tensor from integers (i32), placing it on a given device.
+    /// Applies the argmin function along the given dimension, returning wide indices.
+    ///
+    /// Unlike [argmin](Tensor::argmin), the indices don't have to fit the int element type of
+    /// the backend, so dimensions longer than its positive range can be reduced. The indices
+    /// stay on the device until read with [into_data](WideIndices::into_data).
+    pub fn argmin_usize(self, dim: usize) -> WideIndices<B, D> {
+        check!(TensorCheck::aggregate_dim::<D>("Argmin", dim));
+
+        B::int_argmin_usize(self.primitive, dim)
+    }
+
+    /// Computes the cumulative product along the given dimension, accumulating in floating point
+    /// to avoid overflows.
+    ///
+    /// The running products are only exact as long as they fit the mantissa of the float element
+    /// type, and they are rounded beyond that.
     ///
     /// # Example
     ///
     /// ```rust
     /// use burn_tensor::backend::Backend;
-    /// use burn_tensor::{Tensor, Int};
+    /// use burn_tensor::{Int, Tensor};
     ///
     /// fn example<B: Backend>() {
-    ///     let device = B::Device::default();
-    ///     let _x: Tensor<B, 1, Int> = Tensor::from_ints([1, 2], &device);
-    ///     let _y: Tensor<B, 2, Int> = Tensor::from_ints([[1, 2], [3, 4]], &device);
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3, 4], &device);
+    ///     let output = tensor.cumprod_float(0);
+    ///     println!("{}", output); // [1.0, 2.0, 6.0, 24.0]
     /// }
     /// ```
-    pub fn from_ints<A: Into<TensorData>>(ints: A, device: &B::Device) -> Self {
-        Self::from_data(ints.into().convert::<i32>(), device)
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn cumprod_float(self, dim: usize) -> Tensor<B, D, Float> {
+        check!(TensorCheck::aggregate_dim::<D>("Cumprod", dim));
+
+        Tensor::new(B::int_cumprod_float(self.primitive, dim))
     }
 
-    /// Returns a new tensor with the same shape and device as the current tensor and the data
-    /// casted to Float.
+    /// Returns the value at the quantile `q` of all the elements, using the nearest-rank method.
+    ///
+    /// The returned value is always an element of the tensor, since no interpolation is done.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is empty or if `q` is not in `[0, 1]`.
     ///
     /// # Example
     ///
@@ -63,42 +2223,372 @@ where
     ///
     /// fn example<B: Backend>() {
     ///     let device = Default::default();
-    ///     let int_tensor = Tensor::<B, 1, Int>::arange(0..5, &device);
-    ///     let float_tensor = int_tensor.float();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([7, 1, 5, 3, 9], &device);
+    ///     let median = tensor.quantile(0.5);
+    ///     println!("{}", median); // 5
     /// }
     /// ```
-    pub fn float(self) -> Tensor<B, D, Float> {
-        Tensor::new(B::int_into_float(self.primitive))
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn quantile(self, q: f32) -> B::IntElem {
+        check!(TensorCheck::quantile(self.shape().num_elements(), q));
+
+        B::int_quantile(self.primitive, q)
     }
 
-    /// Generates a cartesian grid for the given tensor shape on the specified device.
-    /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
+    /// Scatter the values of the value tensor to the original tensor at the given indices,
+    /// reducing the values that land on the same position.
     ///
-    /// # Arguments
+    /// Example using a 3D tensor with the [max](ReduceKind::Max) reduction:
     ///
-    /// * `shape` - The shape specifying the dimensions of the tensor.
-    /// * `device` - The device to create the tensor on.
+    /// `input[indices[i, j, k], j, k] = max(input[indices[i, j, k], j, k], values[i, j, k]); // dim = 0`
+    ///
+    /// The original value takes part in the reduction, and the positions that no index targets
+    /// keep their original value. The [sum](ReduceKind::Sum) reduction is the same as
+    /// [scatter](Tensor::scatter).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::ops::ReduceKind;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([5, 5, 5], &device);
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([0, 0, 2], &device);
+    ///     let values = Tensor::<B, 1, Int>::from_ints([7, 9, 1], &device);
+    ///     let output = tensor.scatter_reduce(0, indices, values, ReduceKind::Max);
+    ///     println!("{}", output); // [9, 5, 5]
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn scatter_reduce(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        reduce: ReduceKind,
+    ) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Tensor::new(B::int_scatter_reduce(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            reduce,
+        ))
+    }
+
+    /// Scatter the values of the value tensor to the original tensor at the given indices,
+    /// reducing the values that land on the same position, and count them.
+    ///
+    /// Example using a 3D tensor with the [sum](ReduceKind::Sum) reduction:
+    ///
+    /// `input[indices[i, j, k], j, k] += values[i, j, k]; // dim = 0`
+    /// `counts[indices[i, j, k], j, k] += 1; // dim = 0`
+    ///
+    /// The original value takes part in the reduction, so the [mean](ReduceKind::Mean) of a
+    /// position is `(input + sum) / (count + 1)`. The positions that no index targets keep their
+    /// original value and have a count of 0.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the reduced tensor and the counts tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn scatter_reduce_with_counts(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        reduce: ReduceKind,
+    ) -> (Self, Self) {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        let (output, counts) = B::int_scatter_reduce_with_counts(
+            dim,
+            self.primitive,
+            indices.primitive,
+            values.primitive,
+            reduce,
+        );
+
+        (Tensor::new(output), Tensor::new(counts))
+    }
+
+    /// Writes the values of `src` onto the diagonal with the given `offset` over the dimensions
+    /// `dim1` and `dim2`, like `torch.diagonal_scatter`.
+    ///
+    /// The diagonal is above the main diagonal when `offset` is positive and below it when
+    /// negative. The `src` tensor has the shape of the tensor without `dim1` and `dim2`, followed
+    /// by the length of the diagonal.
     ///
     /// # Panics
     ///
-    /// Panics if `D2` is not equal to `D+1`.
+    /// If the dimensions are invalid or if `src` doesn't have the shape of the diagonal.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```rust
-    ///    use burn_tensor::Int;
-    ///    use burn_tensor::{backend::Backend, Shape, Tensor};
-    ///    fn example<B: Backend>() {
-    ///        let device = Default::default();
-    ///        let result: Tensor<B, 3, _> = Tensor::<B, 2, Int>::cartesian_grid([2, 3], &device);
-    ///        println!("{}", result);
-    ///    }
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 2, Int>::zeros([3, 3], &device);
+    ///     let src = Tensor::<B, 1, Int>::from_ints([1, 2], &device);
+    ///     let output = tensor.diagonal_scatter(src, 1, 0, 1);
+    ///     println!("{}", output); // [[0, 1, 0], [0, 0, 2], [0, 0, 0]]
+    /// }
     /// ```
-    pub fn cartesian_grid<S: Into<Shape<D>>, const D2: usize>(
-        shape: S,
-        device: &B::Device,
-    ) -> Tensor<B, D2, Int> {
-        Tensor::new(B::int_cartesian_grid::<S, D, D2>(shape, device))
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn diagonal_scatter<const D2: usize>(
+        self,
+        src: Tensor<B, D2, Int>,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self {
+        check!(TensorCheck::diagonal_scatter(
+            &self.shape(),
+            &src.shape(),
+            offset,
+            dim1,
+            dim2
+        ));
+
+        Tensor::new(B::int_diagonal_scatter(
+            self.primitive,
+            src.primitive,
+            offset,
+            dim1,
+            dim2,
+        ))
+    }
+
+    /// Applies element wise bitwise AND operation.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// `y = x1 & x2`
+    pub fn bitwise_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Bitwise And", &self, &other));
+        Tensor::new(B::int_bitwise_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise OR operation.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// `y = x1 | x2`
+    pub fn bitwise_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Bitwise Or", &self, &other));
+        Tensor::new(B::int_bitwise_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise XOR operation.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// `y = x1 ^ x2`
+    pub fn bitwise_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Bitwise Xor", &self, &other));
+        Tensor::new(B::int_bitwise_xor(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise AND operation with a scalar.
+    ///
+    /// The scalar is converted to the backend's int element type, which panics if it can't be
+    /// represented by that type.
+    ///
+    /// `y = x & s`
+    pub fn bitwise_and_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_bitwise_and_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise OR operation with a scalar.
+    ///
+    /// The scalar is converted to the backend's int element type, which panics if it can't be
+    /// represented by that type.
+    ///
+    /// `y = x | s`
+    pub fn bitwise_or_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_bitwise_or_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise XOR operation with a scalar.
+    ///
+    /// The scalar is converted to the backend's int element type, which panics if it can't be
+    /// represented by that type.
+    ///
+    /// `y = x ^ s`
+    pub fn bitwise_xor_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_bitwise_xor_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise NOT operation.
+    ///
+    /// Signed integers are interpreted in two's complement, so the result is `-x - 1`.
+    ///
+    /// `y = !x`
+    pub fn bitwise_not(self) -> Self {
+        Tensor::new(B::int_bitwise_not(self.primitive))
+    }
+
+    /// Applies element wise bitwise left shift operation.
+    ///
+    /// The shift amounts that are negative or greater than or equal to the bit width of the int
+    /// element type shift every bit out, which gives `0`.
+    ///
+    /// `y = x1 << x2`
+    pub fn bitwise_left_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "Bitwise Left Shift",
+            &self,
+            &other
+        ));
+        Tensor::new(B::int_bitwise_left_shift(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise left shift operation by a scalar.
+    ///
+    /// A shift amount that is negative or greater than or equal to the bit width of the int
+    /// element type shifts every bit out, which gives `0`.
+    ///
+    /// `y = x << s`
+    pub fn bitwise_left_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_bitwise_left_shift_scalar(
+            self.primitive,
+            other.elem(),
+        ))
+    }
+
+    /// Applies element wise bitwise right shift operation.
+    ///
+    /// The shift amounts that are negative or greater than or equal to the bit width of the int
+    /// element type shift every bit out, which gives the sign fill of the element (`0` or `-1`).
+    /// Signed integers are shifted arithmetically.
+    ///
+    /// `y = x1 >> x2`
+    pub fn bitwise_right_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "Bitwise Right Shift",
+            &self,
+            &other
+        ));
+        Tensor::new(B::int_bitwise_right_shift(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise right shift operation by a scalar.
+    ///
+    /// A shift amount that is negative or greater than or equal to the bit width of the int element
+    /// type shifts every bit out, which gives the sign fill of the element (`0` or `-1`). Signed
+    /// integers are shifted arithmetically.
+    ///
+    /// `y = x >> s`
+    pub fn bitwise_right_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Tensor::new(B::int_bitwise_right_shift_scalar(
+            self.primitive,
+            other.elem(),
+        ))
+    }
+
+    /// Applies element wise the remainder operation.
+    ///
+    /// The result follows the floored modulo, like Python's `%`: it has the sign of the divisor,
+    /// so `-7 % 3 == 2` and `7 % -3 == -2`.
+    ///
+    /// `y = x1 % x2`
+    ///
+    /// # Panics
+    ///
+    /// If any divisor is zero. Finding the zero divisors reads a reduction of `other` back from
+    /// the device, so this synchronizes with it. Without `wasm-sync`, the wasm targets can't read
+    /// the reduction and leave zero divisors to the backend.
+    pub fn remainder(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Remainder", &self, &other));
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        check!(TensorCheck::remainder_divisor(
+            other.clone().equal_elem(0).any().into_scalar()
+        ));
+
+        Tensor::new(B::int_remainder(self.primitive, other.primitive))
+    }
+
+    /// Gets the element-wise maximum of the tensor and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([-2, 5, 0], &device);
+    ///     let other = Tensor::<B, 1, Int>::from_ints([1, -3, 0], &device);
+    ///     let output = tensor.maximum(other);
+    ///     println!("{}", output); // [1, 5, 0]
+    /// }
+    /// ```
+    pub fn maximum(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Maximum", &self, &other));
+        Tensor::new(B::int_maximum(self.primitive, other.primitive))
+    }
+
+    /// Gets the element-wise minimum of the tensor and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([-2, 5, 0], &device);
+    ///     let other = Tensor::<B, 1, Int>::from_ints([1, -3, 0], &device);
+    ///     let output = tensor.minimum(other);
+    ///     println!("{}", output); // [-2, -3, 0]
+    /// }
+    /// ```
+    pub fn minimum(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Minimum", &self, &other));
+        Tensor::new(B::int_minimum(self.primitive, other.primitive))
+    }
+
+    /// Compares the tensor element-wise with `other` and reports where they differ.
+    ///
+    /// This reads the data of both tensors, so it is mostly meant for tests and debugging.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let expected = Tensor::<B, 1, Int>::from_ints([1, 2, 3], &device);
+    ///     let actual = Tensor::<B, 1, Int>::from_ints([1, 5, 3], &device);
+    ///     let report = actual.diff_report(expected);
+    ///     println!("{:?}", report); // num_diffs: 1, first_index: Some(1), max_abs_diff: 3
+    /// }
+    /// ```
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn diff_report(self, other: Self) -> IntTensorDiff {
+        B::int_diff_report(self.primitive, other.primitive)
     }
 
     /// Sort the elements by value in ascending order along a given dimension.
@@ -185,3 +2675,14 @@ where
         )
     }
 }
+
+impl<B, const D: usize> core::ops::Rem<Tensor<B, D, Int>> for Tensor<B, D, Int>
+where
+    B: Backend,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Tensor<B, D, Int>) -> Self {
+        Tensor::remainder(self, rhs)
+    }
+}
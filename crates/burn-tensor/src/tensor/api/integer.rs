@@ -0,0 +1,297 @@
+use crate::{
+    backend::Backend,
+    ops::{isqrt_u64, BoolTensor, IntElem, IntTensor, OverflowMode},
+    ElementConversion, Shape, TensorData,
+};
+use alloc::vec::Vec;
+
+use super::{elem_bounds, into_int_data};
+
+/// Computes the element-wise integer square root of the input `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor of non-negative values.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where `output[i] = floor(sqrt(tensor[i]))`.
+///
+/// # Panics
+///
+/// If the tensor contains negative values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn isqrt<B: Backend, const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    let values = data
+        .iter::<i64>()
+        .map(|value| {
+            if value < 0 {
+                panic!("Can't compute the integer square root of negative values, got {value}");
+            }
+            isqrt_u64(value as u64) as i64
+        })
+        .collect::<Vec<_>>();
+
+    B::int_from_data(into_int_data::<B>(values, data.shape), &device)
+}
+
+/// Computes the element-wise integer logarithm of the input `tensor` in the given `base`.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor of positive values.
+/// * `base` - The base of the logarithm, at least 2.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where
+/// `output[i] = floor(log_base(tensor[i]))`.
+///
+/// # Panics
+///
+/// If the tensor contains values that are not positive, or if `base` is smaller than 2.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn ilog<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    base: IntElem<B>,
+) -> IntTensor<B, D> {
+    let base = base.elem::<i64>();
+    assert!(
+        base >= 2,
+        "The base of the logarithm should be at least 2, got {base}"
+    );
+
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    let values = data
+        .iter::<i64>()
+        .map(|value| {
+            if value <= 0 {
+                panic!("Can't compute the integer logarithm of non-positive values, got {value}");
+            }
+            value.ilog(base) as i64
+        })
+        .collect::<Vec<_>>();
+
+    B::int_from_data(into_int_data::<B>(values, data.shape), &device)
+}
+
+/// Raises the elements of `lhs` to the power of the elements of `rhs`, with integer arithmetic.
+///
+/// # Arguments
+///
+/// * `lhs` - The tensor of bases.
+/// * `rhs` - The tensor of non-negative exponents, broadcastable with `lhs`.
+/// * `overflow` - How the powers that don't fit in the int element type are handled.
+///
+/// # Returns
+///
+/// A tensor with the broadcast shape of the inputs containing the powers.
+///
+/// # Panics
+///
+/// If `rhs` contains negative values, or if a power overflows with [OverflowMode::Panicking].
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn pow<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    overflow: OverflowMode,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&lhs);
+    let (lhs, rhs) = broadcast_into_data::<B, D>(lhs, rhs);
+    let bounds = elem_bounds::<IntElem<B>>();
+
+    let values = lhs
+        .iter::<i64>()
+        .zip(rhs.iter::<i64>())
+        .map(|(base, exp)| {
+            if exp < 0 {
+                panic!("Can't raise integers to negative powers, got {exp}");
+            }
+            let (wrapped, overflowed) = overflowing_pow(base, exp as u64, bounds);
+
+            match (overflowed, overflow) {
+                (false, _) | (true, OverflowMode::Wrapping) => wrapped,
+                (true, OverflowMode::Saturating) if base < 0 && exp % 2 == 1 => bounds.0,
+                (true, OverflowMode::Saturating) => bounds.1,
+                (true, OverflowMode::Panicking) => {
+                    panic!("The power {base}^{exp} overflows the int element type")
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    B::int_from_data(into_int_data::<B>(values, lhs.shape), &device)
+}
+
+/// Applies the binary operation `func` element-wise to `lhs` and `rhs`, clamping the results to
+/// the bounds of the int element type.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side tensor, broadcastable with `lhs`.
+/// * `func` - The operation, computed exactly in `i128`.
+///
+/// # Returns
+///
+/// A tensor with the broadcast shape of the inputs containing the saturated results.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn saturating_binary<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    func: fn(i128, i128) -> i128,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&lhs);
+    let (lhs, rhs) = broadcast_into_data::<B, D>(lhs, rhs);
+    let (min, max) = elem_bounds::<IntElem<B>>();
+
+    let values = lhs
+        .iter::<i64>()
+        .zip(rhs.iter::<i64>())
+        .map(|(a, b)| func(a as i128, b as i128).clamp(min as i128, max as i128) as i64)
+        .collect::<Vec<_>>();
+
+    B::int_from_data(into_int_data::<B>(values, lhs.shape), &device)
+}
+
+/// Applies the binary operation `func` element-wise to `lhs` and `rhs`, reporting the results
+/// that overflow the int element type.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side tensor, broadcastable with `lhs`.
+/// * `func` - The operation, computed exactly in `i128`.
+///
+/// # Returns
+///
+/// A tuple of tensors with the broadcast shape of the inputs, containing the results wrapped
+/// around the bounds of the int element type and a mask of the results that overflowed.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn checked_binary<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    func: fn(i128, i128) -> i128,
+) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+    let device = B::int_device(&lhs);
+    let (lhs, rhs) = broadcast_into_data::<B, D>(lhs, rhs);
+    let bounds = elem_bounds::<IntElem<B>>();
+
+    let (values, overflows): (Vec<_>, Vec<_>) = lhs
+        .iter::<i64>()
+        .zip(rhs.iter::<i64>())
+        .map(|(a, b)| {
+            let exact = func(a as i128, b as i128);
+            let overflowed = !(bounds.0 as i128..=bounds.1 as i128).contains(&exact);
+            // Truncating to `i64` wraps modulo 2^64, which is then wrapped again around the
+            // bounds of narrower element types.
+            (wrap_to_bounds(exact as i64, bounds), overflowed)
+        })
+        .unzip();
+
+    (
+        B::int_from_data(into_int_data::<B>(values, lhs.shape.clone()), &device),
+        B::bool_from_data(TensorData::new(overflows, lhs.shape), &device),
+    )
+}
+
+/// Broadcasts `lhs` and `rhs` to their common shape and reads their data.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) fn broadcast_into_data<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+) -> (TensorData, TensorData) {
+    let shape_lhs = B::int_shape(&lhs);
+    let shape_rhs = B::int_shape(&rhs);
+    let mut dims = [0; D];
+    for (d, dim) in dims.iter_mut().enumerate() {
+        *dim = shape_lhs.dims[d].max(shape_rhs.dims[d]);
+    }
+
+    (
+        B::int_into_data(B::int_expand(lhs, Shape::new(dims))).read(),
+        B::int_into_data(B::int_expand(rhs, Shape::new(dims))).read(),
+    )
+}
+
+/// Wraps an `i64` value around the `(min, max)` bounds of a narrower int element type.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) fn wrap_to_bounds(value: i64, (min, max): (i64, i64)) -> i64 {
+    if (min..=max).contains(&value) {
+        return value;
+    }
+
+    let range = max as i128 - min as i128 + 1;
+    ((value as i128 - min as i128).rem_euclid(range) + min as i128) as i64
+}
+
+/// Computes `base^exp` by squaring, returning the result wrapped around the `bounds` and whether
+/// the exact result overflowed them.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+fn overflowing_pow(base: i64, mut exp: u64, bounds: (i64, i64)) -> (i64, bool) {
+    let fits = |value: &i64| (bounds.0..=bounds.1).contains(value);
+    let (mut base_wrapped, mut wrapped) = (base, 1i64);
+    let (mut base_exact, mut exact) = (Some(base).filter(fits), Some(1i64));
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            wrapped = wrapped.wrapping_mul(base_wrapped);
+            exact = exact
+                .zip(base_exact)
+                .and_then(|(acc, base)| acc.checked_mul(base))
+                .filter(fits);
+        }
+        exp >>= 1;
+
+        // The base is only squared when a higher bit remains, so an overflow of the squared base
+        // always carries over to the result.
+        if exp > 0 {
+            base_wrapped = base_wrapped.wrapping_mul(base_wrapped);
+            base_exact = base_exact
+                .and_then(|base| base.checked_mul(base))
+                .filter(fits);
+        }
+    }
+
+    (wrap_to_bounds(wrapped, bounds), exact.is_none())
+}
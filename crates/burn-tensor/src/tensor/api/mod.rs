@@ -1,24 +1,56 @@
 pub(crate) mod check;
 
+mod arg;
 mod argwhere;
 mod autodiff;
 mod base;
 mod bool;
 mod cartesian_grid;
 mod chunk;
+mod count;
+mod diagonal;
 mod float;
+mod gcd;
 mod int;
+mod integer;
 mod kind;
+mod multinomial;
 mod narrow;
 mod numeric;
+mod scan;
+mod scatter;
 mod sort;
+mod topk;
 
+pub(crate) use arg::wide_index_chunk_size;
+pub use arg::{argmax_usize, argmin_usize};
 pub use argwhere::argwhere;
 pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use count::{bincount, bincount_capped, mode_dim, mode_flat, unique_with_counts};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use diagonal::diagonal_scatter;
+pub(crate) use diagonal::{diagonal_flat_indices, diagonal_len};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use gcd::{gcd, lcm};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use integer::pow;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use integer::{checked_binary, ilog, isqrt, saturating_binary};
 pub use kind::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use multinomial::multinomial;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use scan::cumsum_with_slices;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use scan::{cumprod_float, cumsum_saturating, scan};
+pub(crate) use scan::{elem_bounds, for_each_lane, into_int_data};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use scatter::scatter_reduce_with_counts;
 pub use sort::{argsort, sort, sort_with_indices};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) use topk::topk_with_indices;
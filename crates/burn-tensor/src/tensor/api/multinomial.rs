@@ -0,0 +1,109 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Distribution, Shape,
+};
+use alloc::vec::Vec;
+
+use super::{for_each_lane, into_int_data};
+
+/// Draws `num_samples` indices along the last dimension of `weights`, with probabilities
+/// proportional to the weights.
+///
+/// # Arguments
+///
+/// * `weights` - The non-negative weights of each index.
+/// * `num_samples` - The number of indices to draw for each lane of the last dimension.
+/// * `replacement` - Whether an index can be drawn several times. Without replacement, the
+///   weight of a drawn index is set to zero for the following draws.
+///
+/// # Returns
+///
+/// A tensor with the same shape as `weights`, except along the last dimension where the size is
+/// `num_samples`, containing the drawn indices.
+///
+/// # Panics
+///
+/// If the weights are negative, if the weights of a lane sum to zero, or if more samples than
+/// non-zero weights are drawn without replacement.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn multinomial<B: Backend, const D: usize>(
+    weights: FloatTensor<B, D>,
+    num_samples: usize,
+    replacement: bool,
+) -> IntTensor<B, D> {
+    let device = B::float_device(&weights);
+    let data = B::float_into_data(weights).read();
+    let weights = data
+        .iter::<f64>()
+        .inspect(|&weight| {
+            if weight < 0.0 {
+                panic!("Can't sample from negative weights, got {weight}");
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut shape = data.shape.clone();
+    let num_lanes = shape[..D - 1].iter().product::<usize>();
+    shape[D - 1] = num_samples;
+
+    // The uniform values are drawn from the backend so that the samples follow its seed.
+    let uniforms = B::float_random(
+        Shape::new([num_lanes * num_samples]),
+        Distribution::Default,
+        &device,
+    );
+    let uniforms = B::float_into_data(uniforms).read();
+    let mut uniforms = uniforms.iter::<f64>();
+
+    let mut samples = Vec::with_capacity(num_lanes * num_samples);
+    let mut lane_weights = Vec::new();
+
+    for_each_lane(&data.shape, D - 1, |lane| {
+        lane_weights.clear();
+        lane_weights.extend(lane.iter().map(|&index| weights[index]));
+
+        if !replacement {
+            let num_nonzero = lane_weights.iter().filter(|&&weight| weight > 0.0).count();
+            assert!(
+                num_samples <= num_nonzero,
+                "Can't draw {num_samples} samples without replacement from {num_nonzero} \
+                 non-zero weights"
+            );
+        }
+
+        for _ in 0..num_samples {
+            let total = lane_weights.iter().sum::<f64>();
+            assert!(total > 0.0, "Can't sample from weights that sum to zero");
+
+            let target = uniforms.next().unwrap() * total;
+            let mut cumulative = 0.0;
+            // Fall back to the last non-zero weight when rounding leaves the target past the sum.
+            let mut sample = lane_weights
+                .iter()
+                .rposition(|&weight| weight > 0.0)
+                .unwrap();
+            for (index, &weight) in lane_weights.iter().enumerate() {
+                cumulative += weight;
+                if weight > 0.0 && target < cumulative {
+                    sample = index;
+                    break;
+                }
+            }
+
+            if !replacement {
+                lane_weights[sample] = 0.0;
+            }
+            samples.push(sample as i64);
+        }
+    });
+
+    B::int_from_data(into_int_data::<B>(samples, shape), &device)
+}
@@ -1,10 +1,8 @@
 use alloc::vec::Vec;
 
-use crate::alloc::borrow::ToOwned;
-
 use crate::{
     backend::Backend, check, check::TensorCheck, BasicOps, Bool, Distribution, Element,
-    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
+    ElementConversion, Float, Int, IntDistribution, Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -252,6 +250,9 @@ where
     ///
     /// The index tensor should have the same shape as the original tensor except for the dim
     /// specified.
+    ///
+    /// The index tensor can be created from data of any integer type, it is converted to the int
+    /// element type of the backend.
     pub fn gather(self, dim: usize, indices: Tensor<B, D, Int>) -> Self {
         check!(TensorCheck::gather::<D>(
             dim,
@@ -315,6 +316,12 @@ where
         values: Tensor<B, D, K>,
     ) -> Self {
         check!(TensorCheck::select_assign::<D>(dim));
+        check!(TensorCheck::select_assign_values::<D>(
+            dim,
+            &self.shape(),
+            indices.dims()[0],
+            &values.shape()
+        ));
 
         Self::new(K::select_assign(
             self.primitive,
@@ -326,6 +333,8 @@ where
 
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
+    /// The indices are always non-negative.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -387,6 +396,8 @@ where
 
     /// Applies the argmin function along the given dimension and returns an integer tensor.
     ///
+    /// The indices are always non-negative.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -523,11 +534,7 @@ where
     pub fn triu(self, diagonal: i64) -> Self {
         check!(TensorCheck::tri::<{ D }>());
 
-        // last two dimensions
-        let shape = &self.shape().dims[D - 2..].to_owned();
-
-        let mask = Tensor::<B, 2, Bool>::triu_mask(shape, diagonal, &self.device()).unsqueeze();
-        self.mask_fill(mask, 0)
+        Self::new(K::triu(self.primitive, diagonal))
     }
 
     /// Returns the lower triangular part of a matrix (2-D tensor) or batch of matrices input,
@@ -561,11 +568,7 @@ where
     pub fn tril(self, diagonal: i64) -> Self {
         check!(TensorCheck::tri::<{ D }>());
 
-        // last two dimensions
-        let shape = &self.shape().dims[D - 2..].to_owned();
-
-        let mask = Tensor::<B, 2, Bool>::tril_mask(shape, diagonal, &self.device()).unsqueeze();
-        self.mask_fill(mask, 0)
+        Self::new(K::tril(self.primitive, diagonal))
     }
 
     /// Applies element wise power operation with a float Tensor
@@ -655,7 +658,7 @@ where
     ///
     /// A boolean tensor with the same shape as the input tensor.
     pub fn bool(self) -> Tensor<B, D, Bool> {
-        K::not_equal_elem::<D>(self.primitive, 0.elem())
+        K::into_bool::<D>(self.primitive)
     }
 
     /// Create a random tensor of the given shape on the given device where each element is
@@ -802,10 +805,7 @@ where
     ///
     /// * `size` - The size of the square matrix.
     pub fn eye(size: usize, device: &B::Device) -> Self {
-        let indices = Tensor::<B, 1, Int>::arange(0..size as i64, device).unsqueeze();
-        let ones = K::ones([1, size].into(), device);
-        let zeros = K::zeros([size, size].into(), device);
-        Self::new(K::scatter(0, zeros, indices, ones))
+        Self::new(K::eye(size, device))
     }
 }
 
@@ -1305,6 +1305,64 @@ where
         rhs: Self::Elem,
     ) -> Tensor<B, D, Bool>;
 
+    /// Converts a tensor to a boolean tensor, where the non-zero elements are true.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to convert.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the same shape as the input tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For converting a tensor to a boolean tensor, users should prefer the [Tensor::bool](Tensor::bool)
+    /// function, which is more high-level and designed for public use.
+    fn into_bool<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool>;
+
+    /// Keeps the upper triangle of the last two dimensions of the tensor, relative to the given
+    /// diagonal, and sets the other elements to zero.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::triu](Tensor::triu) function, which is more high-level and
+    /// designed for public use.
+    fn triu<const D: usize>(tensor: Self::Primitive<D>, diagonal: i64) -> Self::Primitive<D>;
+
+    /// Keeps the lower triangle of the last two dimensions of the tensor, relative to the given
+    /// diagonal, and sets the other elements to zero.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::tril](Tensor::tril) function, which is more high-level and
+    /// designed for public use.
+    fn tril<const D: usize>(tensor: Self::Primitive<D>, diagonal: i64) -> Self::Primitive<D>;
+
+    /// Creates a square matrix with ones on the diagonal and zeros elsewhere.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::eye](Tensor::eye) function, which is more high-level and
+    /// designed for public use.
+    fn eye(size: usize, device: &B::Device) -> Self::Primitive<2>;
+
     /// Element-wise greater than comparison between two tensors.
     ///
     /// # Arguments
@@ -2136,7 +2194,10 @@ impl<B: Backend> Numeric<B> for Int {
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D> {
-        B::int_remainder_scalar(lhs, rhs.elem())
+        let rhs: B::IntElem = rhs.elem();
+        check!(TensorCheck::remainder_divisor(rhs.elem::<i64>() == 0));
+
+        B::int_remainder_scalar(lhs, rhs)
     }
     fn mul<const D: usize>(
         lhs: Self::Primitive<D>,
@@ -2199,6 +2260,18 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Tensor<B, D, Bool> {
         Tensor::new(B::int_not_equal_elem(lhs, rhs))
     }
+    fn into_bool<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool> {
+        Tensor::new(B::int_into_bool(tensor))
+    }
+    fn triu<const D: usize>(tensor: Self::Primitive<D>, diagonal: i64) -> Self::Primitive<D> {
+        B::int_triu(tensor, diagonal)
+    }
+    fn tril<const D: usize>(tensor: Self::Primitive<D>, diagonal: i64) -> Self::Primitive<D> {
+        B::int_tril(tensor, diagonal)
+    }
+    fn eye(size: usize, device: &B::Device) -> Self::Primitive<2> {
+        B::int_eye(size, device)
+    }
     fn greater<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -2404,6 +2477,18 @@ impl<B: Backend> Numeric<B> for Int {
         distribution: Distribution,
         device: &<B as Backend>::Device,
     ) -> Self::Primitive<D> {
+        let distribution = match distribution {
+            Distribution::Default => IntDistribution::Uniform { low: 0, high: 255 },
+            Distribution::Uniform(low, high) => IntDistribution::Uniform {
+                low: low.ceil() as i64,
+                high: high.ceil() as i64,
+            },
+            Distribution::Bernoulli(p) => IntDistribution::Bernoulli { p },
+            Distribution::Normal(_, _) => {
+                return B::float_into_int(B::float_random(shape, distribution, device))
+            }
+        };
+
         B::int_random(shape, distribution, device)
     }
 
@@ -2545,6 +2630,27 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> Tensor<B, D, Bool> {
         Tensor::new(B::float_not_equal_elem(lhs, rhs))
     }
+    fn into_bool<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, D, Bool> {
+        Tensor::new(B::float_not_equal_elem(tensor, 0.elem()))
+    }
+    fn triu<const D: usize>(tensor: Self::Primitive<D>, diagonal: i64) -> Self::Primitive<D> {
+        let shape = B::float_shape(&tensor);
+        let mask =
+            Tensor::<B, D, Bool>::triu_mask(shape.clone(), diagonal, &B::float_device(&tensor));
+        B::float_mask_fill(tensor, mask.expand(shape).into_primitive(), 0.elem())
+    }
+    fn tril<const D: usize>(tensor: Self::Primitive<D>, diagonal: i64) -> Self::Primitive<D> {
+        let shape = B::float_shape(&tensor);
+        let mask =
+            Tensor::<B, D, Bool>::tril_mask(shape.clone(), diagonal, &B::float_device(&tensor));
+        B::float_mask_fill(tensor, mask.expand(shape).into_primitive(), 0.elem())
+    }
+    fn eye(size: usize, device: &B::Device) -> Self::Primitive<2> {
+        let indices = Tensor::<B, 1, Int>::arange(0..size as i64, device).unsqueeze::<2>();
+        let ones = B::float_ones([1, size].into(), device);
+        let zeros = B::float_zeros([size, size].into(), device);
+        B::float_scatter(0, zeros, indices.into_primitive(), ones)
+    }
     fn greater<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -0,0 +1,225 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatElem, FloatTensor, IntElem, IntTensor, ScanOp},
+    Element, ElementConversion, TensorData,
+};
+use alloc::vec::Vec;
+
+/// Computes the cumulative sum of the elements of the input `tensor` along a given dimension,
+/// saturating instead of overflowing.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+/// * `max` - The value at which the running sum saturates.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the running sum along
+/// `dim` clamped to `max` and to the bounds of the int element type.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn cumsum_saturating<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+    max: IntElem<B>,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let (min_elem, max_elem) = elem_bounds::<IntElem<B>>();
+    let max = max.elem::<i64>().min(max_elem);
+
+    let mut values = data.iter::<i64>().collect::<Vec<_>>();
+    for_each_lane(&data.shape, dim, |lane| {
+        let mut acc = 0i64;
+        for &index in lane {
+            acc = acc.saturating_add(values[index]).clamp(min_elem, max);
+            values[index] = acc;
+        }
+    });
+
+    B::int_from_data(into_int_data::<B>(values, data.shape), &device)
+}
+
+/// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the sum of the
+/// elements preceding it along `dim`, itself included.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// It stays on the device, but adds the slices along `dim` one after the other. It is not designed for
+/// direct usage by users, and not recommended to import or use this function directly.
+pub fn cumsum_with_slices<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+) -> IntTensor<B, D> {
+    let size = B::int_shape(&tensor).dims[dim];
+
+    if size <= 1 {
+        return tensor;
+    }
+
+    let mut sum = B::int_narrow(tensor.clone(), dim, 0, 1);
+    let mut slices = Vec::with_capacity(size);
+    slices.push(sum.clone());
+
+    for index in 1..size {
+        sum = B::int_add(sum, B::int_narrow(tensor.clone(), dim, index, 1));
+        slices.push(sum.clone());
+    }
+
+    B::int_cat(slices, dim)
+}
+
+/// Computes a cumulative scan of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+/// * `op` - The accumulated operation.
+/// * `exclusive` - Whether the current element is excluded from its accumulated value.
+/// * `reverse` - Whether to accumulate from the last element to the first one.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor containing the accumulated values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn scan<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+    op: ScanOp,
+    exclusive: bool,
+    reverse: bool,
+) -> IntTensor<B, D> {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let (min_elem, max_elem) = elem_bounds::<IntElem<B>>();
+
+    let (identity, func): (i64, fn(i64, i64) -> i64) = match op {
+        ScanOp::Sum => (0, i64::wrapping_add),
+        ScanOp::Prod => (1, i64::wrapping_mul),
+        ScanOp::Max => (min_elem, i64::max),
+        ScanOp::Min => (max_elem, i64::min),
+    };
+
+    let mut values = data.iter::<i64>().collect::<Vec<_>>();
+    for_each_lane(&data.shape, dim, |lane| {
+        let mut acc = identity;
+        let mut accumulate = |index: usize| {
+            let value = values[index];
+            if exclusive {
+                values[index] = acc;
+                acc = func(acc, value);
+            } else {
+                acc = func(acc, value);
+                values[index] = acc;
+            }
+        };
+
+        match reverse {
+            true => lane.iter().rev().for_each(|&index| accumulate(index)),
+            false => lane.iter().for_each(|&index| accumulate(index)),
+        }
+    });
+
+    B::int_from_data(into_int_data::<B>(values, data.shape), &device)
+}
+
+/// Computes the cumulative product of the elements of the input `tensor` along a given dimension,
+/// accumulating in floating point to avoid overflows.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A float tensor with the same shape as the input tensor containing the running products.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn cumprod_float<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    dim: usize,
+) -> FloatTensor<B, D> {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    let mut values = data.iter::<f64>().collect::<Vec<_>>();
+    for_each_lane(&data.shape, dim, |lane| {
+        let mut acc = 1.0;
+        for &index in lane {
+            acc *= values[index];
+            values[index] = acc;
+        }
+    });
+
+    let values = values
+        .into_iter()
+        .map(|v| v.elem::<FloatElem<B>>())
+        .collect::<Vec<_>>();
+
+    B::float_from_data(TensorData::new(values, data.shape), &device)
+}
+
+/// Returns the bounds of the element type, defaulting to the `i64` bounds for non-integer types.
+pub(crate) fn elem_bounds<E: Element>() -> (i64, i64) {
+    E::dtype().int_bounds().unwrap_or((i64::MIN, i64::MAX))
+}
+
+/// Converts the `i64` values computed on the host back to the int element type of the backend.
+pub(crate) fn into_int_data<B: Backend>(values: Vec<i64>, shape: Vec<usize>) -> TensorData {
+    let values = values
+        .into_iter()
+        .map(|v| v.elem::<IntElem<B>>())
+        .collect::<Vec<_>>();
+
+    TensorData::new(values, shape)
+}
+
+/// Calls `func` with the flat indices of each lane of elements along `dim`, in order.
+pub(crate) fn for_each_lane<F: FnMut(&[usize])>(dims: &[usize], dim: usize, mut func: F) {
+    let size = dims[dim];
+    let stride: usize = dims[dim + 1..].iter().product();
+    let num_outer: usize = dims[..dim].iter().product();
+    let mut lane = Vec::with_capacity(size);
+
+    for outer in 0..num_outer {
+        for inner in 0..stride {
+            let offset = outer * size * stride + inner;
+            lane.clear();
+            lane.extend((0..size).map(|i| offset + i * stride));
+            func(&lane);
+        }
+    }
+}
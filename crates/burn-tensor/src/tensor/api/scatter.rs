@@ -0,0 +1,91 @@
+use crate::{
+    backend::Backend,
+    ops::{IntTensor, ReduceKind},
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::into_int_data;
+
+/// Scatters the `value` elements into the input `tensor` at the given `indices` along `dim`,
+/// reducing the values that land on the same position, and counts them.
+///
+/// # Arguments
+///
+/// * `dim` - The dimension to scatter to.
+/// * `tensor` - The input tensor.
+/// * `indices` - The indices, with the same shape as `value`.
+/// * `value` - The values to scatter.
+/// * `reduce` - The reduction applied to the original value and the scattered values.
+///
+/// # Returns
+///
+/// A tuple of tensors with the same shape as the input tensor, containing the reduced values and
+/// the number of scattered values that landed on each position.
+///
+/// # Panics
+///
+/// If an index is out of bounds.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn scatter_reduce_with_counts<B: Backend, const D: usize>(
+    dim: usize,
+    tensor: IntTensor<B, D>,
+    indices: IntTensor<B, D>,
+    value: IntTensor<B, D>,
+    reduce: ReduceKind,
+) -> (IntTensor<B, D>, IntTensor<B, D>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let data_indices = B::int_into_data(indices).read();
+    let data_value = B::int_into_data(value).read();
+
+    let shape = data.shape.clone();
+    let size = shape[dim];
+    let size_indices = data_indices.shape[dim];
+    // The index tensor has the same shape as the tensor, except along `dim`.
+    let stride: usize = shape[dim + 1..].iter().product();
+
+    let mut values = data.iter::<i64>().collect::<Vec<_>>();
+    let mut counts = vec![0i64; values.len()];
+
+    for (position, (index, value)) in data_indices
+        .iter::<i64>()
+        .zip(data_value.iter::<i64>())
+        .enumerate()
+    {
+        assert!(
+            (0..size as i64).contains(&index),
+            "Index {index} is out of bounds for dimension {dim} of size {size}"
+        );
+
+        let outer = position / (size_indices * stride);
+        let inner = position % stride;
+        let target = (outer * size + index as usize) * stride + inner;
+
+        values[target] = match reduce {
+            ReduceKind::Sum | ReduceKind::Mean => values[target].wrapping_add(value),
+            ReduceKind::Prod => values[target].wrapping_mul(value),
+            ReduceKind::Max => values[target].max(value),
+            ReduceKind::Min => values[target].min(value),
+        };
+        counts[target] += 1;
+    }
+
+    if reduce == ReduceKind::Mean {
+        for (value, &count) in values.iter_mut().zip(counts.iter()) {
+            *value /= count + 1;
+        }
+    }
+
+    (
+        B::int_from_data(into_int_data::<B>(values, shape.clone()), &device),
+        B::int_from_data(into_int_data::<B>(counts, shape), &device),
+    )
+}
@@ -0,0 +1,77 @@
+use crate::{backend::Backend, ops::IntTensor};
+use alloc::vec::Vec;
+
+use super::{for_each_lane, into_int_data};
+
+/// Selects the `k` largest or smallest elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `k` - The number of elements to select.
+/// * `dim` - The axis along which to select.
+/// * `largest` - Whether to select the largest elements instead of the smallest ones.
+///
+/// # Returns
+///
+/// The selected values and their indices along `dim`, ordered from the first selected element
+/// to the last one. Equal elements are ordered by lowest index.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn topk_with_indices<B: Backend, const D: usize>(
+    tensor: IntTensor<B, D>,
+    k: usize,
+    dim: usize,
+    largest: bool,
+) -> (IntTensor<B, D>, IntTensor<B, D>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+    let input = data.iter::<i64>().collect::<Vec<_>>();
+
+    let mut dims = data.shape.clone();
+    dims[dim] = k;
+    let stride: usize = dims[dim + 1..].iter().product();
+
+    let mut values = alloc::vec![0i64; input.len() / data.shape[dim].max(1) * k];
+    let mut indices = alloc::vec![0i64; values.len()];
+    let mut lane_index = 0;
+
+    let compare = |a: &(i64, usize), b: &(i64, usize)| {
+        let order = if largest {
+            b.0.cmp(&a.0)
+        } else {
+            a.0.cmp(&b.0)
+        };
+        order.then(a.1.cmp(&b.1))
+    };
+
+    let mut candidates = Vec::new();
+    for_each_lane(&data.shape, dim, |lane| {
+        candidates.clear();
+        candidates.extend(lane.iter().enumerate().map(|(i, &index)| (input[index], i)));
+
+        if k > 0 && k < candidates.len() {
+            candidates.select_nth_unstable_by(k - 1, compare);
+        }
+        candidates.truncate(k);
+        candidates.sort_unstable_by(compare);
+
+        let offset = (lane_index / stride) * k * stride + lane_index % stride;
+        for (i, &(value, index)) in candidates.iter().enumerate() {
+            values[offset + i * stride] = value;
+            indices[offset + i * stride] = index as i64;
+        }
+        lane_index += 1;
+    });
+
+    (
+        B::int_from_data(into_int_data::<B>(values, dims.clone()), &device),
+        B::int_from_data(into_int_data::<B>(indices, dims), &device),
+    )
+}
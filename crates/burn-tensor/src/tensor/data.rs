@@ -48,9 +48,13 @@ impl TensorData {
         }
     }
 
-    /// Returns the immutable slice view of the tensor data.
+    /// Returns the immutable slice view of the tensor data, with the values in row-major order.
     pub fn as_slice<E: Element>(&self) -> Result<&[E], DataError> {
         if E::dtype() == self.dtype {
+            if self.value.is_empty() {
+                // The empty byte buffer isn't necessarily aligned for `E`.
+                return Ok(&[]);
+            }
             bytemuck::checked::try_cast_slice(&self.value).map_err(DataError::CastError)
         } else {
             Err(DataError::TypeMismatch(format!(
@@ -67,6 +71,10 @@ impl TensorData {
     /// If the target element type is different from the stored element type.
     pub fn as_mut_slice<E: Element>(&mut self) -> Result<&mut [E], DataError> {
         if E::dtype() == self.dtype {
+            if self.value.is_empty() {
+                // The empty byte buffer isn't necessarily aligned for `E`.
+                return Ok(&mut []);
+            }
             bytemuck::checked::try_cast_slice_mut(&mut self.value).map_err(DataError::CastError)
         } else {
             Err(DataError::TypeMismatch(format!(
@@ -77,14 +85,25 @@ impl TensorData {
         }
     }
 
-    /// Returns the tensor data as a vector of scalar values.
+    /// Returns the tensor data as a vector of scalar values, in row-major order.
     pub fn to_vec<E: Element>(&self) -> Result<Vec<E>, DataError> {
         Ok(self.as_slice()?.to_vec())
     }
 
+    /// Converts the tensor data into a vector of scalar values, in row-major order.
+    ///
+    /// The values are stored as bytes, which aren't necessarily aligned for `E`, so they are
+    /// copied into a new vector.
+    pub fn into_vec<E: Element>(self) -> Result<Vec<E>, DataError> {
+        self.to_vec()
+    }
+
     /// Returns an iterator over the values of the tensor data.
     pub fn iter<E: Element>(&self) -> Box<dyn Iterator<Item = E> + '_> {
-        if E::dtype() == self.dtype {
+        if self.value.is_empty() {
+            // The empty byte buffer isn't necessarily aligned for the stored element type.
+            Box::new(core::iter::empty())
+        } else if E::dtype() == self.dtype {
             Box::new(bytemuck::checked::cast_slice(&self.value).iter().copied())
         } else {
             match self.dtype {
@@ -154,6 +173,28 @@ impl TensorData {
         shape.iter().product()
     }
 
+    /// Reinterprets the tensor data with a new shape, keeping the values untouched.
+    ///
+    /// # Panics
+    ///
+    /// If the new shape doesn't have the same number of elements as the current one.
+    pub fn reshape<S: Into<Vec<usize>>>(mut self, shape: S) -> Self {
+        let shape = shape.into();
+        let num_elements = Self::numel(&shape);
+        assert_eq!(
+            num_elements,
+            self.num_elements(),
+            "Can't reshape data of {} elements with shape {:?} into shape {:?} of {} elements",
+            self.num_elements(),
+            self.shape,
+            shape,
+            num_elements
+        );
+
+        self.shape = shape;
+        self
+    }
+
     /// Populates the data with random values.
     pub fn random<E: Element, R: RngCore, S: Into<Vec<usize>>>(
         shape: S,
@@ -766,6 +807,56 @@ impl<const D: usize> Data<usize, D> {
     }
 }
 
+/// Converts the shape of deserialized data, checking that it matches the rank and the number of
+/// elements.
+fn deserialized_shape<const D: usize>(
+    shape: &[usize],
+    num_elements: usize,
+) -> Result<Shape<D>, String> {
+    if shape.len() != D {
+        return Err(format!(
+            "The deserialized shape {shape:?} doesn't have {D} dimensions"
+        ));
+    }
+
+    let mut dims = [0; D];
+    dims.copy_from_slice(shape);
+    let shape = Shape::new(dims);
+    if shape.num_elements() != num_elements {
+        return Err(format!(
+            "The deserialized shape {:?} has {} elements, but {} values were provided",
+            shape.dims,
+            shape.num_elements(),
+            num_elements
+        ));
+    }
+
+    Ok(shape)
+}
+
+#[allow(deprecated)]
+impl<E: serde::Serialize, const D: usize> serde::Serialize for Data<E, D> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Data", 2)?;
+        state.serialize_field("value", &self.value)?;
+        state.serialize_field("shape", &self.shape.dims[..])?;
+        state.end()
+    }
+}
+
+#[allow(deprecated)]
+impl<'de, E: serde::Deserialize<'de>, const D: usize> serde::Deserialize<'de> for Data<E, D> {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let data = DataSerialize::<E>::deserialize(deserializer)?;
+        let shape =
+            deserialized_shape(&data.shape, data.value.len()).map_err(serde::de::Error::custom)?;
+
+        Ok(Data::new(data.value, shape))
+    }
+}
+
 #[allow(deprecated)]
 impl<E: Clone, const D: usize> From<&DataSerialize<E>> for Data<E, D> {
     fn from(data: &DataSerialize<E>) -> Self {
@@ -932,4 +1023,95 @@ mod tests {
 
         data1.assert_approx_eq(&data2, 2);
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_round_trip_data_through_json() {
+        let data = Data::<i64, 2>::from([[1, -2, 3], [4, 5, -6]]);
+
+        let json = serde_json::to_string(&data).unwrap();
+        let deserialized: Data<i64, 2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, data);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_deserialize_data_from_data_serialize_json() {
+        let data = Data::<i64, 2>::from([[1, -2, 3], [4, 5, -6]]);
+
+        let json = serde_json::to_string(&data.serialize()).unwrap();
+        let deserialized: Data<i64, 2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, data);
+    }
+
+    #[test]
+    fn should_round_trip_tensor_data_through_json() {
+        let data = TensorData::from([[1i64, -2, 3], [4, 5, -6]]);
+
+        let json = serde_json::to_string(&data).unwrap();
+        let deserialized: TensorData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, data);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_reject_deserialized_data_with_mismatched_shape() {
+        let json = r#"{"value":[1,2,3,4,5],"shape":[2,3]}"#;
+
+        let error = serde_json::from_str::<Data<i64, 2>>(json).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("has 6 elements, but 5 values were provided"));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn should_reject_deserialized_data_with_mismatched_rank() {
+        let json = r#"{"value":[1,2,3,4,5,6],"shape":[6]}"#;
+
+        let error = serde_json::from_str::<Data<i64, 2>>(json).unwrap_err();
+
+        assert!(error.to_string().contains("doesn't have 2 dimensions"));
+    }
+
+    #[test]
+    fn should_return_values_in_row_major_order() {
+        let data = TensorData::from([[1i64, 2, 3], [4, 5, 6]]);
+        let expected = vec![1i64, 2, 3, 4, 5, 6];
+
+        assert_eq!(data.as_slice::<i64>().unwrap(), expected.as_slice());
+        assert_eq!(data.to_vec::<i64>().unwrap(), expected);
+        assert_eq!(data.into_vec::<i64>().unwrap(), expected);
+    }
+
+    #[test]
+    fn should_reject_vec_of_another_element_type() {
+        let data = TensorData::from([[1i64, 2, 3], [4, 5, 6]]);
+
+        assert!(matches!(
+            data.into_vec::<f32>(),
+            Err(DataError::TypeMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn should_reshape_without_touching_values() {
+        let data = TensorData::from([1i64, 2, 3, 4, 5, 6]);
+
+        let data = data.reshape([2, 3]);
+
+        assert_eq!(data, TensorData::from([[1i64, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    #[should_panic = "Can't reshape data of 6 elements with shape [6] into shape [4, 2] of 8 elements"]
+    fn should_reject_reshape_with_mismatched_elements() {
+        let data = TensorData::from([1i64, 2, 3, 4, 5, 6]);
+
+        let _data = data.reshape([4, 2]);
+    }
 }
@@ -18,6 +18,35 @@ pub enum Distribution {
     Normal(f64, f64),
 }
 
+/// Distribution for random value of an int tensor.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum IntDistribution {
+    /// Uniform distribution over the half-open range `[low, high)`.
+    Uniform {
+        /// The lowest value, inclusive.
+        low: i64,
+        /// The highest value, exclusive.
+        high: i64,
+    },
+
+    /// Bernoulli distribution, where the value is `1` with probability `p` and `0` otherwise.
+    Bernoulli {
+        /// The probability of sampling `1`.
+        p: f64,
+    },
+}
+
+impl From<IntDistribution> for Distribution {
+    fn from(distribution: IntDistribution) -> Self {
+        match distribution {
+            IntDistribution::Uniform { low, high } => {
+                Distribution::Uniform(low as f64, high as f64)
+            }
+            IntDistribution::Bernoulli { p } => Distribution::Bernoulli(p),
+        }
+    }
+}
+
 /// Distribution sampler for random value of a tensor.
 #[derive(new)]
 pub struct DistributionSampler<'a, E, R>
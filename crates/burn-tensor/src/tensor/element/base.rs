@@ -257,3 +257,22 @@ pub enum DType {
     U8,
     Bool,
 }
+
+impl DType {
+    /// Returns the minimum and maximum values representable by the data type, or `None` if it
+    /// isn't an integer data type.
+    ///
+    /// The bounds of `U64` are capped to `i64::MAX`.
+    pub fn int_bounds(&self) -> Option<(i64, i64)> {
+        match self {
+            DType::I64 => Some((i64::MIN, i64::MAX)),
+            DType::I32 => Some((i32::MIN as i64, i32::MAX as i64)),
+            DType::I16 => Some((i16::MIN as i64, i16::MAX as i64)),
+            DType::I8 => Some((i8::MIN as i64, i8::MAX as i64)),
+            DType::U64 => Some((0, i64::MAX)),
+            DType::U32 => Some((0, u32::MAX as i64)),
+            DType::U8 => Some((0, u8::MAX as i64)),
+            _ => None,
+        }
+    }
+}
@@ -83,7 +83,7 @@ pub trait BoolTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The int tensor with the same data as the bool tensor.
+    /// The int tensor with the same data as the bool tensor, where true is `1` and false is `0`.
     fn bool_into_int<const D: usize>(tensor: BoolTensor<B, D>) -> IntTensor<B, D>;
 
     /// Converts bool tensor to float tensor.
@@ -1,16 +1,146 @@
 use super::cat::cat_with_slice_assign;
-use super::repeat::repeat_with_slice_assign;
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::cast::ToElement;
-use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Int, TensorData};
-use crate::{cartesian_grid, Tensor};
+use crate::{
+    argmax_usize, argmin_usize, cartesian_grid, cumsum_with_slices,
+    tensor::api::diagonal_flat_indices, tensor::api::diagonal_len, tensor::api::unsqueeze_shape,
+    tensor::api::wide_index_chunk_size, tensor::stats, Tensor,
+};
+use crate::{
+    backend::Backend, tensor::Shape, Bool, Distribution, ElementConversion, Int, IntDistribution,
+    TensorData,
+};
 use crate::{tensor::api::chunk, tensor::api::narrow};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
 
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-use crate::{argsort, sort, sort_with_indices};
+use crate::{
+    argsort, bincount, bincount_capped, checked_binary, cumprod_float, cumsum_saturating,
+    diagonal_scatter, gcd, ilog, isqrt, lcm, mode_dim, mode_flat, multinomial, pow,
+    saturating_binary, scan, scatter_reduce_with_counts, sort, sort_with_indices,
+    tensor::api::elem_bounds, topk_with_indices, unique_with_counts,
+};
+
+/// Report of the element-wise differences between two int tensors, see
+/// [int_diff_report](IntTensorOps::int_diff_report).
+#[derive(new, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntTensorDiff {
+    /// The number of elements that differ.
+    pub num_diffs: usize,
+
+    /// The flat (row-major) index of the first element that differs, if any.
+    pub first_index: Option<usize>,
+
+    /// The maximum absolute difference between two elements.
+    pub max_abs_diff: u64,
+}
+
+/// Indices that may not fit the int element type of the backend, returned by
+/// [int_argmax_usize](IntTensorOps::int_argmax_usize).
+///
+/// Each index is `chunk * chunk_size + offset`, where both parts fit the int element type, and
+/// they stay on the device until read with [into_data](WideIndices::into_data).
+///
+/// Int tensors of a backend all share the same element type, so there is no wider int tensor to
+/// hold the indices: the pair of tensors can only be combined into actual indices on the host.
+#[derive(new, Debug, Clone)]
+pub struct WideIndices<B: Backend, const D: usize> {
+    /// The chunk of `chunk_size` elements containing each index.
+    pub chunk: IntTensor<B, D>,
+
+    /// The offset of each index in its chunk.
+    pub offset: IntTensor<B, D>,
+
+    /// The number of elements of every chunk but the last one.
+    pub chunk_size: usize,
+}
+
+impl<B: Backend, const D: usize> WideIndices<B, D> {
+    /// Reads the indices, returning data with the `U64` data type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn into_data(self) -> TensorData {
+        let chunk = B::int_into_data(self.chunk).read();
+        let offset = B::int_into_data(self.offset).read();
+        let indices = chunk
+            .iter::<u64>()
+            .zip(offset.iter::<u64>())
+            .map(|(chunk, offset)| chunk * self.chunk_size as u64 + offset)
+            .collect::<Vec<_>>();
+
+        TensorData::new(indices, chunk.shape)
+    }
+}
+
+/// Error returned by [int_clamp_checked](IntTensorOps::int_clamp_checked).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClampError {
+    /// The minimum value is greater than the maximum value.
+    InvertedBounds {
+        /// The minimum value.
+        min: i64,
+        /// The maximum value.
+        max: i64,
+    },
+}
+
+/// Error returned by [int_arange_step_checked](IntTensorOps::int_arange_step_checked).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArangeError {
+    /// The step is zero, so the range can't be traversed.
+    ZeroStep,
+    /// The range doesn't produce any element (e.g. a reversed range with a positive step).
+    EmptyRange(Range<i64>),
+}
+
+/// Error returned when the result of an int operation doesn't fit in the int element type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError;
+
+/// How an int operation handles the results that don't fit in the int element type, see
+/// [int_pow](IntTensorOps::int_pow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// The result wraps around the bounds of the int element type.
+    Wrapping,
+    /// The result is clamped to the bounds of the int element type.
+    Saturating,
+    /// The operation panics.
+    Panicking,
+}
+
+/// The binary operation accumulated by [int_scan_config](IntTensorOps::int_scan_config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanOp {
+    /// Running sum, the identity is `0`.
+    Sum,
+    /// Running product, the identity is `1`.
+    Prod,
+    /// Running maximum, the identity is the minimum value of the int element type.
+    Max,
+    /// Running minimum, the identity is the maximum value of the int element type.
+    Min,
+}
+
+/// The reduction applied to the values scattered onto the same position, see
+/// [int_scatter_reduce_with_counts](IntTensorOps::int_scatter_reduce_with_counts).
+///
+/// The original value of the tensor takes part in the reduction, and the positions that no index
+/// targets keep their original value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReduceKind {
+    /// Sum of the original value and the scattered values.
+    Sum,
+    /// Product of the original value and the scattered values.
+    Prod,
+    /// Maximum of the original value and the scattered values.
+    Max,
+    /// Minimum of the original value and the scattered values.
+    Min,
+    /// Mean of the original value and the scattered values, rounded toward zero.
+    Mean,
+}
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -74,6 +204,20 @@ pub trait IntTensorOps<B: Backend> {
     /// The tensor with the data.
     fn int_from_data<const D: usize>(data: TensorData, device: &Device<B>) -> IntTensor<B, D>;
 
+    /// Creates a 1D tensor from a slice of values.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the tensor.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The 1D tensor with the values.
+    fn int_from_slice(values: &[IntElem<B>], device: &Device<B>) -> IntTensor<B, 1> {
+        B::int_from_data(TensorData::new(values.to_vec(), [values.len()]), device)
+    }
+
     /// Gets the device of the tensor.
     ///
     /// # Arguments
@@ -101,11 +245,89 @@ pub trait IntTensorOps<B: Backend> {
     /// # Returns
     ///
     /// The tensor with the new shape.
+    ///
+    /// # Remarks
+    ///
+    /// Reshaping a tensor to its current shape should be free: implementations are expected to
+    /// return the same tensor without launching any kernel.
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: IntTensor<B, D1>,
         shape: Shape<D2>,
     ) -> IntTensor<B, D2>;
 
+    /// Collapses the dimensions from `start_dim` to `end_dim` inclusive into a single dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `start_dim` - The first dimension to flatten.
+    /// * `end_dim` - The last dimension to flatten.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the flattened dimensions replaced by their product, which has
+    /// `D1 - (end_dim - start_dim)` dimensions.
+    fn int_flatten<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        start_dim: usize,
+        end_dim: usize,
+    ) -> IntTensor<B, D2> {
+        let dims = B::int_shape(&tensor).dims;
+        let mut new_dims = [0; D2];
+
+        new_dims[..start_dim].copy_from_slice(&dims[..start_dim]);
+        new_dims[start_dim] = dims[start_dim..=end_dim].iter().product();
+        new_dims[start_dim + 1..].copy_from_slice(&dims[end_dim + 1..]);
+
+        B::int_reshape(tensor, Shape::new(new_dims))
+    }
+
+    /// Removes the dimension `dim` of size one.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to remove, which must have a size of one.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the same elements and one dimension less.
+    fn int_squeeze<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        dim: usize,
+    ) -> IntTensor<B, D2> {
+        let dims = B::int_shape(&tensor).dims;
+        let mut new_dims = [0; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim..].copy_from_slice(&dims[dim + 1..]);
+
+        B::int_reshape(tensor, Shape::new(new_dims))
+    }
+
+    /// Inserts a dimension of size one at `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The position of the new dimension in the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the same elements and one dimension more.
+    fn int_unsqueeze<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        dim: usize,
+    ) -> IntTensor<B, D2> {
+        let dims = B::int_shape(&tensor).dims;
+        let mut new_dims = [1; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim + 1..=D1].copy_from_slice(&dims[dim..]);
+
+        B::int_reshape(tensor, Shape::new(new_dims))
+    }
+
     /// Gets the element at the given indices.
     ///
     /// # Arguments
@@ -137,6 +359,39 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B, D1>,
     ) -> IntTensor<B, D1>;
 
+    /// Reverses the elements inside the window given by the ranges, along every dimension whose
+    /// range is flagged as reversed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `ranges` - The window to reverse, as one `(range, reversed)` pair per dimension.
+    ///   Dimensions without a range are fully included in the window.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where the elements inside the window are reversed
+    /// along the flagged dimensions and all the other elements are unchanged.
+    fn int_slice_reverse<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        ranges: [(Range<usize>, bool); D2],
+    ) -> IntTensor<B, D1> {
+        let axes = ranges
+            .iter()
+            .enumerate()
+            .filter_map(|(dim, (_, reversed))| reversed.then_some(dim))
+            .collect::<Vec<_>>();
+
+        if axes.is_empty() {
+            return tensor;
+        }
+
+        let ranges = ranges.map(|(range, _)| range);
+        let window = B::int_flip(B::int_slice(tensor.clone(), ranges.clone()), &axes);
+
+        B::int_slice_assign(tensor, ranges, window)
+    }
+
     /// Converts int tensor to float tensor.
     ///
     /// # Arguments
@@ -148,6 +403,19 @@ pub trait IntTensorOps<B: Backend> {
     /// The int tensor with the same data as the float tensor.
     fn int_into_float<const D: usize>(tensor: IntTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Converts int tensor to bool tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bool tensor that is true where the elements of the int tensor are non-zero.
+    fn int_into_bool<const D: usize>(tensor: IntTensor<B, D>) -> BoolTensor<B, D> {
+        B::int_not_equal_elem(tensor, 0.elem())
+    }
+
     /// Fills the tensor with values from the source tensor if the mask is true at the given
     /// indices.
     ///
@@ -166,6 +434,26 @@ pub trait IntTensorOps<B: Backend> {
         source: IntTensor<B, D>,
     ) -> IntTensor<B, D>;
 
+    /// Selects the elements of `on_true` where the condition is true and the elements of
+    /// `on_false` elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - The condition.
+    /// * `on_true` - The tensor to select from where the condition is true.
+    /// * `on_false` - The tensor to select from where the condition is false.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the inputs containing the selected elements.
+    fn int_where<const D: usize>(
+        condition: BoolTensor<B, D>,
+        on_true: IntTensor<B, D>,
+        on_false: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_mask_where(on_false, condition, on_true)
+    }
+
     /// Fills the tensor with the given value if the mask is true at the given indices.
     ///
     /// # Arguments
@@ -190,12 +478,99 @@ pub trait IntTensorOps<B: Backend> {
     /// * `dim` - The dimension to gather from.
     /// * `tensor` - The tensor.
     /// * `indices` - The indices.
+    ///
+    /// # Remarks
+    ///
+    /// Index tensors always use the int element type of the backend, so indices created from
+    /// data of any integer type (e.g. `i32`) are already widened when the tensor is created.
     fn int_gather<const D: usize>(
         dim: usize,
         tensor: IntTensor<B, D>,
         indices: IntTensor<B, D>,
     ) -> IntTensor<B, D>;
 
+    /// Gather elements from the tensor at the given indices, broadcasting the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to gather from.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices, where every dimension other than `dim` has either the same size
+    ///   as the tensor or a size of 1.
+    ///
+    /// # Returns
+    ///
+    /// The gathered elements, with the indices broadcasted to the shape of the tensor except
+    /// along `dim`.
+    fn int_gather_broadcast<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        let shape_indices = B::int_shape(&indices);
+        let mut shape = B::int_shape(&tensor);
+        shape.dims[dim] = shape_indices.dims[dim];
+
+        let indices = if shape == shape_indices {
+            indices
+        } else {
+            B::int_expand(indices, shape)
+        };
+
+        B::int_gather(dim, tensor, indices)
+    }
+
+    /// Takes the elements of the tensor at the given indices along a dimension, like PyTorch's
+    /// `take_along_dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices along `dim`, where every other dimension has either the same size
+    ///   as the tensor or a size of 1, in which case the indices are broadcasted.
+    /// * `dim` - The dimension to take the elements from.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements, with the shape of the tensor except along `dim`, which has the size
+    /// of the indices.
+    fn int_take_along_dim<const D: usize>(
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        dim: usize,
+    ) -> IntTensor<B, D> {
+        B::int_gather_broadcast(dim, tensor, indices)
+    }
+
+    /// Gather elements from the tensor at the given indices, placing a default value where the
+    /// indices are not valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to gather from.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices, whose values are ignored where `valid` is false.
+    /// * `valid` - The mask of the valid indices, with the same shape as the indices.
+    /// * `default` - The value of the output where the indices are not valid.
+    ///
+    /// # Returns
+    ///
+    /// The gathered elements, with `default` where the indices are not valid.
+    fn int_gather_masked<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        valid: BoolTensor<B, D>,
+        default: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let invalid = B::bool_not(valid);
+        // The invalid indices are replaced so that they are never read out of bounds.
+        let indices = B::int_mask_fill(indices, invalid.clone(), 0.elem());
+        let output = B::int_gather(dim, tensor, indices);
+
+        B::int_mask_fill(output, invalid, default)
+    }
+
     /// Scatter a given value to the tensor at the given indices.
     ///
     /// # Arguments
@@ -226,6 +601,11 @@ pub trait IntTensorOps<B: Backend> {
     /// # Returns
     ///
     /// The tensor with the selected elements.
+    ///
+    /// # Remarks
+    ///
+    /// Index tensors always use the int element type of the backend, so indices created from
+    /// data of any integer type (e.g. `i32`) are already widened when the tensor is created.
     fn int_select<const D: usize>(
         tensor: IntTensor<B, D>,
         dim: usize,
@@ -252,40 +632,179 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B, D>,
     ) -> IntTensor<B, D>;
 
+    /// Fill the selected slices along the given dimension corresponding to the given indices
+    /// with a scalar value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to select from.
+    /// * `indices` - The indices.
+    /// * `value` - The value to fill the selected slices with.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected slices set to the given value.
+    fn int_select_fill<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        value: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+        let num_indices = B::int_shape(&indices).dims[0];
+
+        // Mark the selected positions along `dim`, duplicated indices are counted more than once.
+        let selected = B::int_select_assign(
+            B::int_zeros(Shape::new([shape.dims[dim]]), &device),
+            0,
+            indices,
+            B::int_ones(Shape::new([num_indices]), &device),
+        );
+        let mask = B::int_greater_elem(selected, 0.elem());
+
+        let mut mask_shape = Shape::new([1; D]);
+        mask_shape.dims[dim] = shape.dims[dim];
+        let mask = B::bool_expand(B::bool_reshape(mask, mask_shape), shape);
+
+        B::int_mask_fill(tensor, mask, value)
+    }
+
+    /// Assign the selected elements along the given dimension corresponding to the given indices
+    /// to the given value, where negative indices count from the end of the dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension to select from.
+    /// * `indices` - The indices, where `-1` refers to the last element of the dimension.
+    /// * `value` - The value.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the selected elements assigned to the given value.
+    ///
+    /// # Panics
+    ///
+    /// If an index is out of bounds after resolving the negative indices.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_select_assign_wrapped<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        let size = B::int_shape(&tensor).dims[dim] as i64;
+        let device = B::int_device(&indices);
+        let data = B::int_into_data(indices).read();
+        let indices = data
+            .iter::<i64>()
+            .map(|index| {
+                let resolved = if index < 0 { index + size } else { index };
+                assert!(
+                    (0..size).contains(&resolved),
+                    "Index {index} is out of bounds for dimension {dim} of size {size}"
+                );
+                resolved.elem::<IntElem<B>>()
+            })
+            .collect::<Vec<_>>();
+        let indices = B::int_from_data(TensorData::new(indices, data.shape), &device);
+
+        B::int_select_assign(tensor, dim, indices, value)
+    }
+
+    /// Adds the slices of `source` into the tensor at the given indices along a dimension,
+    /// accumulating the slices whose indices are repeated.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate into.
+    /// * `dim` - The dimension along which to add.
+    /// * `indices` - The index in `tensor` of each slice of `source` along `dim`.
+    /// * `source` - The slices to add, with the shape of the tensor except along `dim`, which has
+    ///   the number of indices.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the slices of `source` added at the given indices.
+    fn int_index_add<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        source: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        // The select assign operation sums the assigned values into the tensor.
+        B::int_select_assign(tensor, dim, indices, source)
+    }
+
     /// Repeats the tensor along the given dimension the given number of times.
     ///
     /// # Arguments
     ///
     /// * `tensor` - The tensor.
-    /// * `dim` - The dimension to repeat.
+    /// * `dim` - The dimension to repeat, which can have any size.
     /// * `times` - The number of times to repeat.
     ///
     /// # Returns
     ///
-    /// The tensor with the given dimension repeated the given number of times.
+    /// The tensor concatenated `times` times with itself along `dim`, so a dimension of size `s`
+    /// has a size of `s * times`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation performs a single [int_cat](IntTensorOps::int_cat) of `times`
+    /// handles to the tensor, so the output is allocated once by backends with a native
+    /// concatenation.
     fn int_repeat<const D: usize>(
         tensor: IntTensor<B, D>,
         dim: usize,
         times: usize,
     ) -> IntTensor<B, D> {
-        repeat_with_slice_assign::<B, D, Int>(
-            Tensor::<B, D, Int>::from_primitive(tensor),
-            dim,
-            times,
-        )
-        .into_primitive()
+        if times == 0 {
+            let mut shape = B::int_shape(&tensor);
+            shape.dims[dim] = 0;
+            return B::int_empty(shape, &B::int_device(&tensor));
+        }
+
+        B::int_cat(alloc::vec![tensor; times], dim)
+    }
+
+    /// Tiles the tensor by repeating it along every dimension at once, like `numpy.tile`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `reps` - The positive number of times the tensor is repeated along each dimension.
+    ///
+    /// # Returns
+    ///
+    /// The tiled tensor, where dimension `i` has a size of `reps[i]` times its original size.
+    fn int_tile<const D: usize>(tensor: IntTensor<B, D>, reps: [usize; D]) -> IntTensor<B, D> {
+        reps.iter()
+            .enumerate()
+            .filter(|(_, &times)| times != 1)
+            .fold(tensor, |tensor, (dim, &times)| {
+                B::int_cat(alloc::vec![tensor; times], dim)
+            })
     }
 
     /// Concatenates the given tensors along the given dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensors` - The tensors.
+    /// * `tensors` - The tensors, which may have a length of zero along `dim`.
     /// * `dim` - The dimension to concatenate along.
     ///
     /// # Returns
     ///
-    /// The concatenated tensor.
+    /// The concatenated tensor, to which the zero-length tensors contribute nothing.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation, like [Tensor::cat](crate::Tensor::cat), returns a single
+    /// tensor unchanged without copying its data, and panics if `tensors` is empty since the
+    /// output shape would be undefined. Backend overrides are encouraged to do the same.
     fn int_cat<const D: usize>(tensors: Vec<IntTensor<B, D>>, dim: usize) -> IntTensor<B, D> {
         cat_with_slice_assign::<B, D, Int>(
             tensors
@@ -297,6 +816,37 @@ pub trait IntTensorOps<B: Backend> {
         .into_primitive()
     }
 
+    /// Concatenates the given tensors along a new dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensors` - The tensors, which must all have the same shape.
+    /// * `dim` - The new dimension to stack along.
+    ///
+    /// # Returns
+    ///
+    /// The stacked tensor, with a dimension of size `tensors.len()` inserted at `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation unsqueezes each tensor and concatenates them. Backends are
+    /// encouraged to write all inputs directly into a single preallocated output, which is much
+    /// faster when stacking many small tensors.
+    fn int_stack<const D: usize, const D2: usize>(
+        tensors: Vec<IntTensor<B, D>>,
+        dim: usize,
+    ) -> IntTensor<B, D2> {
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| {
+                let shape = B::int_shape(&tensor);
+                B::int_reshape(tensor, unsqueeze_shape::<D, D2>(&shape, dim))
+            })
+            .collect();
+
+        B::int_cat(tensors, dim)
+    }
+
     /// Element-wise equality comparison.
     ///
     /// # Arguments
@@ -465,21 +1015,126 @@ pub trait IntTensorOps<B: Backend> {
         rhs: IntElem<B>,
     ) -> BoolTensor<B, D>;
 
-    // ====  NUMERIC ==== //
-
-    /// Element-wise addition.
+    /// Element-wise equality comparison with a scalar, returning an integer indicator.
     ///
     /// # Arguments
     ///
     /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The result of the addition.
-    fn int_add<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
-
-    /// Element-wise addition with a scalar.
+    /// The int tensor containing `1` where the comparison holds and `0` elsewhere.
+    fn int_equal_elem_int<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::bool_into_int(B::int_equal_elem(lhs, rhs))
+    }
+
+    /// Element-wise non-equality comparison with a scalar, returning an integer indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The int tensor containing `1` where the comparison holds and `0` elsewhere.
+    fn int_not_equal_elem_int<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::bool_into_int(B::int_not_equal_elem(lhs, rhs))
+    }
+
+    /// Element-wise greater than comparison with a scalar, returning an integer indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The int tensor containing `1` where the comparison holds and `0` elsewhere.
+    fn int_greater_elem_int<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::bool_into_int(B::int_greater_elem(lhs, rhs))
+    }
+
+    /// Element-wise greater than or equal comparison with a scalar, returning an integer indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The int tensor containing `1` where the comparison holds and `0` elsewhere.
+    fn int_greater_equal_elem_int<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::bool_into_int(B::int_greater_equal_elem(lhs, rhs))
+    }
+
+    /// Element-wise less than comparison with a scalar, returning an integer indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The int tensor containing `1` where the comparison holds and `0` elsewhere.
+    fn int_lower_elem_int<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::bool_into_int(B::int_lower_elem(lhs, rhs))
+    }
+
+    /// Element-wise less than or equal comparison with a scalar, returning an integer indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The int tensor containing `1` where the comparison holds and `0` elsewhere.
+    fn int_lower_equal_elem_int<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::bool_into_int(B::int_lower_equal_elem(lhs, rhs))
+    }
+
+    // ====  NUMERIC ==== //
+
+    /// Element-wise addition.
+    ///
+    /// The tensors are broadcasted: every dimension must have the same size in both tensors, or a
+    /// size of 1 in one of them, in which case it is repeated to the size of the other one.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the addition, with the broadcasted shape of both tensors.
+    fn int_add<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
+
+    /// Element-wise addition with a scalar.
     ///
     /// # Arguments
     ///
@@ -522,6 +1177,33 @@ pub trait IntTensorOps<B: Backend> {
         B::float_into_int(B::float_powf(B::int_into_float(lhs), rhs))
     }
 
+    /// Element-wise power with a IntTensor, computed with integer arithmetic.
+    ///
+    /// Unlike [int_powi](IntTensorOps::int_powi), the power doesn't go through floats, so it stays
+    /// exact for large results.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side IntTensor.
+    /// * `rhs` - The right hand side IntTensor of non-negative exponents.
+    /// * `overflow` - How the powers that don't fit in the int element type are handled.
+    ///
+    /// # Returns
+    ///
+    /// The elements of `lhs` raised to the power of the elements of `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// If a power overflows with [OverflowMode::Panicking].
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_pow<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+        overflow: OverflowMode,
+    ) -> IntTensor<B, D> {
+        pow::<B, D>(lhs, rhs, overflow)
+    }
+
     /// Element-wise power with a scalar.
     ///
     /// # Arguments
@@ -599,8 +1281,64 @@ pub trait IntTensorOps<B: Backend> {
         Self::int_clamp_min(Self::int_clamp_max(tensor, max), min)
     }
 
+    /// Clamps a tensor between a minimum and maximum value, validating the bounds first.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `min` - The minimum value.
+    /// * `max` - The maximum value.
+    ///
+    /// # Returns
+    ///
+    /// The clamped tensor, or an error if `min` is greater than `max`, since the result would
+    /// silently be `min` everywhere.
+    fn int_clamp_checked<const D: usize>(
+        tensor: IntTensor<B, D>,
+        min: IntElem<B>,
+        max: IntElem<B>,
+    ) -> Result<IntTensor<B, D>, ClampError> {
+        let (min_value, max_value) = (min.to_i64(), max.to_i64());
+
+        if min_value > max_value {
+            return Err(ClampError::InvertedBounds {
+                min: min_value,
+                max: max_value,
+            });
+        }
+
+        Ok(Self::int_clamp(tensor, min, max))
+    }
+
+    /// Clamps a tensor between element-wise minimum and maximum values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to clamp.
+    /// * `min` - The minimum values, broadcast to the shape of the tensor.
+    /// * `max` - The maximum values, broadcast to the shape of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The clamped tensor. Like [int_clamp](IntTensorOps::int_clamp), the minimum value is kept
+    /// where it is greater than the maximum value.
+    fn int_clamp_tensor<const D: usize>(
+        tensor: IntTensor<B, D>,
+        min: IntTensor<B, D>,
+        max: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let min = B::int_expand(min, shape.clone());
+        let max = B::int_expand(max, shape);
+
+        B::int_maximum(B::int_minimum(tensor, max), min)
+    }
+
     /// Element-wise subtraction.
     ///
+    /// The tensors are broadcasted: every dimension must have the same size in both tensors, or a
+    /// size of 1 in one of them, in which case it is repeated to the size of the other one.
+    ///
     /// # Arguments
     ///
     /// * `lhs` - The left hand side tensor.
@@ -608,7 +1346,7 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The result of the subtraction.
+    /// The result of the subtraction, with the broadcasted shape of both tensors.
     fn int_sub<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
 
     /// Element-wise subtraction with a scalar.
@@ -625,6 +1363,9 @@ pub trait IntTensorOps<B: Backend> {
 
     /// Element-wise multiplication.
     ///
+    /// The tensors are broadcasted: every dimension must have the same size in both tensors, or a
+    /// size of 1 in one of them, in which case it is repeated to the size of the other one.
+    ///
     /// # Arguments
     ///
     /// * `lhs` - The left hand side tensor.
@@ -632,7 +1373,7 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The result of the multiplication.
+    /// The result of the multiplication, with the broadcasted shape of both tensors.
     fn int_mul<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
 
     /// Element-wise multiplication with a scalar.
@@ -647,651 +1388,2923 @@ pub trait IntTensorOps<B: Backend> {
     /// The result of the multiplication.
     fn int_mul_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
 
-    /// Element-wise division.
+    /// Element-wise addition, which may reuse the storage of `lhs` for the result.
     ///
     /// # Arguments
     ///
-    /// * `lhs` - The left hand side tensor.
+    /// * `lhs` - The left hand side tensor, which may be overwritten by the result.
     /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The result of the division.
-    fn int_div<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
+    /// The same result as [int_add](IntTensorOps::int_add).
+    ///
+    /// # Remarks
+    ///
+    /// Backends can override this function to accumulate in place when the buffer of `lhs` isn't
+    /// shared with another tensor. Whether the storage is reused is an implementation detail, the
+    /// default implementation simply calls [int_add](IntTensorOps::int_add).
+    fn int_add_inplace<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        Self::int_add(lhs, rhs)
+    }
 
-    /// Element-wise division with a scalar.
+    /// Element-wise subtraction, which may reuse the storage of `lhs` for the result.
     ///
     /// # Arguments
     ///
-    /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side scalar.
+    /// * `lhs` - The left hand side tensor, which may be overwritten by the result.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The result of the division.
-    fn int_div_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
-
-    /// Element-wise modulus with a scalar.
-    ///
-    /// # Arguments
-    /// * `lhs` - The left hand side tensor.
-    /// * `rhs` - The right hand side scalar.
+    /// The same result as [int_sub](IntTensorOps::int_sub).
     ///
-    /// # Returns
+    /// # Remarks
     ///
-    /// The result of applying the modulus of the scalar to the tensor.
-    fn int_remainder_scalar<const D: usize>(
+    /// Backends can override this function to subtract in place when the buffer of `lhs` isn't
+    /// shared with another tensor. Whether the storage is reused is an implementation detail, the
+    /// default implementation simply calls [int_sub](IntTensorOps::int_sub).
+    fn int_sub_inplace<const D: usize>(
         lhs: IntTensor<B, D>,
-        rhs: IntElem<B>,
-    ) -> IntTensor<B, D>;
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        Self::int_sub(lhs, rhs)
+    }
 
-    /// Element-wise negation.
+    /// Element-wise multiplication, which may reuse the storage of `lhs` for the result.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to negate.
+    /// * `lhs` - The left hand side tensor, which may be overwritten by the result.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The negated tensor.
-    fn int_neg<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
-        Self::int_mul_scalar(tensor, (-1.0).elem::<IntElem<B>>())
+    /// The same result as [int_mul](IntTensorOps::int_mul).
+    ///
+    /// # Remarks
+    ///
+    /// Backends can override this function to multiply in place when the buffer of `lhs` isn't
+    /// shared with another tensor. Whether the storage is reused is an implementation detail, the
+    /// default implementation simply calls [int_mul](IntTensorOps::int_mul).
+    fn int_mul_inplace<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        Self::int_mul(lhs, rhs)
     }
 
-    /// Creates a tensor of zeros.
+    /// Element-wise addition, saturating at the bounds of the int element type instead of
+    /// wrapping around on overflow.
     ///
     /// # Arguments
     ///
-    /// * `shape` - The shape of the tensor.
-    /// * `device` - The device to create the tensor on.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The tensor of zeros.
-    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D>;
+    /// The result of `lhs + rhs`, clamped to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_saturating_add<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        saturating_binary::<B, D>(lhs, rhs, |a, b| a + b)
+    }
 
-    /// Creates a tensor of ones.
+    /// Element-wise addition with a scalar, saturating at the bounds of the int element type
+    /// instead of wrapping around on overflow.
     ///
     /// # Arguments
     ///
-    /// * `shape` - The shape of the tensor.
-    /// * `device` - The device to create the tensor on.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The tensor of ones.
-    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D>;
+    /// The result of `lhs + rhs`, clamped to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_saturating_add_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let rhs = B::int_full(B::int_shape(&lhs), rhs, &B::int_device(&lhs));
+        B::int_saturating_add(lhs, rhs)
+    }
 
-    /// Creates a tensor filled with given value.
+    /// Element-wise subtraction, saturating at the bounds of the int element type instead of
+    /// wrapping around on overflow.
     ///
     /// # Arguments
     ///
-    /// * `shape` - The shape of the tensor.
-    /// * `fill_value` - The value with which to fill the tensor.
-    /// * `device` - The device to create the tensor on.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The tensor filled with given value
-    fn int_full<const D: usize>(
-        shape: Shape<D>,
-        fill_value: IntElem<B>,
-        device: &Device<B>,
+    /// The result of `lhs - rhs`, clamped to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_saturating_sub<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
     ) -> IntTensor<B, D> {
-        Self::int_add_scalar(Self::int_zeros(shape, device), fill_value)
+        saturating_binary::<B, D>(lhs, rhs, |a, b| a - b)
     }
 
-    /// Sums all elements in the tensor.
+    /// Element-wise subtraction with a scalar, saturating at the bounds of the int element type
+    /// instead of wrapping around on overflow.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to sum.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The sum of all elements in the tensor.
-    fn int_sum<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1>;
+    /// The result of `lhs - rhs`, clamped to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_saturating_sub_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let rhs = B::int_full(B::int_shape(&lhs), rhs, &B::int_device(&lhs));
+        B::int_saturating_sub(lhs, rhs)
+    }
 
-    /// Sums all elements in the tensor along a dimension.
+    /// Element-wise multiplication, saturating at the bounds of the int element type instead of
+    /// wrapping around on overflow.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to sum.
-    /// * `dim` - The dimension to sum along.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The sum of all elements in the tensor along the dimension.
-    fn int_sum_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
+    /// The result of `lhs * rhs`, clamped to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_saturating_mul<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        saturating_binary::<B, D>(lhs, rhs, |a, b| a * b)
+    }
 
-    /// Computes the product of all elements in the tensor.
+    /// Element-wise multiplication with a scalar, saturating at the bounds of the int element type
+    /// instead of wrapping around on overflow.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to compute the product of.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The product of all elements in the tensor.
-    fn int_prod<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1>;
+    /// The result of `lhs * rhs`, clamped to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_saturating_mul_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let rhs = B::int_full(B::int_shape(&lhs), rhs, &B::int_device(&lhs));
+        B::int_saturating_mul(lhs, rhs)
+    }
 
-    /// Computes the product of all elements in the tensor along a dimension.
+    /// Element-wise addition, reporting the positions where the result overflows the int element
+    /// type.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to compute the product of.
-    /// * `dim` - The dimension to compute the product along.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The product of all elements in the tensor along the dimension.
-    fn int_prod_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
+    /// A tuple containing the result of `lhs + rhs` wrapped around the bounds of the int element
+    /// type, and a boolean tensor that is `true` where the exact result doesn't fit in the int
+    /// element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_checked_add<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+        checked_binary::<B, D>(lhs, rhs, |a, b| a + b)
+    }
 
-    /// Computes the mean of all elements in the tensor.
+    /// Element-wise subtraction, reporting the positions where the result overflows the int element
+    /// type.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to compute the mean of.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The mean of all elements in the tensor.
-    fn int_mean<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
-        let num_elems = B::int_shape(&tensor).num_elements();
-        B::int_div_scalar(B::int_sum(tensor), (num_elems as i64).elem())
+    /// A tuple containing the result of `lhs - rhs` wrapped around the bounds of the int element
+    /// type, and a boolean tensor that is `true` where the exact result doesn't fit in the int
+    /// element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_checked_sub<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+        checked_binary::<B, D>(lhs, rhs, |a, b| a - b)
     }
 
-    /// Computes the mean of all elements in the tensor along a dimension.
+    /// Element-wise multiplication, reporting the positions where the result overflows the int
+    /// element type.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to compute the mean of.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The mean of all elements in the tensor along the dimension.
-    fn int_mean_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
+    /// A tuple containing the result of `lhs * rhs` wrapped around the bounds of the int element
+    /// type, and a boolean tensor that is `true` where the exact result doesn't fit in the int
+    /// element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_checked_mul<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> (IntTensor<B, D>, BoolTensor<B, D>) {
+        checked_binary::<B, D>(lhs, rhs, |a, b| a * b)
+    }
 
-    /// Gets the indices of the maximum elements along a dimension.
+    /// Element-wise division.
+    ///
+    /// The tensors are broadcasted: every dimension must have the same size in both tensors, or a
+    /// size of 1 in one of them, in which case it is repeated to the size of the other one.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the maximum indices of.
-    /// * `dim` - The dimension to get the maximum indices along.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The indices of the maximum elements along the dimension.
-    fn int_argmax<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
+    /// The result of the division, with the broadcasted shape of both tensors.
+    fn int_div<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
 
-    /// Gets the indices of the minimum elements along a dimension.
+    /// Element-wise division with a scalar.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum indices of.
-    /// * `dim` - The dimension to get the minimum indices along.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
     ///
     /// # Returns
     ///
-    /// The indices of the minimum elements along the dimension.
-    fn int_argmin<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
+    /// The result of the division.
+    fn int_div_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
 
-    /// Gets the maximum element in the tensor.
+    /// Element-wise modulus.
     ///
-    /// # Arguments
+    /// The result follows the floored modulo, like Python's `%`: it has the sign of the divisor,
+    /// so `-7 % 3 == 2` and `7 % -3 == -2`.
     ///
-    /// * `tensor` - The tensor to get the maximum element of.
+    /// # Arguments
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
     ///
     /// # Returns
     ///
-    /// The maximum element in the tensor.
-    fn int_max<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
-        let shape = B::int_shape(&tensor);
-        let tensor = B::int_reshape(tensor, Shape::new([shape.num_elements()]));
+    /// The result of applying the modulus of the right hand side to the left hand side.
+    fn int_remainder<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>)
+        -> IntTensor<B, D>;
 
-        B::int_max_dim(tensor, 0)
-    }
+    /// Element-wise modulus with a scalar.
+    ///
+    /// The result follows the floored modulo, like Python's `%`: it has the sign of the divisor.
+    ///
+    /// # Arguments
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of applying the modulus of the scalar to the tensor.
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
 
-    /// Gets the maximum element in the tensor along a dimension.
+    /// Element-wise negation.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the maximum element of.
-    /// * `dim` - The dimension to get the maximum element along.
+    /// * `tensor` - The tensor to negate.
     ///
     /// # Returns
     ///
-    /// The maximum element in the tensor along the dimension.
-    fn int_max_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
-        let index = B::int_argmax(tensor.clone(), dim);
-
-        B::int_gather(D - 1, tensor, index)
+    /// The negated tensor.
+    fn int_neg<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        Self::int_mul_scalar(tensor, (-1.0).elem::<IntElem<B>>())
     }
 
-    /// Gets the maximum elements and corresponding indices along a dimension.
+    /// Creates a tensor of zeros.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the maximum elements and indices of.
-    /// * `dim` - The dimension to get the maximum elements and indices along.
+    /// * `shape` - The shape of the tensor.
+    /// * `device` - The device to create the tensor on.
     ///
     /// # Returns
     ///
-    /// The maximum elements and corresponding indices along the dimension.
-    fn int_max_dim_with_indices<const D: usize>(
-        tensor: IntTensor<B, D>,
-        dim: usize,
-    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
-        let index = B::int_argmax(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, index.clone());
-
-        (values, index)
-    }
+    /// The tensor of zeros.
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D>;
 
-    /// Gets the minimum element in the tensor.
+    /// Creates a tensor of ones.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum element of.
+    /// * `shape` - The shape of the tensor.
+    /// * `device` - The device to create the tensor on.
     ///
     /// # Returns
     ///
-    /// The minimum element in the tensor.
-    fn int_min<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
-        let shape = B::int_shape(&tensor);
-        let tensor = B::int_reshape(tensor, Shape::new([shape.num_elements()]));
-
-        B::int_min_dim(tensor, 0)
-    }
+    /// The tensor of ones.
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D>;
 
-    /// Gets the minimum elements in the tensor along a dimension.
+    /// Creates a tensor filled with given value.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum element of.
-    /// * `dim` - The dimension to get the minimum element along.
+    /// * `shape` - The shape of the tensor.
+    /// * `fill_value` - The value with which to fill the tensor.
+    /// * `device` - The device to create the tensor on.
     ///
     /// # Returns
     ///
-    /// The minimum element in the tensor along the dimension.
-    fn int_min_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
-        let index = B::int_argmin(tensor.clone(), dim);
-
-        B::int_gather(D - 1, tensor, index)
+    /// The tensor filled with given value
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<B>,
+        device: &Device<B>,
+    ) -> IntTensor<B, D> {
+        Self::int_add_scalar(Self::int_zeros(shape, device), fill_value)
     }
 
-    /// Gets the minimum elements and corresponding indices along a dimension.
+    /// Sums all elements in the tensor.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to get the minimum elements and indices of.
-    /// * `dim` - The dimension to get the minimum elements and indices along.
+    /// * `tensor` - The tensor to sum.
     ///
     /// # Returns
     ///
-    /// The minimum elements and corresponding indices along the dimension.
-    fn int_min_dim_with_indices<const D: usize>(
-        tensor: IntTensor<B, D>,
-        dim: usize,
-    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
-        let indices = B::int_argmin(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, indices.clone());
-
-        (values, indices)
-    }
+    /// The sum of all elements in the tensor.
+    fn int_sum<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1>;
 
-    /// Returns a new tensor with absolute values.
+    /// Sums all elements in the tensor along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to take absolute value of.
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension to sum along.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as `tensor` with absolute values.
-    fn int_abs<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D>;
+    /// The sum of all elements in the tensor along the dimension.
+    ///
+    /// The reduced dimension is kept with a size of 1, so the result can be broadcasted against
+    /// the input tensor.
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
-    /// Transposes an int tensor.
+    /// Sums the consecutive groups of `group_size` elements of the tensor.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to transpose.
+    /// * `tensor` - The tensor to sum, whose length must be divisible by `group_size`.
+    /// * `group_size` - The number of elements of each group.
     ///
     /// # Returns
     ///
-    /// The transposed tensor.
-    fn int_transpose<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
-        Self::int_swap_dims(tensor, D - 2, D - 1)
+    /// A tensor of length `N / group_size` containing the sum of each group.
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is zero or doesn't divide the length of the tensor.
+    fn int_sum_grouped(tensor: IntTensor<B, 1>, group_size: usize) -> IntTensor<B, 1> {
+        let [length] = B::int_shape(&tensor).dims;
+        assert!(
+            group_size != 0 && length % group_size == 0,
+            "Can't split a tensor of length {length} into groups of size {group_size}"
+        );
+        let num_groups = length / group_size;
+        let tensor = B::int_reshape(tensor, Shape::new([num_groups, group_size]));
+
+        B::int_reshape(B::int_sum_dim(tensor, 1), Shape::new([num_groups]))
     }
 
-    /// Swaps two dimensions of an int tensor.
+    /// Computes the product of all elements in the tensor.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to swap the dimensions of.
-    /// * `dim1` - The first dimension to swap.
-    /// * `dim2` - The second dimension to swap.
+    /// * `tensor` - The tensor to compute the product of.
     ///
     /// # Returns
     ///
-    /// The tensor with the dimensions swapped.
-    fn int_swap_dims<const D: usize>(
-        tensor: IntTensor<B, D>,
-        dim1: usize,
-        dim2: usize,
-    ) -> IntTensor<B, D>;
+    /// The product of all elements in the tensor.
+    fn int_prod<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1>;
 
-    /// Permutes the dimensions of a tensor.
+    /// Computes the product of all elements in the tensor, reporting overflows.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to permute the dimensions of.
-    /// * `axes` - The new order of the dimensions.
+    /// * `tensor` - The tensor to compute the product of.
+    ///
     /// # Returns
     ///
-    /// The tensor with the dimensions permuted.
-    fn int_permute<const D: usize>(tensor: IntTensor<B, D>, axes: [usize; D]) -> IntTensor<B, D>;
+    /// The product of all elements in the tensor, or an error if the product doesn't fit in the
+    /// int element type.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the data of the tensor to compute the product.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_prod_checked<const D: usize>(
+        tensor: IntTensor<B, D>,
+    ) -> Result<IntTensor<B, 1>, OverflowError> {
+        let device = B::int_device(&tensor);
+        let data = B::int_into_data(tensor).read();
+        let (min, max) = elem_bounds::<IntElem<B>>();
 
-    /// Reverse the order of elements in a tensor along the given axes.
+        let product = data.iter::<i64>().try_fold(1i64, |acc, value| {
+            acc.checked_mul(value)
+                .filter(|product| (min..=max).contains(product))
+                .ok_or(OverflowError)
+        })?;
+
+        Ok(B::int_from_data(
+            TensorData::new(alloc::vec![product.elem::<IntElem<B>>()], [1]),
+            &device,
+        ))
+    }
+
+    /// Computes the product of all elements in the tensor along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to reverse.
-    /// * `axes` - The axes to reverse.
+    /// * `tensor` - The tensor to compute the product of.
+    /// * `dim` - The dimension to compute the product along.
     ///
-    /// The tensor with the elements reversed.
-    fn int_flip<const D: usize>(tensor: IntTensor<B, D>, axes: &[usize]) -> IntTensor<B, D>;
+    /// # Returns
+    ///
+    /// The product of all elements in the tensor along the dimension.
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
-    /// Returns a new tensor with the given dimension narrowed to the given range.
+    /// Computes the mean of all elements in the tensor.
     ///
     /// # Arguments
     ///
-    /// * `dim` - The dimension along which the tensor will be narrowed.
-    /// * `start` - The starting point of the given range.
-    /// * `length` - The ending point of the given range.
-    /// # Panics
+    /// * `tensor` - The tensor to compute the mean of.
     ///
-    /// - If the dimension is greater than the number of dimensions of the tensor.
-    /// - If the given range exceeds the number of elements on the given dimension.
+    /// # Returns
+    ///
+    /// The mean of all elements in the tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The sum is divided by the number of elements with integer division, so the mean is
+    /// truncated toward zero. See [int_mean_round](IntTensorOps::int_mean_round) for a rounded
+    /// mean.
+    fn int_mean<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        let num_elems = B::int_shape(&tensor).num_elements();
+        B::int_div_scalar(B::int_sum(tensor), (num_elems as i64).elem())
+    }
+
+    /// Computes the mean of all elements in the tensor, rounded to the nearest integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the mean of.
     ///
     /// # Returns
     ///
-    /// A new tensor with the given dimension narrowed to the given range.
-    fn int_narrow<const D: usize>(
-        tensor: IntTensor<B, D>,
-        dim: usize,
-        start: usize,
-        length: usize,
-    ) -> IntTensor<B, D> {
-        narrow::<B, D, Int>(tensor, dim, start, length)
+    /// The mean of all elements in the tensor, where halves are rounded away from zero.
+    fn int_mean_round<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        let num_elems = B::int_shape(&tensor).num_elements();
+        div_round_half_away::<B, 1>(B::int_sum(tensor), num_elems)
     }
 
-    /// Generates a cartesian grid for the given tensor shape on the specified device.
-    /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
+    /// Computes the mean of all elements in the tensor along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `shape` - The shape specifying the dimensions of the tensor.
-    /// * `device` - The device to create the tensor on.
+    /// * `tensor` - The tensor to compute the mean of.
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// Panics if `D2` is not equal to `D+1`.
+    /// The mean of all elements in the tensor along the dimension.
     ///
-    /// # Examples
+    /// The reduced dimension is kept with a size of 1, so the result can be broadcasted against
+    /// the input tensor.
     ///
-    /// ```rust
-    ///    use burn_tensor::Int;
-    ///    use burn_tensor::{backend::Backend, Shape, Tensor};
-    ///    fn example<B: Backend>() {
-    ///        let device = Default::default();
-    ///        let result: Tensor<B, 3, _> = Tensor::<B, 2, Int>::cartesian_grid([2, 3], &device);
-    ///        println!("{}", result);
-    ///    }
-    /// ```
-    fn int_cartesian_grid<S: Into<Shape<D>>, const D: usize, const D2: usize>(
-        shape: S,
-        device: &B::Device,
-    ) -> IntTensor<B, D2> {
-        cartesian_grid::<B, _, D, D2>(shape, device)
+    /// # Remarks
+    ///
+    /// Like [int_mean](IntTensorOps::int_mean), the mean is truncated toward zero.
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
+
+    /// Computes the mean of all elements in the tensor along a dimension, rounded to the nearest
+    /// integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the mean of.
+    /// * `dim` - The dimension to compute the mean along.
+    ///
+    /// # Returns
+    ///
+    /// The mean along the dimension, where halves are rounded away from zero. The reduced
+    /// dimension is kept with a size of 1.
+    fn int_mean_dim_round<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let count = B::int_shape(&tensor).dims[dim];
+        div_round_half_away::<B, D>(B::int_sum_dim(tensor, dim), count)
     }
 
-    /// Split the tensor along the given dimension into chunks.
+    /// Computes the variance of the int `tensor` along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor.
-    /// * `chunks` - The number of chunks to be produced
-    /// * `times` - The dimension along which the tensor will be split.
+    /// * `tensor` - The tensor to compute the variance of.
+    /// * `dim` - The dimension to compute the variance along.
+    /// * `unbiased` - Whether to apply the Bessel's correction, dividing by `n - 1` instead of `n`.
     ///
     /// # Returns
     ///
-    /// A vector of tensors
-    fn int_chunk<const D: usize>(
+    /// The float variance along the dimension, which is kept with a size of 1.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation converts the tensor to float before computing the variance.
+    fn int_var<const D: usize>(
         tensor: IntTensor<B, D>,
-        chunks: usize,
         dim: usize,
-    ) -> Vec<IntTensor<B, D>> {
-        chunk::<B, D, Int>(tensor, chunks, dim)
+        unbiased: bool,
+    ) -> FloatTensor<B, D> {
+        let tensor = Tensor::<B, D>::new(B::int_into_float(tensor));
+        let var = match unbiased {
+            true => stats::var(tensor, dim),
+            false => stats::var_bias(tensor, dim),
+        };
+
+        var.into_primitive()
     }
 
-    /// Creates a new int tensor with random values.
+    /// Computes the standard deviation of the int `tensor` along a dimension.
     ///
-    ///  # Arguments
-    ///  * `shape` - The shape of the tensor.
-    ///  * `distribution` - The distribution to sample from.
-    ///  * `device` - The device to create the tensor on.
+    /// # Arguments
     ///
-    ///  # Returns
+    /// * `tensor` - The tensor to compute the standard deviation of.
+    /// * `dim` - The dimension to compute the standard deviation along.
+    /// * `unbiased` - Whether to apply the Bessel's correction to the variance.
     ///
-    ///  The tensor with the given shape and random values.
-    fn int_random<const D: usize>(
-        shape: Shape<D>,
-        distribution: Distribution,
-        device: &Device<B>,
-    ) -> IntTensor<B, D>;
+    /// # Returns
+    ///
+    /// The float standard deviation along the dimension, which is kept with a size of 1.
+    fn int_std<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        unbiased: bool,
+    ) -> FloatTensor<B, D> {
+        B::float_sqrt(Self::int_var(tensor, dim, unbiased))
+    }
 
-    /// Creates a new tensor with values from the given range with the given step size.
+    /// Gets the indices of the maximum elements along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `range` - The range of values.
-    /// * `step` - The step size.
-    /// * `device` - The device to create the tensor on.
+    /// * `tensor` - The tensor to get the maximum indices of.
+    /// * `dim` - The dimension to get the maximum indices along.
     ///
     /// # Returns
     ///
-    /// The tensor with the given values.
-    fn int_arange_step(range: Range<i64>, step: usize, device: &Device<B>) -> IntTensor<B, 1> {
-        let value = range
-            .step_by(step)
-            .map(|i| i.elem())
-            .collect::<Vec<IntElem<B>>>();
-        let shape = Shape::new([value.len()]);
-        let data = TensorData::new(value, shape);
-        B::int_from_data(data, device)
-    }
+    /// The indices of the maximum elements along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// The indices are always non-negative, and they are stored with the int element type of the
+    /// backend. For dimensions longer than the positive range of the element type, use
+    /// [int_argmax_usize](IntTensorOps::int_argmax_usize) instead.
+    fn int_argmax<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
-    /// Creates a new tensor with values from the given range.
+    /// Gets the indices of the maximum elements along a dimension as wide indices.
     ///
     /// # Arguments
     ///
-    /// * `range` - The range of values.
-    /// * `device` - The device to create the tensor on.
+    /// * `tensor` - The tensor to get the maximum indices of.
+    /// * `dim` - The dimension to get the maximum indices along.
     ///
     /// # Returns
     ///
-    /// The tensor with the given values.
+    /// The wide indices of the maximum elements, which can index dimensions longer than the
+    /// positive range of the int element type.
     ///
     /// # Remarks
     ///
-    /// Uses `arange_step` with a step size of 1 under the hood.
-    fn int_arange(range: Range<i64>, device: &Device<B>) -> IntTensor<B, 1> {
-        Self::int_arange_step(range, 1, device)
+    /// The default implementation reduces chunks of the dimension that the int element type can
+    /// index with [int_argmax](IntTensorOps::int_argmax), then reduces the selected values of
+    /// every chunk, so the indices never leave the device.
+    fn int_argmax_usize<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> WideIndices<B, D> {
+        argmax_usize::<B, D>(tensor, dim, wide_index_chunk_size::<B>())
     }
 
-    /// Tests if any element in the int `tensor` evaluates to True.
+    /// Gets the indices of the minimum elements along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to test.
+    /// * `tensor` - The tensor to get the minimum indices of.
+    /// * `dim` - The dimension to get the minimum indices along.
     ///
     /// # Returns
     ///
-    /// A boolean tensor with a single element, True if any element in the tensor is True, False otherwise.
-    fn int_any<const D: usize>(tensor: IntTensor<B, D>) -> BoolTensor<B, 1> {
-        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
-        let bool_tensor = B::bool_not(bool_tensor);
-        let sum = B::int_sum(B::bool_into_int(bool_tensor));
-        B::int_greater_elem(sum, 0.elem())
-    }
+    /// The indices of the minimum elements along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// The indices are always non-negative, and they are stored with the int element type of the
+    /// backend. For dimensions longer than the positive range of the element type, use
+    /// [int_argmin_usize](IntTensorOps::int_argmin_usize) instead.
+    fn int_argmin<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
-    /// Tests if any element in the int `tensor` evaluates to True along a given dimension `dim`.
+    /// Gets the indices of the minimum elements along a dimension as wide indices.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to test.
-    /// * `dim` - The axis along which to test.
+    /// * `tensor` - The tensor to get the minimum indices of.
+    /// * `dim` - The dimension to get the minimum indices along.
     ///
     /// # Returns
     ///
-    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
-    /// where the size is 1. The elem in the `dim` axis is True if any element along this dim in the input
-    /// evaluates to True, False otherwise.
-    fn int_any_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
-        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
-        let bool_tensor = B::bool_not(bool_tensor);
-        let sum = B::int_sum_dim(B::bool_into_int(bool_tensor), dim);
-        B::int_greater_elem(sum, 0.elem())
+    /// The wide indices of the minimum elements, which can index dimensions longer than the
+    /// positive range of the int element type.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reduces chunks of the dimension that the int element type can
+    /// index with [int_argmin](IntTensorOps::int_argmin), then reduces the selected values of
+    /// every chunk, so the indices never leave the device.
+    fn int_argmin_usize<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> WideIndices<B, D> {
+        argmin_usize::<B, D>(tensor, dim, wide_index_chunk_size::<B>())
     }
 
-    /// Tests if all elements in the int `tensor` evaluate to True.
+    /// Gets the maximum element in the tensor.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to test.
+    /// * `tensor` - The tensor to get the maximum element of.
     ///
     /// # Returns
     ///
-    /// A boolean tensor `Tensor<B, 1, Bool>` with a single element, True if all elements in the input tensor
-    /// evaluate to True, False otherwise.
-    fn int_all<const D: usize>(tensor: IntTensor<B, D>) -> BoolTensor<B, 1> {
-        let num_elems = B::int_shape(&tensor).num_elements();
-        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
-        let bool_tensor = B::bool_not(bool_tensor);
-        let sum = B::int_sum(B::bool_into_int(bool_tensor));
-        B::int_equal_elem(sum, (num_elems as i32).elem())
+    /// The maximum element in the tensor.
+    fn int_max<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        let shape = B::int_shape(&tensor);
+        let tensor = B::int_reshape(tensor, Shape::new([shape.num_elements()]));
+
+        B::int_max_dim(tensor, 0)
     }
 
-    /// Tests if all elements in the int `tensor` evaluate to True along a given dimension `dim`.
+    /// Gets the maximum element in the tensor along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to test.
-    /// * `dim` - The axis along which to test.
+    /// * `tensor` - The tensor to get the maximum element of.
+    /// * `dim` - The dimension to get the maximum element along.
     ///
     /// # Returns
     ///
-    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
-    /// where the size is 1. The elem in the `dim` axis is True if all elements along this dim in the input
-    /// evaluates to True, False otherwise.
-    fn int_all_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
-        let num_elems = B::int_shape(&tensor).dims[dim];
-        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
-        let bool_tensor = B::bool_not(bool_tensor);
-        let sum = B::int_sum_dim(B::bool_into_int(bool_tensor), dim);
-        B::int_equal_elem(sum, (num_elems as i32).elem())
+    /// The maximum element in the tensor along the dimension.
+    ///
+    /// The reduced dimension is kept with a size of 1, so the result can be broadcasted against
+    /// the input tensor.
+    fn int_max_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let index = B::int_argmax(tensor.clone(), dim);
+
+        B::int_gather(dim, tensor, index)
     }
 
-    /// Returns the signs of the int `tensor`.
+    /// Gets the maximum elements and corresponding indices along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The tensor to extract the signs from.
+    /// * `tensor` - The tensor to get the maximum elements and indices of.
+    /// * `dim` - The dimension to get the maximum elements and indices along.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as `tensor` containing the signs of the elements of `tensor`.
-    fn int_sign<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
-        let zeros = B::int_zeros(B::int_shape(&tensor), &B::int_device(&tensor));
-        let less_than_zero = B::int_lower_elem(tensor.clone(), 0.0f32.elem());
-        let greater_than_zero = B::int_greater_elem(tensor, 0.0f32.elem());
+    /// The maximum elements and corresponding indices along the dimension.
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let index = B::int_argmax(tensor.clone(), dim);
+        let values = B::int_gather(dim, tensor, index.clone());
 
-        let mut result = B::int_mask_fill(zeros, less_than_zero, (-1.0f32).elem());
-        result = B::int_mask_fill(result, greater_than_zero, 1.0f32.elem());
-        result
+        (values, index)
     }
 
-    /// Broadcasts the int `tensor` to the given `shape`.
-    fn int_expand<const D1: usize, const D2: usize>(
-        tensor: IntTensor<B, D1>,
-        shape: Shape<D2>,
-    ) -> IntTensor<B, D2>;
-
-    /// Sort the elements of the input `tensor` by value along a given dimension.
-    ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// Gets the minimum element in the tensor.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The input tensor.
-    /// * `dim` - The axis along which to sort.
-    /// * `descending` - The sorting order.
+    /// * `tensor` - The tensor to get the minimum element of.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor, where the elements are sorted by value.
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-    fn int_sort<const D: usize>(
-        tensor: IntTensor<B, D>,
-        dim: usize,
-        descending: bool,
-    ) -> IntTensor<B, D> {
-        sort::<B, D, Int>(tensor, dim, descending)
+    /// The minimum element in the tensor.
+    fn int_min<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        let shape = B::int_shape(&tensor);
+        let tensor = B::int_reshape(tensor, Shape::new([shape.num_elements()]));
+
+        B::int_min_dim(tensor, 0)
     }
 
-    /// Sort the elements of the input `tensor` by value along a given dimension.
+    /// Gets the minimum elements in the tensor along a dimension.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the minimum element of.
+    /// * `dim` - The dimension to get the minimum element along.
+    ///
+    /// # Returns
+    ///
+    /// The minimum element in the tensor along the dimension.
+    ///
+    /// The reduced dimension is kept with a size of 1, so the result can be broadcasted against
+    /// the input tensor.
+    fn int_min_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        let index = B::int_argmin(tensor.clone(), dim);
+
+        B::int_gather(dim, tensor, index)
+    }
+
+    /// Gets the minimum elements and corresponding indices along a dimension.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The input tensor.
-    /// * `dim` - The axis along which to sort.
+    /// * `tensor` - The tensor to get the minimum elements and indices of.
+    /// * `dim` - The dimension to get the minimum elements and indices along.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor and corresponding indices, where
-    /// the elements are sorted by value and the indices map back to the original input tensor.
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-    fn int_sort_with_indices<const D: usize>(
+    /// The minimum elements and corresponding indices along the dimension.
+    fn int_min_dim_with_indices<const D: usize>(
         tensor: IntTensor<B, D>,
         dim: usize,
-        descending: bool,
     ) -> (IntTensor<B, D>, IntTensor<B, D>) {
-        sort_with_indices::<B, D, Int>(tensor, dim, descending)
+        let indices = B::int_argmin(tensor.clone(), dim);
+        let values = B::int_gather(dim, tensor, indices.clone());
+
+        (values, indices)
     }
 
-    /// Returns the indices that sort the elements of the input `tensor` by value
-    /// along a given dimension.
+    /// Gets the element-wise maximum of two tensors.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements).
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor, with the same shape as `lhs`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the larger value of the two tensors at each position.
+    fn int_maximum<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        let mask = B::int_greater(rhs.clone(), lhs.clone());
+
+        B::int_mask_where(lhs, mask, rhs)
+    }
+
+    /// Gets the element-wise minimum of two tensors.
     ///
     /// # Arguments
     ///
-    /// * `tensor` - The input tensor.
-    /// * `dim` - The axis along which to sort.
-    /// * `descending` - The sorting order.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor, with the same shape as `lhs`.
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensor the indices map back to the original input tensor.
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-    fn int_argsort<const D: usize>(
+    /// A tensor with the smaller value of the two tensors at each position.
+    fn int_minimum<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        let mask = B::int_greater(lhs.clone(), rhs.clone());
+
+        B::int_mask_where(lhs, mask, rhs)
+    }
+
+    /// Returns a new tensor with absolute values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take absolute value of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with absolute values.
+    fn int_abs<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D>;
+
+    /// Transposes an int tensor, swapping its last two dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to transpose.
+    ///
+    /// # Returns
+    ///
+    /// The transposed tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 2 dimensions, since there is no pair of dimensions to swap.
+    fn int_transpose<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        assert!(
+            D >= 2,
+            "Can't transpose an int tensor with {D} dimension(s), at least 2 are required"
+        );
+        Self::int_swap_dims(tensor, D - 2, D - 1)
+    }
+
+    /// Transposes an int tensor by swapping the two given dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to transpose.
+    /// * `dim0` - The first dimension to swap.
+    /// * `dim1` - The second dimension to swap.
+    ///
+    /// # Returns
+    ///
+    /// The transposed tensor.
+    ///
+    /// # Panics
+    ///
+    /// If one of the dimensions is out of bounds.
+    fn int_transpose_dims<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim0: usize,
+        dim1: usize,
+    ) -> IntTensor<B, D> {
+        assert!(
+            dim0 < D && dim1 < D,
+            "Can't transpose dimensions ({dim0}, {dim1}) of an int tensor with {D} dimension(s)"
+        );
+        Self::int_swap_dims(tensor, dim0, dim1)
+    }
+
+    /// Swaps two dimensions of an int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to swap the dimensions of.
+    /// * `dim1` - The first dimension to swap.
+    /// * `dim2` - The second dimension to swap.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimensions swapped.
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<B, D>;
+
+    /// Permutes the dimensions of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to permute the dimensions of.
+    /// * `axes` - The new order of the dimensions.
+    /// # Returns
+    ///
+    /// The tensor with the dimensions permuted.
+    fn int_permute<const D: usize>(tensor: IntTensor<B, D>, axes: [usize; D]) -> IntTensor<B, D>;
+
+    /// Moves the dimension `src` to the position `dst`, shifting the dimensions in between.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `src` - The dimension to move.
+    /// * `dst` - The position of the moved dimension in the output tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the dimension moved.
+    fn int_movedim<const D: usize>(
+        tensor: IntTensor<B, D>,
+        src: usize,
+        dst: usize,
+    ) -> IntTensor<B, D> {
+        let mut axes: Vec<usize> = (0..D).filter(|&axis| axis != src).collect();
+        axes.insert(dst, src);
+
+        B::int_permute(tensor, axes.try_into().unwrap())
+    }
+
+    /// Reverse the order of elements in a tensor along the given axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to reverse.
+    /// * `axes` - The axes to reverse.
+    ///
+    /// The tensor with the elements reversed.
+    ///
+    /// # Remarks
+    ///
+    /// Backends supporting negative strides may return a strided view sharing the storage of the
+    /// input tensor instead of copying its data, while others fall back to a copy. Since tensors
+    /// are immutable, the sharing is never observable: an in-place operation on a shared storage
+    /// copies the data first.
+    fn int_flip<const D: usize>(tensor: IntTensor<B, D>, axes: &[usize]) -> IntTensor<B, D>;
+
+    /// Returns a new tensor with the given dimension narrowed to the given range.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which the tensor will be narrowed.
+    /// * `start` - The starting point of the given range.
+    /// * `length` - The ending point of the given range.
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the number of dimensions of the tensor.
+    /// - If the given range exceeds the number of elements on the given dimension.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the given dimension narrowed to the given range.
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<B, D> {
+        narrow::<B, D, Int>(tensor, dim, start, length)
+    }
+
+    /// Generates a cartesian grid for the given tensor shape on the specified device.
+    /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape specifying the dimensions of the tensor.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `D2` is not equal to `D+1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///    use burn_tensor::Int;
+    ///    use burn_tensor::{backend::Backend, Shape, Tensor};
+    ///    fn example<B: Backend>() {
+    ///        let device = Default::default();
+    ///        let result: Tensor<B, 3, _> = Tensor::<B, 2, Int>::cartesian_grid([2, 3], &device);
+    ///        println!("{}", result);
+    ///    }
+    /// ```
+    fn int_cartesian_grid<S: Into<Shape<D>>, const D: usize, const D2: usize>(
+        shape: S,
+        device: &B::Device,
+    ) -> IntTensor<B, D2> {
+        cartesian_grid::<B, _, D, D2>(shape, device)
+    }
+
+    /// Split the tensor along the given dimension into chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `chunks` - The number of chunks to be produced
+    /// * `times` - The dimension along which the tensor will be split.
+    ///
+    /// # Returns
+    ///
+    /// A vector of tensors, where every chunk has a size of `ceil(size / chunks)` except the last
+    /// one, which holds the remainder. Like `torch.chunk`, fewer chunks than requested can be
+    /// returned, e.g. a size of 6 split into 4 chunks gives sizes `[2, 2, 2]`, and a size smaller
+    /// than `chunks` gives one chunk of size 1 per element.
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<B, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<B, D>> {
+        chunk::<B, D, Int>(tensor, chunks, dim)
+    }
+
+    /// Split the tensor along the given dimension into sections of the given sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `split_sizes` - The size of each section, which must sum to the size of `dim`.
+    /// * `dim` - The dimension along which the tensor will be split.
+    ///
+    /// # Returns
+    ///
+    /// A vector of tensors, one per section, in order.
+    fn int_split<const D: usize>(
+        tensor: IntTensor<B, D>,
+        split_sizes: &[usize],
+        dim: usize,
+    ) -> Vec<IntTensor<B, D>> {
+        let mut start = 0;
+
+        split_sizes
+            .iter()
+            .map(|&size| {
+                let section = B::int_narrow(tensor.clone(), dim, start, size);
+                start += size;
+                section
+            })
+            .collect()
+    }
+
+    /// Creates a new int tensor with random values.
+    ///
+    /// The values of [IntDistribution::Uniform] are sampled in the half-open range `[low, high)`,
+    /// and those of [IntDistribution::Bernoulli] are `1` with probability `p` and `0` otherwise.
+    /// The values are drawn from the random number generator of the backend, so they are
+    /// reproducible after calling [seed](Backend::seed).
+    ///
+    ///  # Arguments
+    ///  * `shape` - The shape of the tensor.
+    ///  * `distribution` - The distribution to sample from.
+    ///  * `device` - The device to create the tensor on.
+    ///
+    ///  # Returns
+    ///
+    ///  The tensor with the given shape and random values.
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: IntDistribution,
+        device: &Device<B>,
+    ) -> IntTensor<B, D>;
+
+    /// Creates a random permutation of the integers from `0` to `n - 1`.
+    ///
+    /// The permutation is drawn from the random number generator of the backend, so it is
+    /// reproducible after calling [seed](Backend::seed).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to permute.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// A 1D tensor of length `n` containing each integer of `0..n` exactly once.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation sorts random float keys, which yields a permutation even when
+    /// some keys are equal.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_randperm(n: usize, device: &Device<B>) -> IntTensor<B, 1> {
+        let keys = B::float_random(Shape::new([n]), Distribution::Default, device);
+        B::float_argsort(keys, 0, false)
+    }
+
+    /// Draws random indices along the last dimension of `weights`, with probabilities
+    /// proportional to the weights.
+    ///
+    /// The indices are drawn from the random number generator of the backend, so they are
+    /// reproducible after calling [seed](Backend::seed).
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The non-negative weights of each index, which don't need to sum to one.
+    /// * `num_samples` - The number of indices to draw for each lane of the last dimension.
+    /// * `replacement` - Whether an index can be drawn several times. Without replacement, the
+    ///   weight of a drawn index is set to zero for the following draws.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `weights`, except along the last dimension where the size
+    /// is `num_samples`, containing the drawn indices.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_multinomial<const D: usize>(
+        weights: FloatTensor<B, D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B, D> {
+        multinomial::<B, D>(weights, num_samples, replacement)
+    }
+
+    /// Creates a new tensor with values from the given range with the given step size.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values.
+    /// * `step` - The step size.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given values.
+    fn int_arange_step(range: Range<i64>, step: usize, device: &Device<B>) -> IntTensor<B, 1> {
+        let value = range
+            .step_by(step)
+            .map(|i| i.elem())
+            .collect::<Vec<IntElem<B>>>();
+        let shape = Shape::new([value.len()]);
+        let data = TensorData::new(value, shape);
+        B::int_from_data(data, device)
+    }
+
+    /// Creates a new tensor with values from the given range with the given step size, validating
+    /// the arguments first.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values.
+    /// * `step` - The step size.
+    /// * `reject_empty` - Whether a range producing no element is an error.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given values, or an error if the step is zero or if `reject_empty` is
+    /// set and the range is empty.
+    ///
+    /// # Remarks
+    ///
+    /// Unlike [int_arange_step](IntTensorOps::int_arange_step), a zero step doesn't panic.
+    fn int_arange_step_checked(
+        range: Range<i64>,
+        step: usize,
+        reject_empty: bool,
+        device: &Device<B>,
+    ) -> Result<IntTensor<B, 1>, ArangeError> {
+        if step == 0 {
+            return Err(ArangeError::ZeroStep);
+        }
+
+        if reject_empty && range.is_empty() {
+            return Err(ArangeError::EmptyRange(range));
+        }
+
+        Ok(Self::int_arange_step(range, step, device))
+    }
+
+    /// Creates a new tensor with values from the given range with a signed step size.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values, where the end is excluded.
+    /// * `step` - The step size, negative for a descending sequence going from `range.start`
+    ///   down to `range.end`.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given values, empty when the step moves away from the end.
+    ///
+    /// # Panics
+    ///
+    /// If the step is zero.
+    fn int_arange_step_signed(range: Range<i64>, step: i64, device: &Device<B>) -> IntTensor<B, 1> {
+        assert!(step != 0, "The step of arange_step_signed must not be zero");
+
+        if step > 0 {
+            return Self::int_arange_step(range, step as usize, device);
+        }
+
+        let value = core::iter::successors(Some(range.start), |&i| i.checked_add(step))
+            .take_while(|&i| i > range.end)
+            .map(|i| i.elem())
+            .collect::<Vec<IntElem<B>>>();
+        let shape = Shape::new([value.len()]);
+        let data = TensorData::new(value, shape);
+        B::int_from_data(data, device)
+    }
+
+    /// Creates a new tensor with values from the given range.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of values.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the given values.
+    ///
+    /// # Remarks
+    ///
+    /// Uses `arange_step` with a step size of 1 under the hood.
+    fn int_arange(range: Range<i64>, device: &Device<B>) -> IntTensor<B, 1> {
+        Self::int_arange_step(range, 1, device)
+    }
+
+    /// Creates a square identity matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of rows and columns.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// A `size x size` tensor with ones on the diagonal and zeros elsewhere.
+    fn int_eye(size: usize, device: &Device<B>) -> IntTensor<B, 2> {
+        Self::int_eye_rect(size, size, device)
+    }
+
+    /// Creates a rectangular identity matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows.
+    /// * `cols` - The number of columns.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// A `rows x cols` tensor where the element at `[i, j]` is one if `i == j` and zero otherwise.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation compares the broadcasted row and column indices.
+    fn int_eye_rect(rows: usize, cols: usize, device: &Device<B>) -> IntTensor<B, 2> {
+        let shape = Shape::new([rows, cols]);
+        let row_indices = B::int_reshape(
+            Self::int_arange(0..rows as i64, device),
+            Shape::new([rows, 1]),
+        );
+        let col_indices = B::int_reshape(
+            Self::int_arange(0..cols as i64, device),
+            Shape::new([1, cols]),
+        );
+        let row_indices = B::int_expand(row_indices, shape.clone());
+        let col_indices = B::int_expand(col_indices, shape);
+
+        B::bool_into_int(B::int_equal(row_indices, col_indices))
+    }
+
+    /// Tests if any element in the int `tensor` evaluates to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, True if any element in the tensor is True, False otherwise.
+    fn int_any<const D: usize>(tensor: IntTensor<B, D>) -> BoolTensor<B, 1> {
+        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
+        let bool_tensor = B::bool_not(bool_tensor);
+        let sum = B::int_sum(B::bool_into_int(bool_tensor));
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Tests if any element in the int `tensor` evaluates to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
+    /// where the size is 1. The elem in the `dim` axis is True if any element along this dim in the input
+    /// evaluates to True, False otherwise.
+    fn int_any_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
+        let bool_tensor = B::bool_not(bool_tensor);
+        let sum = B::int_sum_dim(B::bool_into_int(bool_tensor), dim);
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Tests if all elements in the int `tensor` evaluate to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, 1, Bool>` with a single element, True if all elements in the input tensor
+    /// evaluate to True, False otherwise.
+    fn int_all<const D: usize>(tensor: IntTensor<B, D>) -> BoolTensor<B, 1> {
+        let num_elems = B::int_shape(&tensor).num_elements();
+        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
+        let bool_tensor = B::bool_not(bool_tensor);
+        let sum = B::int_sum(B::bool_into_int(bool_tensor));
+        B::int_equal_elem(sum, (num_elems as i32).elem())
+    }
+
+    /// Tests if all elements in the int `tensor` evaluate to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
+    /// where the size is 1. The elem in the `dim` axis is True if all elements along this dim in the input
+    /// evaluates to True, False otherwise.
+    fn int_all_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let num_elems = B::int_shape(&tensor).dims[dim];
+        let bool_tensor = B::int_equal_elem(tensor, 0.elem());
+        let bool_tensor = B::bool_not(bool_tensor);
+        let sum = B::int_sum_dim(B::bool_into_int(bool_tensor), dim);
+        B::int_equal_elem(sum, (num_elems as i32).elem())
+    }
+
+    /// Counts the non-zero elements of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count the non-zero elements of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with a single element containing the number of non-zero elements.
+    fn int_count_nonzero<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        let mask = B::int_not_equal_elem(tensor, 0.elem());
+        B::int_sum(B::bool_into_int(mask))
+    }
+
+    /// Counts the non-zero elements of the int `tensor` along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count the non-zero elements of.
+    /// * `dim` - The dimension to count along.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, except along `dim` where the size is 1,
+    /// containing the number of non-zero elements along `dim`.
+    fn int_count_nonzero_dim<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> IntTensor<B, D> {
+        let mask = B::int_not_equal_elem(tensor, 0.elem());
+        B::int_sum_dim(B::bool_into_int(mask), dim)
+    }
+
+    /// Returns the signs of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to extract the signs from.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` containing the signs of the elements of `tensor`.
+    fn int_sign<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        let zeros = B::int_zeros(B::int_shape(&tensor), &B::int_device(&tensor));
+        let less_than_zero = B::int_lower_elem(tensor.clone(), 0.0f32.elem());
+        let greater_than_zero = B::int_greater_elem(tensor, 0.0f32.elem());
+
+        let mut result = B::int_mask_fill(zeros, less_than_zero, (-1.0f32).elem());
+        result = B::int_mask_fill(result, greater_than_zero, 1.0f32.elem());
+        result
+    }
+
+    /// Computes the element-wise greatest common divisor of two int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The greatest common divisors of the absolute values of the elements, where the greatest
+    /// common divisor of two zeros is zero.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_gcd<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        gcd::<B, D>(lhs, rhs)
+    }
+
+    /// Computes the element-wise least common multiple of two int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The least common multiples of the absolute values of the elements, where the least common
+    /// multiple with a zero operand is zero.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_lcm<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        lcm::<B, D>(lhs, rhs)
+    }
+
+    /// Computes the element-wise integer square root of the int `tensor`.
+    ///
+    /// The root is computed with integer arithmetic, so it stays exact for large values where a
+    /// float square root would round.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of non-negative values.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where `output[i] =
+    /// floor(sqrt(tensor[i]))`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_isqrt<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        isqrt::<B, D>(tensor)
+    }
+
+    /// Computes the element-wise base 2 integer logarithm of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of positive values.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where `output[i] =
+    /// floor(log2(tensor[i]))`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_ilog2<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_ilog(tensor, 2.elem())
+    }
+
+    /// Computes the element-wise integer logarithm of the int `tensor` in the given `base`.
+    ///
+    /// The logarithm is computed with integer arithmetic, so it is exact at the powers of the
+    /// base where a float logarithm could round to the wrong side.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of positive values.
+    /// * `base` - The base of the logarithm, at least 2.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where
+    /// `output[i] = floor(log_base(tensor[i]))`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_ilog<const D: usize>(tensor: IntTensor<B, D>, base: IntElem<B>) -> IntTensor<B, D> {
+        ilog::<B, D>(tensor, base)
+    }
+
+    /// Broadcasts the int `tensor` to the given `shape`.
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<B, D2>;
+
+    /// Sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements are sorted by value.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        sort::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Sort the elements of the input `tensor` by value along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor and corresponding indices, where
+    /// the elements are sorted by value and the indices map back to the original input tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        sort_with_indices::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Returns the indices that sort the elements of the input `tensor` by value
+    /// along a given dimension.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to sort.
+    /// * `descending` - The sorting order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor the indices map back to the original input tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        argsort::<B, D, Int>(tensor, dim, descending)
+    }
+
+    /// Returns the `k` largest or smallest elements of the input `tensor` along a given dimension.
+    ///
+    /// Equal elements are ordered by lowest index, so the selection is deterministic.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `k` - The number of elements to select, at most the size of `dim`.
+    /// * `dim` - The axis along which to select.
+    /// * `largest` - Whether to select the largest elements instead of the smallest ones.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor except along `dim`, which has length `k`,
+    /// holding the selected elements from the first to the last selected one.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_topk<const D: usize>(
+        tensor: IntTensor<B, D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> IntTensor<B, D> {
+        Self::int_topk_with_indices(tensor, k, dim, largest).0
+    }
+
+    /// Returns the `k` largest or smallest elements of the input `tensor` along a given dimension,
+    /// with their indices.
+    ///
+    /// Equal elements are ordered by lowest index, so the selection is deterministic.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `k` - The number of elements to select, at most the size of `dim`.
+    /// * `dim` - The axis along which to select.
+    /// * `largest` - Whether to select the largest elements instead of the smallest ones.
+    ///
+    /// # Returns
+    ///
+    /// The selected elements and their indices along `dim` in the input tensor, both with the same
+    /// shape as the input tensor except along `dim`, which has length `k`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_topk_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        topk_with_indices::<B, D>(tensor, k, dim, largest)
+    }
+
+    /// Finds the indices where the elements of `values` should be inserted to keep the
+    /// `sorted_sequence` sorted.
+    ///
+    /// The `sorted_sequence` must be sorted in ascending order, otherwise the result is undefined.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted_sequence` - The 1D tensor sorted in ascending order.
+    /// * `values` - The values to insert.
+    /// * `right` - Whether the returned index is the last suitable one instead of the first one,
+    ///   so that `sorted_sequence[i - 1] <= value < sorted_sequence[i]` instead of
+    ///   `sorted_sequence[i - 1] < value <= sorted_sequence[i]`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `values` containing the insertion indices.
+    ///
+    /// The default implementation runs a binary search on the device, which takes
+    /// `log2(sorted_sequence.len())` steps over the values.
+    fn int_searchsorted<const D: usize>(
+        sorted_sequence: IntTensor<B, 1>,
+        values: IntTensor<B, D>,
+        right: bool,
+    ) -> IntTensor<B, D> {
+        let shape = B::int_shape(&values);
+        let num_values = shape.num_elements();
+        let [num_sorted] = B::int_shape(&sorted_sequence).dims;
+        let device = B::int_device(&values);
+
+        if num_sorted == 0 {
+            return B::int_zeros(shape, &device);
+        }
+
+        let values = B::int_reshape(values, Shape::new([num_values]));
+        let mut indices = B::int_zeros(Shape::new([num_values]), &device);
+
+        // The insertion index is the number of sorted elements that must precede the value. It is
+        // built from the largest power of two down, adding each step while the sorted element
+        // before the candidate index still precedes the value. Clamping the candidates to the
+        // length keeps the search exact, since the elements that precede form a prefix.
+        let mut step = 1 << num_sorted.ilog2();
+        while step > 0 {
+            let candidates = B::int_clamp_max(
+                B::int_add_scalar(indices.clone(), (step as i64).elem()),
+                (num_sorted as i64).elem(),
+            );
+            let before = B::int_gather(
+                0,
+                sorted_sequence.clone(),
+                B::int_sub_scalar(candidates.clone(), 1.elem()),
+            );
+            let precedes = if right {
+                B::int_lower_equal(before, values.clone())
+            } else {
+                B::int_lower(before, values.clone())
+            };
+
+            indices = B::int_mask_where(indices, precedes, candidates);
+            step /= 2;
+        }
+
+        B::int_reshape(indices, shape)
+    }
+
+    /// Compares two int tensors element-wise and reports where they differ.
+    ///
+    /// Useful to locate mismatches between an expected and an actual tensor, since it reads the
+    /// data of both tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The number of differing elements, the flat index of the first one and the maximum absolute
+    /// difference.
+    ///
+    /// # Panics
+    ///
+    /// If the tensors don't have the same shape.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_diff_report<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensorDiff {
+        let shape_lhs = B::int_shape(&lhs);
+        let shape_rhs = B::int_shape(&rhs);
+        assert_eq!(
+            shape_lhs, shape_rhs,
+            "Can't compare int tensors with different shapes: {:?} != {:?}",
+            shape_lhs.dims, shape_rhs.dims
+        );
+
+        let lhs = B::int_into_data(lhs).read();
+        let rhs = B::int_into_data(rhs).read();
+
+        let mut report = IntTensorDiff::new(0, None, 0);
+
+        for (i, (a, b)) in lhs.iter::<i64>().zip(rhs.iter::<i64>()).enumerate() {
+            if a != b {
+                report.num_diffs += 1;
+                report.first_index.get_or_insert(i);
+                report.max_abs_diff = report.max_abs_diff.max(a.abs_diff(b));
+            }
+        }
+
+        report
+    }
+
+    /// Computes the cumulative sum of the elements of the int `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of the
+    /// elements preceding it along `dim`, itself included.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation adds the slices along `dim` one after the other, which launches
+    /// one operation per slice. Backends should provide a native implementation when possible.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumsum_with_slices::<B, D>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of the int `tensor` along a given
+    /// dimension, along with the index of that maximum.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of tensors with the same shape as the input tensor, containing the running maximum
+    /// along `dim` and its index along `dim`. When the maximum is reached several times, the index
+    /// of the last occurrence is returned.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation compares the slices along `dim` one after the other, which
+    /// launches a few operations per slice. Backends should provide a native implementation when
+    /// possible.
+    fn int_cummax<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let size = B::int_shape(&tensor).dims[dim];
+
+        if size == 0 {
+            return (tensor.clone(), tensor);
+        }
+
+        let mut max = B::int_narrow(tensor.clone(), dim, 0, 1);
+        let mut index = B::int_zeros(B::int_shape(&max), &B::int_device(&tensor));
+        let mut values = Vec::with_capacity(size);
+        let mut indices = Vec::with_capacity(size);
+        values.push(max.clone());
+        indices.push(index.clone());
+
+        for i in 1..size {
+            let slice = B::int_narrow(tensor.clone(), dim, i, 1);
+            let update = B::int_greater_equal(slice.clone(), max.clone());
+            max = B::int_mask_where(max, update.clone(), slice);
+            index = B::int_mask_fill(index, update, (i as i64).elem());
+            values.push(max.clone());
+            indices.push(index.clone());
+        }
+
+        (B::int_cat(values, dim), B::int_cat(indices, dim))
+    }
+
+    /// Computes the cumulative minimum of the elements of the int `tensor` along a given
+    /// dimension, along with the index of that minimum.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of tensors with the same shape as the input tensor, containing the running minimum
+    /// along `dim` and its index along `dim`. When the minimum is reached several times, the index
+    /// of the last occurrence is returned.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation compares the slices along `dim` one after the other, which
+    /// launches a few operations per slice. Backends should provide a native implementation when
+    /// possible.
+    fn int_cummin<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let size = B::int_shape(&tensor).dims[dim];
+
+        if size == 0 {
+            return (tensor.clone(), tensor);
+        }
+
+        let mut min = B::int_narrow(tensor.clone(), dim, 0, 1);
+        let mut index = B::int_zeros(B::int_shape(&min), &B::int_device(&tensor));
+        let mut values = Vec::with_capacity(size);
+        let mut indices = Vec::with_capacity(size);
+        values.push(min.clone());
+        indices.push(index.clone());
+
+        for i in 1..size {
+            let slice = B::int_narrow(tensor.clone(), dim, i, 1);
+            let update = B::int_lower_equal(slice.clone(), min.clone());
+            min = B::int_mask_where(min, update.clone(), slice);
+            index = B::int_mask_fill(index, update, (i as i64).elem());
+            values.push(min.clone());
+            indices.push(index.clone());
+        }
+
+        (B::int_cat(values, dim), B::int_cat(indices, dim))
+    }
+
+    /// Computes the cumulative sum of the elements of the int `tensor` along a given dimension,
+    /// saturating instead of wrapping around on overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    /// * `max` - The value at which the running sum saturates (e.g. `255` for `u8` counters).
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the running sum
+    /// along `dim`, clamped to `max` and to the bounds of the int element type.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_cumsum_saturating<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        max: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        cumsum_saturating::<B, D>(tensor, dim, max)
+    }
+
+    /// Compute the indices of the non-zero elements of the int `tensor`, with one index tensor
+    /// per dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// An array of `D` tensors, where the `i`-th tensor contains the indices of the non-zero
+    /// elements in the `i`-th dimension. Entry `j` of every tensor corresponds to row `j` of
+    /// the `[N, D]` coordinate form returned by [argwhere](super::BoolTensorOps::bool_argwhere).
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_nonzero_tuple<const D: usize>(tensor: IntTensor<B, D>) -> [IntTensor<B, 1>; D] {
+        let mask = B::int_not_equal_elem(tensor, 0.elem());
+        let mut indices = B::bool_nonzero(mask).into_iter();
+        core::array::from_fn(|_| indices.next().unwrap())
+    }
+
+    /// Extracts the elements of the int `tensor` where the mask is true.
+    ///
+    /// The number of selected elements is only known once the mask has been read, so the default
+    /// implementation forces a synchronization with the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `mask` - The boolean mask, with the same shape as the input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A 1D tensor containing the selected elements in row-major order.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_masked_select<const D: usize>(
+        tensor: IntTensor<B, D>,
+        mask: BoolTensor<B, D>,
+    ) -> IntTensor<B, 1> {
+        let num_elements = B::int_shape(&tensor).num_elements();
+        let tensor = B::int_reshape(tensor, Shape::new([num_elements]));
+        let mask = B::bool_reshape(mask, Shape::new([num_elements]));
+        let indices = B::bool_argwhere(mask);
+        let num_selected = B::int_shape(&indices).dims[0];
+
+        B::int_select(
+            tensor,
+            0,
+            B::int_reshape(indices, Shape::new([num_selected])),
+        )
+    }
+
+    /// Compute the coordinates of the non-zero elements of the int `tensor`.
+    ///
+    /// The number of non-zero elements is only known once the data has been read, so the default
+    /// implementation forces a synchronization with the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A `[N, D]` tensor, where `N` is the number of non-zero elements, containing the coordinates
+    /// of each non-zero element in row-major order.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_nonzero<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 2> {
+        B::bool_argwhere(B::int_not_equal_elem(tensor, 0.elem()))
+    }
+
+    /// Compute the flat indices of the non-zero elements of the int `tensor`.
+    ///
+    /// The number of non-zero elements is only known once the data has been read, so the default
+    /// implementation forces a synchronization with the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A 1D tensor containing the row-major index of each non-zero element, in ascending order.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_nonzero_flat<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        let num_elements = B::int_shape(&tensor).num_elements();
+        let tensor = B::int_reshape(tensor, Shape::new([num_elements]));
+        let indices = Self::int_nonzero(tensor);
+        let num_nonzero = B::int_shape(&indices).dims[0];
+
+        B::int_reshape(indices, Shape::new([num_nonzero]))
+    }
+
+    /// Encodes the int `tensor` of class indices as one hot vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor of class indices, in the range `[0, num_classes)`.
+    /// * `num_classes` - The number of classes.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with a new trailing dimension of size `num_classes`, which is `1` at the index of
+    /// each element and `0` elsewhere.
+    fn int_one_hot<const D: usize, const D2: usize>(
+        tensor: IntTensor<B, D>,
+        num_classes: usize,
+    ) -> IntTensor<B, D2> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+
+        let mut dims = [1; D2];
+        dims[..D].copy_from_slice(&shape.dims);
+        let indices = B::int_reshape(tensor, Shape::new(dims));
+        let ones = B::int_ones(Shape::new(dims), &device);
+        dims[D2 - 1] = num_classes;
+
+        B::int_scatter(
+            D2 - 1,
+            B::int_zeros(Shape::new(dims), &device),
+            indices,
+            ones,
+        )
+    }
+
+    /// Rolls the elements of an int tensor along a dimension, wrapping them around.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The shift amount. Positive shifts move the elements towards higher indices and
+    ///   negative shifts towards lower indices.
+    /// * `dim` - The dimension along which to roll.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where the elements that go past one end of `dim`
+    /// wrap around to the other end.
+    fn int_roll<const D: usize>(
+        tensor: IntTensor<B, D>,
+        shifts: i64,
+        dim: usize,
+    ) -> IntTensor<B, D> {
+        let size = B::int_shape(&tensor).dims[dim];
+
+        if size == 0 {
+            return tensor;
+        }
+
+        let shift = shifts.rem_euclid(size as i64) as usize;
+
+        if shift == 0 {
+            return tensor;
+        }
+
+        let tail = B::int_narrow(tensor.clone(), dim, size - shift, shift);
+        let head = B::int_narrow(tensor, dim, 0, size - shift);
+
+        B::int_cat(alloc::vec![tail, head], dim)
+    }
+
+    /// Rolls the elements of an int tensor along several dimensions at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to roll.
+    /// * `shifts` - The shift amount of each rolled dimension.
+    /// * `dims` - The distinct dimensions along which to roll, with the same length as `shifts`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, rolled by `shifts[i]` along `dims[i]` for every
+    /// `i`.
+    fn int_roll_dims<const D: usize>(
+        tensor: IntTensor<B, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> IntTensor<B, D> {
+        shifts
+            .iter()
+            .zip(dims)
+            .fold(tensor, |tensor, (&shift, &dim)| {
+                Self::int_roll(tensor, shift, dim)
+            })
+    }
+
+    /// Computes the `n`-th order discrete difference of the int `tensor` along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to compute the difference.
+    /// * `n` - The number of times the difference is taken.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, except along `dim` where the size is
+    /// reduced by `n`, where `output[i] = tensor[i + 1] - tensor[i]` for the first order.
+    fn int_diff<const D: usize>(tensor: IntTensor<B, D>, dim: usize, n: usize) -> IntTensor<B, D> {
+        let mut output = tensor;
+
+        for _ in 0..n {
+            let size = B::int_shape(&output).dims[dim];
+            let next = B::int_narrow(output.clone(), dim, 1, size - 1);
+            let previous = B::int_narrow(output, dim, 0, size - 1);
+            output = B::int_sub(next, previous);
+        }
+
+        output
+    }
+
+    /// Rolls each row of a batch of int tensors along a dimension by its own shift amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The batched tensor.
+    /// * `shifts` - The shift of each batch entry, where the batch dimension is the dimension other
+    ///   than `dim`. Positive shifts move the elements towards higher indices.
+    /// * `dim` - The dimension along which each batch entry is rolled.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each entry along the batch dimension is
+    /// rolled along `dim` by the corresponding shift, wrapping the elements around.
+    fn int_roll_batched(
+        tensor: IntTensor<B, 2>,
+        shifts: IntTensor<B, 1>,
+        dim: usize,
+    ) -> IntTensor<B, 2> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+        let size = shape.dims[dim];
+        let batch_size = shape.dims[1 - dim];
+
+        if size == 0 {
+            return tensor;
+        }
+
+        let (shape_positions, shape_shifts) = match dim {
+            0 => ([size, 1], [1, batch_size]),
+            _ => ([1, size], [batch_size, 1]),
+        };
+
+        let positions = B::int_reshape(
+            B::int_arange(0..size as i64, &device),
+            Shape::new(shape_positions),
+        );
+        let shifts = B::int_remainder_scalar(
+            B::int_reshape(shifts, Shape::new(shape_shifts)),
+            (size as i64).elem(),
+        );
+
+        // Source index of each element: (position - shift) mod size, kept non-negative.
+        let indices = B::int_add_scalar(
+            B::int_sub(
+                B::int_expand(positions, shape.clone()),
+                B::int_expand(shifts, shape),
+            ),
+            (size as i64).elem(),
+        );
+        let indices = B::int_remainder_scalar(indices, (size as i64).elem());
+
+        B::int_gather(dim, tensor, indices)
+    }
+
+    /// Computes a cumulative scan of the int `tensor` along a given dimension.
+    ///
+    /// For an input `[x0, x1, x2]` with the [sum](ScanOp::Sum) operation, the four variants are:
+    ///
+    /// | `exclusive` | `reverse` | Output                          |
+    /// |-------------|-----------|---------------------------------|
+    /// | `false`     | `false`   | `[x0, x0+x1, x0+x1+x2]`         |
+    /// | `true`      | `false`   | `[0, x0, x0+x1]`                |
+    /// | `false`     | `true`    | `[x0+x1+x2, x1+x2, x2]`         |
+    /// | `true`      | `true`    | `[x1+x2, x2, 0]`                |
+    ///
+    /// An exclusive scan doesn't include the current element, so the first accumulated element
+    /// is the identity of the operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    /// * `op` - The accumulated operation.
+    /// * `exclusive` - Whether the current element is excluded from its accumulated value.
+    /// * `reverse` - Whether to accumulate from the last element to the first one.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor containing the accumulated values.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_scan_config<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        op: ScanOp,
+        exclusive: bool,
+        reverse: bool,
+    ) -> IntTensor<B, D> {
+        scan::<B, D>(tensor, dim, op, exclusive, reverse)
+    }
+
+    /// Computes the most frequent value of the int `tensor` over all of its elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of single element tensors containing the most frequent value and the number of
+    /// times it appears. Ties are broken by returning the smallest of the most frequent values.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_mode_flat<const D: usize>(
+        tensor: IntTensor<B, D>,
+    ) -> (IntTensor<B, 1>, IntTensor<B, 1>) {
+        mode_flat::<B, D>(tensor)
+    }
+
+    /// Computes the most frequent value of the int `tensor` along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which to compute the mode.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of tensors with the same shape as the input tensor, except along `dim` where the
+    /// size is 1, containing the most frequent values and the index of their first occurrence
+    /// along `dim`. Ties are broken by returning the smallest of the most frequent values.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_mode_dim<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        mode_dim::<B, D>(tensor, dim)
+    }
+
+    /// Returns the distinct elements of the int `tensor`.
+    ///
+    /// The number of distinct elements is only known once the data has been read, so the default
+    /// implementation reads the tensor data, which forces a synchronization with the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `sorted` - Whether the distinct elements are returned in ascending order instead of the
+    ///   order of their first appearance.
+    ///
+    /// # Returns
+    ///
+    /// A 1D tensor containing each distinct element once.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique<const D: usize>(tensor: IntTensor<B, D>, sorted: bool) -> IntTensor<B, 1> {
+        Self::int_unique_with_counts(tensor, sorted).0
+    }
+
+    /// Returns the distinct elements of the int `tensor` along with the number of times each one
+    /// appears.
+    ///
+    /// The number of distinct elements is only known once the data has been read, so the default
+    /// implementation reads the tensor data, which forces a synchronization with the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `sorted` - Whether the distinct elements are returned in ascending order instead of the
+    ///   order of their first appearance.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of 1D tensors containing each distinct element once and its number of occurrences.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique_with_counts<const D: usize>(
+        tensor: IntTensor<B, D>,
+        sorted: bool,
+    ) -> (IntTensor<B, 1>, IntTensor<B, 1>) {
+        unique_with_counts::<B, D>(tensor, sorted)
+    }
+
+    /// Counts the number of occurrences of each value of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of non-negative values.
+    /// * `num_bins` - The number of bins, or `None` to use `max + 1` bins, where `max` is the
+    ///   maximum value of the tensor. It must not be smaller than `max + 1`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of bins, where bin `i` contains the number of occurrences of the value `i`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_bincount(tensor: IntTensor<B, 1>, num_bins: Option<usize>) -> IntTensor<B, 1> {
+        bincount::<B>(tensor, num_bins)
+    }
+
+    /// Counts the number of occurrences of each value of the int `tensor`, with at most
+    /// `maxlength` bins.
+    ///
+    /// Capping the number of bins prevents a single huge value from allocating an enormous output.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor of non-negative values.
+    /// * `maxlength` - The maximum number of bins.
+    /// * `clamp` - The policy for the values greater or equal to `maxlength`: they are counted in
+    ///   the last bin when `true`, and dropped otherwise.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of `min(max + 1, maxlength)` bins, where `max` is the maximum value of the tensor
+    /// and bin `i` contains the number of occurrences of the value `i`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_bincount_capped(
+        tensor: IntTensor<B, 1>,
+        maxlength: usize,
+        clamp: bool,
+    ) -> IntTensor<B, 1> {
+        bincount_capped::<B>(tensor, maxlength, clamp)
+    }
+
+    /// Replaces the elements of the int `tensor` that don't exceed the `threshold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `threshold` - The threshold value.
+    /// * `replacement` - The value of the elements that don't exceed the threshold.
+    /// * `inclusive` - Whether the elements equal to the threshold are kept (`>=`) instead of
+    ///   replaced (`>`).
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where `output[i] = tensor[i]` if
+    /// `tensor[i] > threshold` (or `>=` when inclusive) and `output[i] = replacement` otherwise.
+    fn int_threshold<const D: usize>(
+        tensor: IntTensor<B, D>,
+        threshold: IntElem<B>,
+        replacement: IntElem<B>,
+        inclusive: bool,
+    ) -> IntTensor<B, D> {
+        let mask = match inclusive {
+            true => B::int_lower_elem(tensor.clone(), threshold),
+            false => B::int_lower_equal_elem(tensor.clone(), threshold),
+        };
+
+        B::int_mask_fill(tensor, mask, replacement)
+    }
+
+    /// Pads the int `tensor` by wrapping around each dimension, taking the padded values from the
+    /// opposite edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `pads` - The number of elements `(before, after)` to add for each dimension. Pads
+    ///   larger than the dimension wrap around multiple times.
+    ///
+    /// # Returns
+    ///
+    /// A tensor where each dimension `d` has the size `before + shape[d] + after`.
+    fn int_pad_circular<const D: usize>(
+        tensor: IntTensor<B, D>,
+        pads: [(usize, usize); D],
+    ) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+        let mut tensor = tensor;
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            let size = shape.dims[dim];
+            assert!(
+                size > 0,
+                "Can't circularly pad dimension {dim} of size 0 (pads: {:?})",
+                (before, after)
+            );
+
+            // Shift the positions so that they are non-negative before wrapping them.
+            let start = (size - before % size) as i64;
+            let end = start + (before + size + after) as i64;
+            let indices =
+                B::int_remainder_scalar(B::int_arange(start..end, &device), (size as i64).elem());
+
+            tensor = B::int_select(tensor, dim, indices);
+        }
+
+        tensor
+    }
+
+    /// Pads the int `tensor` with a constant value.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `padding` - The number of elements `(before, after)` to add for each dimension.
+    /// * `value` - The value of the added elements.
+    ///
+    /// # Returns
+    ///
+    /// A tensor where each dimension `d` has the size `before + shape[d] + after`, with the input
+    /// tensor starting at the offsets `before`.
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        padding: [(usize, usize); D],
+        value: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+
+        let mut padded_dims = shape.dims;
+        let ranges: [Range<usize>; D] = core::array::from_fn(|dim| {
+            let (before, after) = padding[dim];
+            padded_dims[dim] += before + after;
+            before..before + shape.dims[dim]
+        });
+        let output = B::int_full(Shape::new(padded_dims), value, &device);
+
+        B::int_slice_assign(output, ranges, tensor)
+    }
+
+    /// Selects the given contiguous `ranges` of the int `tensor` along `dim` and concatenates them.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension to select from.
+    /// * `ranges` - The ranges to select, in the order of concatenation.
+    ///
+    /// # Returns
+    ///
+    /// A tensor where the size of `dim` is the sum of the lengths of the ranges.
+    fn int_select_ranges<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        ranges: &[Range<usize>],
+    ) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let slices = ranges
+            .iter()
+            .map(|range| {
+                let mut slice = shape.dims.map(|size| 0..size);
+                slice[dim] = range.clone();
+
+                B::int_slice(tensor.clone(), slice)
+            })
+            .collect();
+
+        B::int_cat(slices, dim)
+    }
+
+    /// Computes the cumulative product of the int `tensor` along a given dimension, accumulating
+    /// in floating point.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A float tensor with the same shape as the input tensor containing the running products.
+    ///
+    /// # Remarks
+    ///
+    /// The running products never overflow, but they are only exact as long as they fit the
+    /// mantissa of the float element type (e.g. `2^24` for `f32`). Beyond that, they are rounded
+    /// to the nearest representable float.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_cumprod_float<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumprod_float::<B, D>(tensor, dim)
+    }
+
+    /// Computes the value at the given quantile of all the elements of the int `tensor`, using the
+    /// nearest-rank method.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor, which must not be empty.
+    /// * `q` - The quantile, in `[0, 1]`.
+    ///
+    /// # Returns
+    ///
+    /// The element of rank `max(ceil(q * N), 1)` in the sorted flattened tensor of `N` elements,
+    /// without interpolation.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor is empty, or if `q` is not in `[0, 1]`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_quantile<const D: usize>(tensor: IntTensor<B, D>, q: f32) -> IntElem<B> {
+        let num_elements = B::int_shape(&tensor).num_elements();
+        assert!(
+            num_elements > 0,
+            "Can't compute the quantile of an empty tensor"
+        );
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "The quantile must be in the range [0, 1], got {q}"
+        );
+
+        let tensor = B::int_reshape(tensor, Shape::new([num_elements]));
+        let sorted = B::int_sort(tensor, 0, false);
+
+        let rank = ((q as f64 * num_elements as f64).ceil() as usize).clamp(1, num_elements);
+        let value = B::int_narrow(sorted, 0, rank - 1, 1);
+
+        B::int_into_data(value).read().iter().next().unwrap()
+    }
+
+    /// Scatter the given values to the tensor at the given indices, reducing the values that land
+    /// on the same position.
+    ///
+    /// The [sum](ReduceKind::Sum) reduction is the same operation as
+    /// [int_scatter](IntTensorOps::int_scatter), which it delegates to.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter to.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices.
+    /// * `value` - The values to scatter.
+    /// * `reduce` - The reduction applied to the original value and the scattered values.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor containing the reduced values. The
+    /// positions that no index targets keep their original value.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_scatter_reduce<const D: usize>(
+        dim: usize,
         tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+        reduce: ReduceKind,
+    ) -> IntTensor<B, D> {
+        match reduce {
+            ReduceKind::Sum => B::int_scatter(dim, tensor, indices, value),
+            _ => Self::int_scatter_reduce_with_counts(dim, tensor, indices, value, reduce).0,
+        }
+    }
+
+    /// Scatter the given values to the tensor at the given indices, reducing the values that land
+    /// on the same position and counting them.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter to.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices.
+    /// * `value` - The values to scatter.
+    /// * `reduce` - The reduction applied to the original value and the scattered values.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of tensors with the same shape as the input tensor, containing the reduced values
+    /// and the number of scattered values that landed on each position. The positions that no
+    /// index targets keep their original value and have a count of 0.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_scatter_reduce_with_counts<const D: usize>(
         dim: usize,
-        descending: bool,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+        reduce: ReduceKind,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        scatter_reduce_with_counts::<B, D>(dim, tensor, indices, value, reduce)
+    }
+
+    /// Writes the values of `src` onto a diagonal of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `src` - The diagonal values, with the shape of the input tensor without `dim1` and `dim2`,
+    ///   followed by the length of the diagonal.
+    /// * `offset` - The offset of the diagonal, above the main diagonal when positive and below it
+    ///   when negative.
+    /// * `dim1` - The first dimension of the diagonal.
+    /// * `dim2` - The second dimension of the diagonal.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, with the diagonal replaced by `src`.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_diagonal_scatter<const D: usize, const D2: usize>(
+        tensor: IntTensor<B, D>,
+        src: IntTensor<B, D2>,
+        offset: i64,
+        dim1: usize,
+        dim2: usize,
     ) -> IntTensor<B, D> {
-        argsort::<B, D, Int>(tensor, dim, descending)
+        diagonal_scatter::<B, D, D2>(tensor, src, offset, dim1, dim2)
+    }
+
+    /// Extracts a diagonal of the int matrix `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input matrix.
+    /// * `offset` - The offset of the diagonal, above the main diagonal when positive and below it
+    ///   when negative.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the elements of the diagonal, empty if the offset is outside the matrix.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation selects the diagonal positions from the flattened matrix.
+    fn int_diagonal(tensor: IntTensor<B, 2>, offset: i64) -> IntTensor<B, 1> {
+        let [rows, cols] = B::int_shape(&tensor).dims;
+        let device = B::int_device(&tensor);
+        let len = diagonal_len(rows, cols, offset);
+        let indices = diagonal_flat_indices::<B>(cols, offset, len, &device);
+        let flat = B::int_reshape(tensor, Shape::new([rows * cols]));
+
+        B::int_select(flat, 0, indices)
+    }
+
+    /// Builds a square int matrix with the values of `tensor` on a diagonal and zeros elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The diagonal values.
+    /// * `offset` - The offset of the diagonal, above the main diagonal when positive and below it
+    ///   when negative.
+    ///
+    /// # Returns
+    ///
+    /// A matrix of size `n + |offset|`, where `n` is the number of values, with the given diagonal
+    /// set to the values.
+    fn int_diag_embed(tensor: IntTensor<B, 1>, offset: i64) -> IntTensor<B, 2> {
+        let [len] = B::int_shape(&tensor).dims;
+        let device = B::int_device(&tensor);
+        let size = len + offset.unsigned_abs() as usize;
+        let indices = diagonal_flat_indices::<B>(size, offset, len, &device);
+        let flat = B::int_zeros(Shape::new([size * size]), &device);
+        let flat = B::int_select_assign(flat, 0, indices, tensor);
+
+        B::int_reshape(flat, Shape::new([size, size]))
+    }
+
+    /// Computes the trace of the int matrix `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input matrix.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with a single element, the sum of the main diagonal. For rectangular matrices,
+    /// the main diagonal has the length of the smallest dimension.
+    fn int_trace(tensor: IntTensor<B, 2>) -> IntTensor<B, 1> {
+        B::int_sum(Self::int_diagonal(tensor, 0))
+    }
+
+    /// Keeps the upper triangle of the last two dimensions of the int `tensor` and sets the other
+    /// elements to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor, with at least two dimensions.
+    /// * `diagonal` - The diagonal above which the elements are kept, where 0 is the main diagonal
+    ///   and positive values move towards the upper right corner.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, broadcasting over the leading dimensions.
+    fn int_triu<const D: usize>(tensor: IntTensor<B, D>, diagonal: i64) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+        let mask = Tensor::<B, D, Bool>::triu_mask(shape.clone(), diagonal, &device).expand(shape);
+
+        B::int_mask_fill(tensor, mask.into_primitive(), 0.elem())
+    }
+
+    /// Keeps the lower triangle of the last two dimensions of the int `tensor` and sets the other
+    /// elements to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor, with at least two dimensions.
+    /// * `diagonal` - The diagonal below which the elements are kept, where 0 is the main diagonal
+    ///   and negative values move towards the lower left corner.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, broadcasting over the leading dimensions.
+    fn int_tril<const D: usize>(tensor: IntTensor<B, D>, diagonal: i64) -> IntTensor<B, D> {
+        let shape = B::int_shape(&tensor);
+        let device = B::int_device(&tensor);
+        let mask = Tensor::<B, D, Bool>::tril_mask(shape.clone(), diagonal, &device).expand(shape);
+
+        B::int_mask_fill(tensor, mask.into_primitive(), 0.elem())
+    }
+
+    /// Computes the Kronecker product of two int matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side matrix, of shape `[m, n]`.
+    /// * `rhs` - The right hand side matrix, of shape `[p, q]`.
+    ///
+    /// # Returns
+    ///
+    /// A matrix of shape `[m * p, n * q]` made of the blocks `lhs[i, j] * rhs`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation multiplies broadcasted views of both matrices, then merges the
+    /// block dimensions with a reshape.
+    fn int_kron(lhs: IntTensor<B, 2>, rhs: IntTensor<B, 2>) -> IntTensor<B, 2> {
+        let [m, n] = B::int_shape(&lhs).dims;
+        let [p, q] = B::int_shape(&rhs).dims;
+
+        let lhs = B::int_reshape(lhs, Shape::new([m, 1, n, 1]));
+        let rhs = B::int_reshape(rhs, Shape::new([1, p, 1, q]));
+        let blocks = B::int_mul(lhs, rhs);
+
+        B::int_reshape(blocks, Shape::new([m * p, n * q]))
+    }
+
+    /// Computes the dot product of two int vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side vector.
+    /// * `rhs` - The right hand side vector, with the same length as `lhs`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with a single element, the sum of the element-wise products.
+    fn int_dot(lhs: IntTensor<B, 1>, rhs: IntTensor<B, 1>) -> IntTensor<B, 1> {
+        B::int_sum(B::int_mul(lhs, rhs))
+    }
+
+    /// Computes the outer product of two int vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side vector, of length `m`.
+    /// * `rhs` - The right hand side vector, of length `n`.
+    ///
+    /// # Returns
+    ///
+    /// A matrix of shape `[m, n]` where the element at `[i, j]` is `lhs[i] * rhs[j]`.
+    fn int_outer(lhs: IntTensor<B, 1>, rhs: IntTensor<B, 1>) -> IntTensor<B, 2> {
+        let [m] = B::int_shape(&lhs).dims;
+        let [n] = B::int_shape(&rhs).dims;
+
+        let lhs = B::int_reshape(lhs, Shape::new([m, 1]));
+        let rhs = B::int_reshape(rhs, Shape::new([1, n]));
+
+        B::int_mul(lhs, rhs)
+    }
+
+    /// Element-wise bitwise AND.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise AND.
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise OR.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise OR.
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise XOR.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise XOR.
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise AND with a scalar.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise AND.
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise OR with a scalar.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise OR.
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise XOR with a scalar.
+    ///
+    /// Signed integers are interpreted in two's complement.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise XOR.
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise NOT, the one's complement of each element.
+    ///
+    /// Signed integers are interpreted in two's complement, so the result is `-x - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise NOT.
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise left shift.
+    ///
+    /// The shift amounts that are negative or greater than or equal to the bit width of the int
+    /// element type shift every bit out, which gives `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amounts.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise left shift.
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise left shift by a scalar.
+    ///
+    /// A shift amount that is negative or greater than or equal to the bit width of the int
+    /// element type shifts every bit out, which gives `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amount.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise left shift.
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise right shift.
+    ///
+    /// The shift amounts that are negative or greater than or equal to the bit width of the int
+    /// element type shift every bit out, which gives the sign fill of the element (`0` or `-1`).
+    /// Signed integers are shifted arithmetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amounts.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise right shift.
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D>;
+
+    /// Element-wise bitwise right shift by a scalar.
+    ///
+    /// A shift amount that is negative or greater than or equal to the bit width of the int element
+    /// type shifts every bit out, which gives the sign fill of the element (`0` or `-1`). Signed
+    /// integers are shifted arithmetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amount.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise right shift.
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D>;
+}
+
+/// Divides `sum` by the positive `count`, rounding halves away from zero.
+fn div_round_half_away<B: Backend, const D: usize>(
+    sum: IntTensor<B, D>,
+    count: usize,
+) -> IntTensor<B, D> {
+    let sign = B::int_sign(sum.clone());
+    let magnitude = B::int_add_scalar(B::int_abs(sum), ((count / 2) as i64).elem());
+    let magnitude = B::int_div_scalar(magnitude, (count as i64).elem());
+
+    B::int_mul(magnitude, sign)
+}
+
+/// Computes `floor(sqrt(value))` with Newton's method, which stays exact where the float square
+/// root would round.
+pub fn isqrt_u64(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    // Start above the root with a power of two, the iterates then decrease monotonically until
+    // they reach the floor of the root.
+    let mut root = 1u64 << (64 - value.leading_zeros()).div_ceil(2);
+    loop {
+        let next = (root + value / root) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
     }
 }
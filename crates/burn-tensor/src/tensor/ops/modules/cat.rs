@@ -5,7 +5,12 @@ pub(crate) fn cat_with_slice_assign<B: Backend, const D: usize, K: TensorKind<B>
     tensors: Vec<Tensor<B, D, K>>,
     dim: usize,
 ) -> Tensor<B, D, K> {
-    let first_tensor = tensors.first().expect("Tensors should not be empty");
+    let mut tensors = tensors;
+    if let Some(tensor) = take_single_cat_input(&mut tensors) {
+        return tensor;
+    }
+
+    let first_tensor = tensors.first().unwrap();
     let mut shape = first_tensor.shape();
     let device = first_tensor.device();
 
@@ -35,3 +40,21 @@ pub(crate) fn cat_with_slice_assign<B: Backend, const D: usize, K: TensorKind<B>
 
     tensor_output
 }
+
+/// Takes the tensor out of a concatenation of a single tensor, so it can be returned as is.
+///
+/// # Panics
+///
+/// If `tensors` is empty, since the output shape would be undefined.
+pub(crate) fn take_single_cat_input<T>(tensors: &mut Vec<T>) -> Option<T> {
+    assert!(
+        !tensors.is_empty(),
+        "Can't concatenate an empty list of tensors"
+    );
+
+    if tensors.len() == 1 {
+        tensors.pop()
+    } else {
+        None
+    }
+}
@@ -4,6 +4,9 @@ mod module;
 mod ops;
 mod stats;
 
+/// Utilities to validate backend implementations.
+pub mod utils;
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_all {
@@ -70,6 +73,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf_scalar!();
         burn_tensor::testgen_random!();
+        burn_tensor::testgen_random_seed!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
@@ -99,6 +103,29 @@ macro_rules! testgen_all {
         burn_tensor::testgen_topk!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
+        burn_tensor::testgen_diff_report!();
+        burn_tensor::testgen_cumsum_saturating!();
+        burn_tensor::testgen_roll!();
+        burn_tensor::testgen_scan!();
+        burn_tensor::testgen_mode!();
+        burn_tensor::testgen_bincount!();
+        burn_tensor::testgen_diagonal!();
+        burn_tensor::testgen_bitwise!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_count_nonzero!();
+        burn_tensor::testgen_searchsorted!();
+        burn_tensor::testgen_diff!();
+        burn_tensor::testgen_gcd!();
+        burn_tensor::testgen_isqrt!();
+        burn_tensor::testgen_ilog!();
+        burn_tensor::testgen_pow_int!();
+        burn_tensor::testgen_saturating!();
+        burn_tensor::testgen_checked!();
+        burn_tensor::testgen_randperm!();
+        burn_tensor::testgen_multinomial!();
+        burn_tensor::testgen_inplace!();
+        burn_tensor::testgen_kron!();
+        burn_tensor::testgen_dot!();
 
         // test stats
         burn_tensor::testgen_var!();
@@ -107,6 +107,27 @@ mod tests {
             .assert_eq(&TensorData::from([[3, 5, 7], [6, 8, 10]]), false);
     }
 
+    #[test]
+    fn test_add_broadcast_bias_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let bias = TestTensorInt::<1>::from([10, 20, 30]).unsqueeze::<2>();
+
+        let output = tensor + bias;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[11, 22, 33], [14, 25, 36]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_broadcast_incompatible_shapes_int() {
+        let tensor_1 = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let tensor_2 = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let _ = tensor_1 + tensor_2;
+    }
+
     #[test]
     fn should_support_add_scalar_ops_int() {
         let scalar = 2;
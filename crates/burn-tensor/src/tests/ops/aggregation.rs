@@ -1,7 +1,8 @@
 #[burn_tensor_testgen::testgen(aggregation)]
 mod tests {
     use super::*;
-    use burn_tensor::{Shape, Tensor, TensorData};
+    use burn_tensor::ops::{IntTensorOps, OverflowError};
+    use burn_tensor::{ElementConversion, Shape, Tensor, TensorData};
 
     #[test]
     fn test_should_mean() {
@@ -85,6 +86,23 @@ mod tests {
             .assert_eq(&TensorData::from([[3], [12]]), false);
     }
 
+    #[test]
+    fn test_should_keep_reduced_dim_int() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        assert_eq!(tensor.clone().sum_dim(1).shape(), Shape::new([2, 1]));
+        assert_eq!(tensor.clone().mean_dim(1).shape(), Shape::new([2, 1]));
+        assert_eq!(tensor.clone().max_dim(1).shape(), Shape::new([2, 1]));
+        assert_eq!(tensor.clone().min_dim(0).shape(), Shape::new([1, 3]));
+
+        // The reduced dimension broadcasts back against the input.
+        let output = tensor.clone() - tensor.sum_dim(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-3, -2, -1], [-9, -8, -7]]), false);
+    }
+
     #[test]
     fn test_should_sum_first_dim() {
         let tensor = TestTensor::<2>::from([[3.0, 1.0, 2.0], [4.0, 2.0, 3.0]]);
@@ -171,6 +189,25 @@ mod tests {
         output.into_data().assert_eq(&TensorData::from([0]), false);
     }
 
+    #[test]
+    fn test_prod_checked_int() {
+        let tensor = TestTensorInt::<2>::from([[2, 1, 2], [3, 4, 5]]);
+        let output = tensor.prod_checked().unwrap();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([240]), false);
+    }
+
+    #[test]
+    fn test_prod_checked_should_report_overflow_int() {
+        // The product already overflows i32 after two elements and i64 after four.
+        let tensor = TestTensorInt::<1>::from([65536, 65536, 65536, 65536]);
+        let output = tensor.prod_checked();
+
+        assert_eq!(output.err(), Some(OverflowError));
+    }
+
     #[test]
     fn test_prod_dim_float() {
         let tensor = TestTensor::<2>::from([[2.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -203,4 +240,130 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[0], [60]]), false);
     }
+
+    #[test]
+    fn test_sum_grouped_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3, 4, 5, 6]);
+
+        let output = tensor.sum_grouped(2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 7, 11]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_grouped_should_panic_when_not_divisible_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3, 4, 5, 6]);
+
+        tensor.sum_grouped(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_backend_sum_grouped_should_panic_when_not_divisible_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3, 4, 5, 6]);
+
+        let _output = TestBackend::int_sum_grouped(tensor.into_primitive(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty tensor")]
+    fn test_backend_quantile_should_panic_on_empty_tensor_int() {
+        let tensor = TestTensorInt::<1>::from_data(
+            TensorData::new(alloc::vec::Vec::<i64>::new(), [0]),
+            &Default::default(),
+        );
+
+        let _value = TestBackend::int_quantile::<1>(tensor.into_primitive(), 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "range [0, 1]")]
+    fn test_backend_quantile_should_panic_on_invalid_quantile_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+
+        let _value = TestBackend::int_quantile::<1>(tensor.into_primitive(), 1.5);
+    }
+
+    #[test]
+    fn test_quantile_median_int() {
+        let tensor = TestTensorInt::<2>::from([[7, 1, 5], [3, 9, 4]]);
+
+        // Nearest-rank median: the 3rd of the 6 sorted values [1, 3, 4, 5, 7, 9].
+        let output = tensor.quantile(0.5);
+
+        assert_eq!(output.elem::<i64>(), 4);
+    }
+
+    #[test]
+    fn test_quantile_extremes_int() {
+        let tensor = TestTensorInt::<2>::from([[7, 1, 5], [3, 9, 4]]);
+
+        let min = tensor.clone().min().into_scalar();
+        let max = tensor.clone().max().into_scalar();
+
+        assert_eq!(tensor.clone().quantile(0.0), min);
+        assert_eq!(tensor.quantile(1.0), max);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_quantile_should_panic_out_of_range_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+
+        tensor.quantile(1.5);
+    }
+
+    #[test]
+    fn test_mean_int_truncates_toward_zero() {
+        let positive = TestTensorInt::<1>::from([1, 2]);
+        let negative = TestTensorInt::<1>::from([-1, -2]);
+
+        positive
+            .mean()
+            .into_data()
+            .assert_eq(&TensorData::from([1]), false);
+        negative
+            .mean()
+            .into_data()
+            .assert_eq(&TensorData::from([-1]), false);
+    }
+
+    #[test]
+    fn test_mean_round_int_rounds_half_away_from_zero() {
+        let positive = TestTensorInt::<1>::from([1, 2]);
+        let negative = TestTensorInt::<1>::from([-1, -2]);
+        let below_half = TestTensorInt::<1>::from([1, 1, 2]);
+        let above_half = TestTensorInt::<1>::from([-1, -2, -2]);
+
+        positive
+            .mean_round()
+            .into_data()
+            .assert_eq(&TensorData::from([2]), false);
+        negative
+            .mean_round()
+            .into_data()
+            .assert_eq(&TensorData::from([-2]), false);
+        below_half
+            .mean_round()
+            .into_data()
+            .assert_eq(&TensorData::from([1]), false);
+        above_half
+            .mean_round()
+            .into_data()
+            .assert_eq(&TensorData::from([-2]), false);
+    }
+
+    #[test]
+    fn test_mean_dim_round_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [-1, -2], [3, 5], [-4, 1]]);
+
+        let output = tensor.mean_dim_round(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [-2], [4], [-2]]), false);
+    }
 }
@@ -58,4 +58,21 @@ mod tests {
         let data_expected = TensorData::from([[false], [true]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn test_any_all_int_non_zero_truth() {
+        let tensor = TestTensorInt::<1>::from([0, 0, 5]);
+        assert_eq!(tensor.clone().any().into_data(), TensorData::from([true]));
+        assert_eq!(tensor.all().into_data(), TensorData::from([false]));
+
+        let tensor = TestTensorInt::<2>::from([[0, 3], [0, -2]]);
+        assert_eq!(
+            tensor.clone().any_dim(0).into_data(),
+            TensorData::from([[false, true]])
+        );
+        assert_eq!(
+            tensor.all_dim(0).into_data(),
+            TensorData::from([[false, true]])
+        );
+    }
 }
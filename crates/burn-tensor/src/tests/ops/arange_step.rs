@@ -2,6 +2,7 @@
 mod tests {
     use super::*;
     use burn_tensor::backend::Backend;
+    use burn_tensor::ops::ArangeError;
     use burn_tensor::{Int, Tensor, TensorData};
 
     #[test]
@@ -45,4 +46,87 @@ mod tests {
         // Test that arange_step panics when the step is 0
         let _tensor = Tensor::<TestBackend, 1, Int>::arange_step(0..3, 0, &device);
     }
+
+    #[test]
+    fn arange_step_checked_should_reject_zero_step() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let result = Tensor::<TestBackend, 1, Int>::arange_step_checked(0..3, 0, false, &device);
+
+        assert_eq!(result.err(), Some(ArangeError::ZeroStep));
+    }
+
+    #[test]
+    fn arange_step_checked_should_flag_empty_range_when_requested() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let range = 5..0;
+
+        let result =
+            Tensor::<TestBackend, 1, Int>::arange_step_checked(range.clone(), 1, true, &device);
+        assert_eq!(result.err(), Some(ArangeError::EmptyRange(range.clone())));
+
+        let tensor =
+            Tensor::<TestBackend, 1, Int>::arange_step_checked(range, 1, false, &device).unwrap();
+        assert_eq!(tensor.dims(), [0]);
+    }
+
+    #[test]
+    fn arange_step_checked_should_support_valid_range() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let tensor =
+            Tensor::<TestBackend, 1, Int>::arange_step_checked(0..7, 3, true, &device).unwrap();
+
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([0, 3, 6]), false);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn arange_step_signed_should_support_descending_ranges() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let tensor = Tensor::<TestBackend, 1, Int>::arange_step_signed(10..0, -2, &device);
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([10, 8, 6, 4, 2]), false);
+
+        let tensor = Tensor::<TestBackend, 1, Int>::arange_step_signed(2..-5, -3, &device);
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([2, -1, -4]), false);
+    }
+
+    #[test]
+    fn arange_step_signed_should_match_arange_step_for_positive_steps() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let tensor = Tensor::<TestBackend, 1, Int>::arange_step_signed(-3..3, 2, &device);
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([-3, -1, 1]), false);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn arange_step_signed_should_be_empty_when_step_moves_away() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let ascending = Tensor::<TestBackend, 1, Int>::arange_step_signed(0..5, -1, &device);
+        let descending = Tensor::<TestBackend, 1, Int>::arange_step_signed(5..0, 1, &device);
+
+        assert_eq!(ascending.dims(), [0]);
+        assert_eq!(descending.dims(), [0]);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn arange_step_signed_should_panic_when_step_is_zero() {
+        let device = <TestBackend as Backend>::Device::default();
+        let _tensor = Tensor::<TestBackend, 1, Int>::arange_step_signed(3..0, 0, &device);
+    }
 }
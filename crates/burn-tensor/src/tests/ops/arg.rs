@@ -68,4 +68,48 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[2], [1]]), false);
     }
+
+    #[test]
+    fn test_argmax_usize_dim_longer_than_index_range_int() {
+        // Chunks of 100 elements stand for an element type that can't index the 300 elements.
+        let mut values = (0..300).map(|i| (i % 100) as i64).collect::<Vec<_>>();
+        values[257] = 1000;
+        values[291] = 1000;
+        let tensor =
+            TestTensorInt::<1>::from_data(TensorData::new(values, [300]), &Default::default());
+
+        let output = burn_tensor::argmax_usize::<TestBackend, 1>(tensor.into_primitive(), 0, 100);
+
+        TestTensorInt::<1>::from_primitive(output.chunk.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([2]), false);
+        TestTensorInt::<1>::from_primitive(output.offset.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([57]), false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([257u64]), true);
+    }
+
+    #[test]
+    fn test_argmin_usize_dim_longer_than_index_range_int() {
+        let tensor = TestTensorInt::<2>::from([[5, 3, 8, 1, 9, 1, 0], [2, 2, 7, 4, 6, 9, 3]]);
+
+        let output = burn_tensor::argmin_usize::<TestBackend, 2>(tensor.into_primitive(), 1, 3);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[6u64], [0]]), true);
+    }
+
+    #[test]
+    fn test_argmin_usize_2d_dim1_int() {
+        let tensor = TestTensorInt::<2>::from([[10, 11, 2], [30, 4, 4]]);
+
+        let output = tensor.argmin_usize(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2u64], [1]]), true);
+    }
 }
@@ -91,4 +91,52 @@ mod tests {
             actual.assert_eq(&data_expected[idx], false)
         }
     }
+
+    #[test]
+    fn test_nonzero_tuple_2d_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 5, 0], [-2, 0, 7]], &device);
+
+        let [rows, cols] = tensor.clone().nonzero_tuple();
+
+        rows.into_data()
+            .assert_eq(&TensorData::from([0, 1, 1]), false);
+        cols.into_data()
+            .assert_eq(&TensorData::from([1, 0, 2]), false);
+    }
+
+    #[test]
+    fn test_nonzero_tuple_consistent_with_argwhere_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 5, 0], [-2, 0, 7]], &device);
+
+        let coordinates = tensor.clone().not_equal_elem(0).argwhere();
+        let [rows, cols] = tensor.nonzero_tuple();
+
+        Tensor::stack::<2>(vec![rows, cols], 1)
+            .into_data()
+            .assert_eq(&coordinates.into_data(), true);
+    }
+
+    #[test]
+    fn test_nonzero_int_2d() {
+        let tensor = TestTensorInt::<2>::from([[0, 3, 0], [4, 0, -1]]);
+
+        let output = tensor.nonzero();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1], [1, 0], [1, 2]]), false);
+    }
+
+    #[test]
+    fn test_nonzero_flat_int_2d() {
+        let tensor = TestTensorInt::<2>::from([[0, 3, 0], [4, 0, -1]]);
+
+        let output = tensor.nonzero_flat();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 5]), false);
+    }
 }
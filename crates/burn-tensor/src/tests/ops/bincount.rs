@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(bincount)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn bincount_should_count_each_value() {
+        let tensor = TestTensorInt::<1>::from([0, 1, 1, 3]);
+
+        tensor
+            .clone()
+            .bincount(None)
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 0, 1]), false);
+        tensor
+            .bincount(Some(6))
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 0, 1, 0, 0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bincount_should_panic_on_negative_values() {
+        let tensor = TestTensorInt::<1>::from([0, -1, 2]);
+
+        let _ = tensor.bincount(None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bincount_should_panic_when_num_bins_is_too_small() {
+        let tensor = TestTensorInt::<1>::from([0, 1, 1, 3]);
+
+        let _ = tensor.bincount(Some(3));
+    }
+
+    #[test]
+    fn bincount_capped_should_drop_values_above_maxlength() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 2, 1000000, 5], &device);
+
+        let output = tensor.bincount_capped(4, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 2, 0]), false);
+    }
+
+    #[test]
+    fn bincount_capped_should_clamp_values_above_maxlength_into_last_bin() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 2, 1000000, 5], &device);
+
+        let output = tensor.bincount_capped(4, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 0, 2, 2]), false);
+    }
+
+    #[test]
+    fn bincount_capped_should_not_pad_to_maxlength() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 1, 0], &device);
+
+        let output = tensor.bincount_capped(10, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2]), false);
+    }
+}
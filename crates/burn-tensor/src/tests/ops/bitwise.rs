@@ -0,0 +1,211 @@
+#[burn_tensor_testgen::testgen(bitwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, TensorData};
+
+    /// The bit width of the int element type of the test backend.
+    fn bits() -> i64 {
+        core::mem::size_of::<<TestBackend as Backend>::IntElem>() as i64 * 8
+    }
+
+    #[test]
+    fn should_apply_bitwise_and() {
+        let tensor_1 = TestTensorInt::<2>::from([[3, 5, 12], [-1, -8, 7]]);
+        let tensor_2 = TestTensorInt::from([[6, 1, 10], [42, 12, -2]]);
+
+        let output = tensor_1.bitwise_and(tensor_2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 1, 8], [42, 8, 6]]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_or() {
+        let tensor_1 = TestTensorInt::<2>::from([[3, 5, 12], [-1, -8, 7]]);
+        let tensor_2 = TestTensorInt::from([[6, 1, 10], [42, 3, -2]]);
+
+        let output = tensor_1.bitwise_or(tensor_2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[7, 5, 14], [-1, -5, -1]]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_xor() {
+        let tensor_1 = TestTensorInt::<2>::from([[3, 5, 12], [-1, -8, 7]]);
+        let tensor_2 = TestTensorInt::from([[6, 1, 10], [42, 3, -2]]);
+
+        let output = tensor_1.bitwise_xor(tensor_2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 4, 6], [-43, -5, -7]]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_ops_to_negative_operands_as_twos_complement() {
+        let tensor_1 = TestTensorInt::<1>::from([-1, -2, -128, -7]);
+        let tensor_2 = TestTensorInt::from([-1, -3, -1, -9]);
+
+        let and = tensor_1.clone().bitwise_and(tensor_2.clone());
+        let or = tensor_1.clone().bitwise_or(tensor_2.clone());
+        let xor = tensor_1.bitwise_xor(tensor_2);
+
+        and.into_data()
+            .assert_eq(&TensorData::from([-1, -4, -128, -15]), false);
+        or.into_data()
+            .assert_eq(&TensorData::from([-1, -1, -1, -1]), false);
+        xor.into_data()
+            .assert_eq(&TensorData::from([0, 3, 127, 14]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_and_with_broadcast() {
+        let tensor_1 = TestTensorInt::<2>::from([[1, 2, 3], [-4, 5, -6]]);
+        let tensor_2 = TestTensorInt::from([[1, 3, -1]]);
+
+        let output = tensor_1.bitwise_and(tensor_2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [0, 1, -6]]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_and_scalar() {
+        let tensor = TestTensorInt::<1>::arange(0..16, &Default::default());
+
+        let output = tensor.bitwise_and_scalar(3);
+
+        output.into_data().assert_eq(
+            &TensorData::from([0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_apply_bitwise_scalar_ops_to_negative_operands() {
+        let tensor = TestTensorInt::<1>::from([-5, -1, 0, 6]);
+
+        let and = tensor.clone().bitwise_and_scalar(-4);
+        let or = tensor.clone().bitwise_or_scalar(-8);
+        let xor = tensor.bitwise_xor_scalar(-1);
+
+        and.into_data()
+            .assert_eq(&TensorData::from([-8, -4, 0, 4]), false);
+        or.into_data()
+            .assert_eq(&TensorData::from([-5, -1, -8, -2]), false);
+        xor.into_data()
+            .assert_eq(&TensorData::from([4, 0, -1, -7]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_not() {
+        let tensor = TestTensorInt::<2>::from([[-3, -1, 0], [1, 2, 127]]);
+
+        let output = tensor.bitwise_not();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 0, -1], [-2, -3, -128]]), false);
+    }
+
+    #[test]
+    fn should_get_back_the_tensor_after_applying_bitwise_not_twice() {
+        let data = TensorData::from([-1024, -7, -1, 0, 1, 5, 4096]);
+        let tensor = TestTensorInt::<1>::from_data(data.clone(), &Default::default());
+
+        let output = tensor.bitwise_not().bitwise_not();
+
+        output.into_data().assert_eq(&data, false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_left_shift() {
+        let tensor = TestTensorInt::<2>::from([[1, 3, -1], [-8, 5, 0]]);
+        let shift = TestTensorInt::from([[0, 2, 3], [1, 4, 7]]);
+
+        let output = tensor.bitwise_left_shift(shift);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 12, -8], [-16, 80, 0]]), false);
+    }
+
+    #[test]
+    fn should_apply_arithmetic_bitwise_right_shift() {
+        let tensor = TestTensorInt::<2>::from([[16, -16, 7], [-1, -7, 100]]);
+        let shift = TestTensorInt::from([[2, 2, 1], [5, 1, 0]]);
+
+        let output = tensor.bitwise_right_shift(shift);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4, -4, 3], [-1, -4, 100]]), false);
+    }
+
+    #[test]
+    fn should_give_zeros_when_left_shift_amount_is_out_of_range() {
+        let bits = bits();
+        let tensor = TestTensorInt::<1>::from([1, 5, -5, -1]);
+        let shift = TestTensorInt::from([-1, bits, bits + 1, -bits]);
+
+        let output = tensor.clone().bitwise_left_shift(shift);
+        let output_scalar = tensor.clone().bitwise_left_shift_scalar(bits);
+        let output_negative_scalar = tensor.bitwise_left_shift_scalar(-1);
+
+        let expected = TensorData::from([0, 0, 0, 0]);
+        output.into_data().assert_eq(&expected, false);
+        output_scalar.into_data().assert_eq(&expected, false);
+        output_negative_scalar
+            .into_data()
+            .assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_sign_fill_when_right_shift_amount_is_out_of_range() {
+        let bits = bits();
+        let tensor = TestTensorInt::<1>::from([5, -5, 7, -1]);
+        let shift = TestTensorInt::from([-1, bits, bits + 1, -bits]);
+
+        let output = tensor.clone().bitwise_right_shift(shift);
+        let output_scalar = tensor.clone().bitwise_right_shift_scalar(bits);
+        let output_negative_scalar = tensor.bitwise_right_shift_scalar(-1);
+
+        let expected = TensorData::from([0, -1, 0, -1]);
+        output.into_data().assert_eq(&expected, false);
+        output_scalar.into_data().assert_eq(&expected, false);
+        output_negative_scalar
+            .into_data()
+            .assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_shift_by_the_largest_in_range_amount() {
+        let max_shift = bits() - 1;
+        let tensor = TestTensorInt::<1>::from([1, 3, 0]);
+
+        let shifted = tensor.bitwise_left_shift_scalar(max_shift);
+        let output = shifted.bitwise_right_shift_scalar(max_shift);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1, -1, 0]), false);
+    }
+
+    #[test]
+    fn should_apply_bitwise_shift_scalar() {
+        let tensor = TestTensorInt::<1>::from([1, 2, -4, 9]);
+
+        let left = tensor.clone().bitwise_left_shift_scalar(2);
+        let right = tensor.bitwise_right_shift_scalar(1);
+
+        left.into_data()
+            .assert_eq(&TensorData::from([4, 8, -16, 36]), false);
+        right
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, -2, 4]), false);
+    }
+}
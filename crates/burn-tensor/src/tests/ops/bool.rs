@@ -18,4 +18,20 @@ mod tests {
         let data_expected = TensorData::from([[false, true, false], [true, true, true]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn test_int_bool_round_trip() {
+        let tensor = TestTensorInt::<1>::from([0, 5, -3, 0]);
+
+        let bool_tensor = tensor.bool();
+        bool_tensor
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([false, true, true, false]), false);
+
+        bool_tensor
+            .int()
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 1, 0]), false);
+    }
 }
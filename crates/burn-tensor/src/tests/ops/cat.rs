@@ -2,6 +2,7 @@
 mod tests {
     use super::*;
     use alloc::vec::Vec;
+    use burn_tensor::ops::IntTensorOps;
     use burn_tensor::{Bool, Int, Tensor, TensorData};
     #[test]
     fn should_support_cat_ops_2d_dim0() {
@@ -66,6 +67,45 @@ mod tests {
         output.into_data().assert_approx_eq(&expected, 3);
     }
 
+    #[test]
+    fn should_skip_zero_length_tensors_int() {
+        let device = Default::default();
+        let tensor_1 = TestTensorInt::<2>::from_data([[1, 2, 3], [4, 5, 6]], &device);
+        let tensor_2 =
+            TestTensorInt::<2>::from_data(TensorData::new(Vec::<i32>::new(), [0, 3]), &device);
+        let tensor_3 = TestTensorInt::<2>::from_data([[7, 8, 9]], &device);
+
+        let output = Tensor::cat(vec![tensor_1, tensor_2, tensor_3], 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]), false);
+    }
+
+    #[test]
+    fn should_support_cat_of_only_zero_length_tensors_int() {
+        let device = Default::default();
+        let tensor_1 =
+            TestTensorInt::<2>::from_data(TensorData::new(Vec::<i32>::new(), [0, 3]), &device);
+        let tensor_2 =
+            TestTensorInt::<2>::from_data(TensorData::new(Vec::<i32>::new(), [0, 3]), &device);
+
+        let output = Tensor::cat(vec![tensor_1, tensor_2], 0);
+
+        assert_eq!(output.dims(), [0, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_zero_length_tensor_has_other_dims_mismatch_int() {
+        let device = Default::default();
+        let tensor_1 = TestTensorInt::<2>::from_data([[1, 2, 3]], &device);
+        let tensor_2 =
+            TestTensorInt::<2>::from_data(TensorData::new(Vec::<i32>::new(), [0, 2]), &device);
+
+        Tensor::cat(vec![tensor_1, tensor_2], 0);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_dimensions_are_not_the_same() {
@@ -92,4 +132,23 @@ mod tests {
 
         TestTensor::cat(vec![tensor_1, tensor_2], 3).into_data();
     }
+
+    #[test]
+    fn should_return_single_tensor_unchanged_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let output = Tensor::cat(vec![tensor.clone()], 1);
+        let primitive = TestBackend::int_cat::<2>(vec![tensor.clone().into_primitive()], 0);
+
+        output.into_data().assert_eq(&tensor.to_data(), false);
+        TestTensorInt::<2>::from_primitive(primitive)
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_int_cat_op_gets_no_tensors() {
+        let _output = TestBackend::int_cat::<2>(vec![], 0);
+    }
 }
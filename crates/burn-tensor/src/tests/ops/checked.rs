@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(checked)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Element, Int, Tensor, TensorData};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    #[test]
+    fn checked_add_should_flag_overflows_near_max() {
+        let device = Default::default();
+        let (min, max) = IntElem::dtype().int_bounds().unwrap();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([max, max - 1, 5], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([1, 1, 2], &device);
+
+        let (output, overflowed) = lhs.checked_add(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([min, max, 7]), false);
+        overflowed
+            .into_data()
+            .assert_eq(&TensorData::from([true, false, false]), false);
+    }
+
+    #[test]
+    fn checked_ops_should_not_flag_in_range_results() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2, Int>::from_ints([[1, -2], [30, 0]], &device);
+        let rhs = Tensor::<TestBackend, 2, Int>::from_ints([[4, 5], [-6, 7]], &device);
+        let expected = TensorData::from([[false, false], [false, false]]);
+
+        let (sum, overflowed_sum) = lhs.clone().checked_add(rhs.clone());
+        let (difference, overflowed_difference) = lhs.clone().checked_sub(rhs.clone());
+        let (product, overflowed_product) = lhs.checked_mul(rhs);
+
+        sum.into_data()
+            .assert_eq(&TensorData::from([[5, 3], [24, 7]]), false);
+        difference
+            .into_data()
+            .assert_eq(&TensorData::from([[-3, -7], [36, -7]]), false);
+        product
+            .into_data()
+            .assert_eq(&TensorData::from([[4, -10], [-180, 0]]), false);
+        overflowed_sum.into_data().assert_eq(&expected, false);
+        overflowed_difference
+            .into_data()
+            .assert_eq(&expected, false);
+        overflowed_product.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn checked_sub_and_mul_should_flag_overflows() {
+        let device = Default::default();
+        let (min, max) = IntElem::dtype().int_bounds().unwrap();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([min, max], &device);
+        let subtrahend = Tensor::<TestBackend, 1, Int>::from_data([1, -1], &device);
+        let factor = Tensor::<TestBackend, 1, Int>::from_data([1, 2], &device);
+
+        let (difference, overflowed_difference) = lhs.clone().checked_sub(subtrahend);
+        let (product, overflowed_product) = lhs.checked_mul(factor);
+
+        difference
+            .into_data()
+            .assert_eq(&TensorData::from([max, min]), false);
+        overflowed_difference
+            .into_data()
+            .assert_eq(&TensorData::from([true, true]), false);
+        product
+            .into_data()
+            .assert_eq(&TensorData::from([min, -2]), false);
+        overflowed_product
+            .into_data()
+            .assert_eq(&TensorData::from([false, true]), false);
+    }
+}
@@ -76,6 +76,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chunk_last_chunk_holds_remainder() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..10, &Default::default()).chunk(3, 0);
+
+        let sizes = tensors
+            .iter()
+            .map(|tensor| tensor.dims()[0])
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [4, 4, 2]);
+        tensors[2]
+            .to_data()
+            .assert_eq(&TensorData::from([8, 9]), false);
+    }
+
+    #[test]
+    fn test_chunk_returns_fewer_chunks_when_remainder_is_covered() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..6, &Default::default()).chunk(4, 0);
+
+        let sizes = tensors
+            .iter()
+            .map(|tensor| tensor.dims()[0])
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [2, 2, 2]);
+    }
+
     #[test]
     fn test_chunk_multi_dimension() {
         let tensors: Vec<Tensor<TestBackend, 2, Int>> =
@@ -95,4 +122,33 @@ mod tests {
         let tensors: Vec<Tensor<TestBackend, 1, Int>> =
             Tensor::arange(0..12, &Default::default()).chunk(6, 1);
     }
+
+    #[test]
+    fn test_split_int_sizes() {
+        let tensor = TestTensorInt::<1>::arange(0..10, &Default::default());
+
+        let sections = tensor.clone().split(&[3, 3, 4], 0);
+
+        assert_eq!(sections.len(), 3);
+        sections[0]
+            .to_data()
+            .assert_eq(&TensorData::from([0, 1, 2]), false);
+        sections[1]
+            .to_data()
+            .assert_eq(&TensorData::from([3, 4, 5]), false);
+        sections[2]
+            .to_data()
+            .assert_eq(&TensorData::from([6, 7, 8, 9]), false);
+        Tensor::cat(sections, 0)
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_int_sizes_not_summing_to_dim() {
+        let tensor = TestTensorInt::<1>::arange(0..10, &Default::default());
+
+        let _ = tensor.split(&[3, 3, 3], 0);
+    }
 }
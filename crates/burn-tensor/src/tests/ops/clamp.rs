@@ -1,6 +1,7 @@
 #[burn_tensor_testgen::testgen(clamp)]
 mod tests {
     use super::*;
+    use burn_tensor::ops::ClampError;
     use burn_tensor::{Int, Tensor, TensorData};
 
     #[test]
@@ -70,4 +71,89 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[1, 1, 2], [3, 4, 4]]), false);
     }
+
+    #[test]
+    fn clamp_checked_should_support_valid_bounds_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([-3, 0, 7], &device);
+
+        let output = tensor.clamp_checked(-1, 5).unwrap();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-1, 0, 5]), false);
+    }
+
+    #[test]
+    fn clamp_checked_should_reject_inverted_bounds_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([-3, 0, 7], &device);
+
+        let output = tensor.clamp_checked(5, -1);
+
+        assert_eq!(
+            output.err(),
+            Some(ClampError::InvertedBounds { min: 5, max: -1 })
+        );
+    }
+
+    #[test]
+    fn threshold_should_replace_boundary_value_when_exclusive() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 2, 3], [4, 5, 6]], &device);
+
+        let output = tensor.threshold(4, -1, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-1, -1, -1], [-1, 5, 6]]), false);
+    }
+
+    #[test]
+    fn threshold_should_keep_boundary_value_when_inclusive() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 2, 3], [4, 5, 6]], &device);
+
+        let output = tensor.threshold(4, -1, true);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-1, -1, -1], [4, 5, 6]]), false);
+    }
+
+    #[test]
+    fn should_clamp_int_tensor_against_broadcast_bounds() {
+        let tensor = TestTensorInt::<2>::from([[-5, 0, 5], [10, -10, 3]]);
+        let min = TestTensorInt::from([[-1, 0, 1]]);
+        let max = TestTensorInt::from([[1, 2, 3]]);
+
+        let output = tensor.clamp_tensor(min, max);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-1, 0, 3], [1, 0, 3]]), false);
+    }
+
+    #[test]
+    fn should_match_scalar_clamp_with_filled_bounds() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from([[-7, -2, 0], [3, 4, 12]]);
+        let min = TestTensorInt::full([2, 3], -2, &device);
+        let max = TestTensorInt::full([2, 3], 4, &device);
+
+        let output = tensor.clone().clamp_tensor(min, max);
+        let expected = tensor.clamp(-2, 4);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_clamp_bounds_cant_be_broadcast() {
+        let tensor = TestTensorInt::<2>::from([[-5, 0, 5], [10, -10, 3]]);
+        let min = TestTensorInt::from([[-1, 0]]);
+        let max = TestTensorInt::from([[1, 2, 3]]);
+
+        let _ = tensor.clamp_tensor(min, max);
+    }
 }
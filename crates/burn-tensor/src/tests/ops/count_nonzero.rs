@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(count_nonzero)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_count_nonzero_int() {
+        let tensor = TestTensorInt::<1>::from([0, 3, 0, 7]);
+
+        let output = tensor.count_nonzero();
+
+        output.into_data().assert_eq(&TensorData::from([2]), false);
+    }
+
+    #[test]
+    fn should_count_nonzero_dim_int() {
+        let tensor = TestTensorInt::<2>::from([[0, 3, 0], [1, -2, 3]]);
+
+        let output = tensor.count_nonzero_dim(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [3]]), false);
+    }
+}
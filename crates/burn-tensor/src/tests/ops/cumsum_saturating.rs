@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(cumsum_saturating)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_saturate_u8_counter_instead_of_wrapping() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([100, 100, 100, 1, 7], &device);
+
+        let output = tensor.cumsum_saturating(0, u8::MAX);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([100, 200, 255, 255, 255]), false);
+    }
+
+    #[test]
+    fn should_support_cumsum_saturating_dim1() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [250, 4, 5]], &device);
+
+        let output = tensor.cumsum_saturating(1, u8::MAX);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3, 6], [250, 254, 255]]), false);
+    }
+}
@@ -0,0 +1,140 @@
+#[burn_tensor_testgen::testgen(diagonal)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn diagonal_scatter_should_write_main_diagonal() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let src = Tensor::<TestBackend, 1, Int>::from_ints([10, 20], &device);
+
+        let output = tensor.diagonal_scatter(src, 0, 0, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[10, 2, 3], [4, 20, 6]]), false);
+    }
+
+    #[test]
+    fn diagonal_scatter_should_write_offset_diagonals() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::zeros([3, 3], &device);
+
+        let output = tensor
+            .diagonal_scatter(TestTensorInt::<1>::from_ints([1, 2], &device), 1, 0, 1)
+            .diagonal_scatter(TestTensorInt::<1>::from_ints([3], &device), -2, 0, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 0], [0, 0, 2], [3, 0, 0]]), false);
+    }
+
+    #[test]
+    fn diagonal_scatter_should_broadcast_over_other_dims() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3, Int>::zeros([2, 2, 2], &device);
+        let src = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4]], &device);
+
+        // The diagonal is over the dims 0 and 2, the leading dim of `src` is the dim 1.
+        let output = tensor.diagonal_scatter(src, 0, 0, 2);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[1, 0], [3, 0]], [[0, 2], [0, 4]]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn diagonal_scatter_should_panic_on_length_mismatch() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::zeros([3, 3], &device);
+        let src = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        tensor.diagonal_scatter(src, 1, 0, 1);
+    }
+
+    #[test]
+    fn diagonal_should_extract_main_and_offset_diagonals() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6], [7, 8, 9]], &device);
+
+        let main = tensor.clone().diagonal(0);
+        let upper = tensor.clone().diagonal(1);
+        let lower = tensor.clone().diagonal(-2);
+        let outside = tensor.diagonal(3);
+
+        main.into_data()
+            .assert_eq(&TensorData::from([1, 5, 9]), false);
+        upper
+            .into_data()
+            .assert_eq(&TensorData::from([2, 6]), false);
+        lower.into_data().assert_eq(&TensorData::from([7]), false);
+        assert_eq!(outside.dims(), [0]);
+    }
+
+    #[test]
+    fn diagonal_should_extract_from_rectangular_matrix() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3, 4], [5, 6, 7, 8]], &device);
+
+        let main = tensor.clone().diagonal(0);
+        let upper = tensor.diagonal(2);
+
+        main.into_data().assert_eq(&TensorData::from([1, 6]), false);
+        upper
+            .into_data()
+            .assert_eq(&TensorData::from([3, 8]), false);
+    }
+
+    #[test]
+    fn diag_embed_should_place_values_on_diagonal() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        let main = tensor.clone().diag_embed(0);
+        let upper = tensor.diag_embed(1);
+
+        main.into_data()
+            .assert_eq(&TensorData::from([[1, 0, 0], [0, 2, 0], [0, 0, 3]]), false);
+        upper.into_data().assert_eq(
+            &TensorData::from([[0, 1, 0, 0], [0, 0, 2, 0], [0, 0, 0, 3], [0, 0, 0, 0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn diag_embed_should_round_trip_with_diagonal() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([4, -5], &device);
+
+        let output = tensor.diag_embed(-1).diagonal(-1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([4, -5]), false);
+    }
+
+    #[test]
+    fn trace_should_sum_main_diagonal() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4]], &device);
+
+        let output = tensor.trace();
+
+        output.into_data().assert_eq(&TensorData::from([5]), false);
+    }
+
+    #[test]
+    fn trace_should_use_smallest_dim_of_rectangular_matrix() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, -5, 6]], &device);
+
+        let output = tensor.trace();
+
+        output.into_data().assert_eq(&TensorData::from([-4]), false);
+    }
+}
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(diff)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_first_order_diff() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, 6, 10], &device);
+
+        let output = tensor.diff(0, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2, 3, 4]), false);
+    }
+
+    #[test]
+    fn should_support_second_order_diff() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, 6, 10], &device);
+
+        let output = tensor.diff(0, 2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 1]), false);
+    }
+
+    #[test]
+    fn should_support_diff_along_inner_dim() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 4, 2], [0, -3, 5]], &device);
+
+        let output = tensor.diff(1, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, -2], [-3, 8]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn diff_should_panic_when_order_exceeds_dim_size() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 3], &device);
+
+        let _output = tensor.diff(0, 3);
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(diff_report)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::IntTensorDiff;
+    use burn_tensor::{Int, Tensor};
+
+    #[test]
+    fn should_report_two_differing_elements() {
+        let device = Default::default();
+        let expected = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let actual = Tensor::<TestBackend, 2, Int>::from_ints([[1, -2, 3], [4, 5, 9]], &device);
+
+        let report = actual.diff_report(expected);
+
+        assert_eq!(report, IntTensorDiff::new(2, Some(1), 4));
+    }
+
+    #[test]
+    fn should_report_no_difference() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        let report = tensor.clone().diff_report(tensor);
+
+        assert_eq!(report, IntTensorDiff::new(0, None, 0));
+    }
+}
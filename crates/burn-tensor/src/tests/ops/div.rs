@@ -77,6 +77,39 @@ mod tests {
             .assert_eq(&TensorData::from([[0, 1, 1], [0, 0, 0]]), false);
     }
 
+    #[test]
+    fn test_div_broadcast_bias_int() {
+        let tensor = TestTensorInt::<2>::from([[10, 40, 90], [40, 100, 180]]);
+        let divisor = TestTensorInt::<1>::from([10, 20, 30]).unsqueeze::<2>();
+
+        let output = tensor / divisor;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [4, 5, 6]]), false);
+    }
+
+    #[test]
+    fn test_div_broadcast_both_sides_int() {
+        let column = TestTensorInt::<2>::from([[12], [24]]);
+        let row = TestTensorInt::<2>::from([[1, 2, 3]]);
+
+        let output = column / row;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[12, 6, 4], [24, 12, 8]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_broadcast_incompatible_shapes_int() {
+        let tensor_1 = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let tensor_2 = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let _ = tensor_1 / tensor_2;
+    }
+
     #[test]
     fn should_support_div_scalar_ops_int() {
         let data = TensorData::from([[0, 1, 2], [3, 4, 5]]);
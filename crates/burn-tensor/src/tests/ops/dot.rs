@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(dot)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_compute_dot_product() {
+        let lhs = TestTensorInt::<1>::from([1, 2, 3]);
+        let rhs = TestTensorInt::<1>::from([4, 5, 6]);
+
+        let output = lhs.dot(rhs);
+
+        output.into_data().assert_eq(&TensorData::from([32]), false);
+    }
+
+    #[test]
+    fn should_compute_dot_product_with_negative_values() {
+        let lhs = TestTensorInt::<1>::from([-1, 2]);
+        let rhs = TestTensorInt::<1>::from([3, -4]);
+
+        let output = lhs.dot(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-11]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_should_panic_when_lengths_mismatch() {
+        let lhs = TestTensorInt::<1>::from([1, 2, 3]);
+        let rhs = TestTensorInt::<1>::from([4, 5]);
+
+        let _output = lhs.dot(rhs);
+    }
+
+    #[test]
+    fn should_compute_outer_product() {
+        let lhs = TestTensorInt::<1>::from([1, 2]);
+        let rhs = TestTensorInt::<1>::from([3, 4, -5]);
+
+        let output = lhs.outer(rhs);
+
+        assert_eq!(output.dims(), [2, 3]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 4, -5], [6, 8, -10]]), false);
+    }
+}
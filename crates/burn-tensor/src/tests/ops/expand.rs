@@ -99,6 +99,24 @@ mod tests {
             .assert_eq(&TensorData::from([[1, 2, 3], [1, 2, 3], [1, 2, 3]]), false);
     }
 
+    #[test]
+    fn expand_leading_unit_dim_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3]]);
+        let output = tensor.expand([4, 3]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1, 2, 3], [1, 2, 3], [1, 2, 3], [1, 2, 3]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_expand_non_unit_dim_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let _expanded_tensor = tensor.expand([4, 3]);
+    }
+
     #[test]
     fn should_all_negative_one() {
         let tensor = TestTensorInt::<1>::from([1, 2, 3]);
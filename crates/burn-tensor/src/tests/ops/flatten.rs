@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(flatten)]
 mod tests {
     use super::*;
-    use burn_tensor::{Shape, Tensor, TensorData};
+    use burn_tensor::{Int, Shape, Tensor, TensorData};
 
     /// Test if the function can successfully flatten a 4D tensor to a 1D tensor.
     #[test]
@@ -55,4 +55,28 @@ mod tests {
         let expected_shape = Shape::new([75]);
         assert_eq!(flattened_tensor.shape(), expected_shape);
     }
+
+    #[test]
+    fn should_flatten_trailing_dims_int() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24, &device).reshape([2, 3, 4]);
+
+        let flattened_tensor: Tensor<TestBackend, 2, Int> = tensor.flatten(1, 2);
+
+        assert_eq!(flattened_tensor.shape(), Shape::new([2, 12]));
+        flattened_tensor.into_data().assert_eq(
+            &TensorData::from([
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+                [12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_destination_dimensions() {
+        let tensor = TestTensorInt::<3>::ones(Shape::new([2, 3, 4]), &Default::default());
+        let flattened_tensor: Tensor<TestBackend, 3, Int> = tensor.flatten(1, 2);
+    }
 }
@@ -23,6 +23,54 @@ mod tests {
         assert_eq!(tensor.into_data(), flipped.into_data());
     }
 
+    #[test]
+    fn flip_int_2d_rows_cols_and_both() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        tensor
+            .clone()
+            .flip([0])
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 4, 5], [0, 1, 2]]), false);
+        tensor
+            .clone()
+            .flip([1])
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 1, 0], [5, 4, 3]]), false);
+        tensor
+            .flip([0, 1])
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 4, 3], [2, 1, 0]]), false);
+    }
+
+    #[test]
+    fn flip_int_twice_should_be_identity() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        let output = tensor.clone().flip([1]).flip([1]);
+
+        output.into_data().assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn flip_int_should_be_readable_by_other_ops() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        let flipped = tensor.clone().flip([0, 1]);
+        let output = flipped.clone().reshape([6]);
+        let sum = flipped.clone() + tensor;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([5, 4, 3, 2, 1, 0]), false);
+        sum.into_data()
+            .assert_eq(&TensorData::from([[5, 5, 5], [5, 5, 5]]), false);
+        flipped
+            .slice([0..1, 1..3])
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 3]]), false);
+    }
+
     #[test]
     fn flip_float() {
         let device = Default::default();
@@ -99,4 +147,27 @@ mod tests {
         // Test with an out of bound axis
         let _ = tensor.clone().flip([3, 0, 1]);
     }
+
+    #[test]
+    fn slice_reverse_should_reverse_middle_window_int() {
+        let tensor = TestTensorInt::<1>::arange(0..8, &Default::default());
+
+        let output = tensor.slice_reverse([(2..6, true)]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 5, 4, 3, 2, 6, 7]), false);
+    }
+
+    #[test]
+    fn slice_reverse_should_only_flip_flagged_dims_int() {
+        let tensor = TestTensorInt::<1>::arange(0..12, &Default::default()).reshape([3, 4]);
+
+        let output = tensor.slice_reverse([(0..2, false), (1..4, true)]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0, 3, 2, 1], [4, 7, 6, 5], [8, 9, 10, 11]]),
+            false,
+        );
+    }
 }
@@ -1,6 +1,7 @@
 #[burn_tensor_testgen::testgen(gather_scatter)]
 mod tests {
     use super::*;
+    use burn_tensor::ops::ReduceKind;
     use burn_tensor::{Tensor, TensorData};
 
     #[test]
@@ -29,6 +30,20 @@ mod tests {
             .assert_eq(&TensorData::from([6, 6, 5, 6, 7]), false);
     }
 
+    #[test]
+    fn should_gather_with_i32_indices_from_i64_data_int() {
+        let device = Default::default();
+        let tensor =
+            TestTensorInt::<1>::from_data(TensorData::new(vec![5i64, 6, 7, 8], [4]), &device);
+        let indices = TestTensorInt::from_data(TensorData::new(vec![3i32, 0, 2, 3], [4]), &device);
+
+        let output = tensor.gather(0, indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([8, 5, 7, 8]), false);
+    }
+
     #[test]
     fn should_gather_2d_dim0() {
         let device = Default::default();
@@ -200,4 +215,207 @@ mod tests {
 
         tensor.scatter(0, indices, values);
     }
+
+    #[test]
+    #[should_panic(expected = "The shape differs: [2, 2] != [2, 3]. Tensor shape [2, 3].")]
+    fn scatter_should_panic_on_mis_shaped_values_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::zeros([2, 3], &device);
+        let values = TestTensorInt::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let indices = TestTensorInt::from_ints([[0, 1], [1, 2]], &device);
+
+        tensor.scatter(1, indices, values);
+    }
+
+    #[test]
+    fn should_take_along_dim_column_of_each_row_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 2, 3], [4, 5, 6], [7, 8, 9]], &device);
+
+        let output = tensor
+            .clone()
+            .take_along_dim(TestTensorInt::from_ints([[2]], &device), 1);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3], [6], [9]]), false);
+
+        let output = tensor.take_along_dim(TestTensorInt::from_ints([[2], [0], [1]], &device), 1);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3], [4], [8]]), false);
+    }
+
+    #[test]
+    fn should_take_along_dim_of_transposed_tensor_int() {
+        let device = Default::default();
+        let tensor =
+            TestTensorInt::<2>::from_ints([[1, 2, 3], [4, 5, 6], [7, 8, 9]], &device).transpose();
+
+        let output = tensor.take_along_dim(TestTensorInt::from_ints([[2]], &device), 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[7], [8], [9]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn take_along_dim_should_panic_on_incompatible_shapes() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 2, 3], [4, 5, 6], [7, 8, 9]], &device);
+        let indices = TestTensorInt::from_ints([[0], [1]], &device);
+
+        let _ = tensor.take_along_dim(indices, 1);
+    }
+
+    #[test]
+    fn should_gather_broadcast_leading_dim_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..32, &device).reshape([8, 4]);
+        let indices = TestTensorInt::from_ints([[3, 0, 0, 1]], &device);
+
+        let output = tensor.gather_broadcast(1, indices);
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                [3, 0, 0, 1],
+                [7, 4, 4, 5],
+                [11, 8, 8, 9],
+                [15, 12, 12, 13],
+                [19, 16, 16, 17],
+                [23, 20, 20, 21],
+                [27, 24, 24, 25],
+                [31, 28, 28, 29],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_broadcast_should_panic_on_incompatible_shapes() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..32, &device).reshape([8, 4]);
+        let indices = TestTensorInt::from_ints([[0, 1], [1, 0]], &device);
+
+        tensor.gather_broadcast(1, indices);
+    }
+
+    #[test]
+    fn should_reconstruct_with_gather_scatter_roundtrip_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[5, 6, 7], [8, 9, 10]], &device);
+
+        burn_tensor::tests::utils::assert_gather_scatter_roundtrip(
+            tensor.clone(),
+            0,
+            TestTensorInt::from_ints([[1, 0, 1], [0, 1, 0]], &device),
+        );
+        burn_tensor::tests::utils::assert_gather_scatter_roundtrip(
+            tensor,
+            1,
+            TestTensorInt::from_ints([[2, 0, 1], [1, 2, 0]], &device),
+        );
+    }
+
+    #[test]
+    fn should_gather_masked_with_default_for_invalid_indices_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[5, 6, 7], [8, 9, 10]], &device);
+        let indices = TestTensorInt::from_ints([[2, 100], [-7, 0]], &device);
+        let valid =
+            TestTensorBool::from_bool(TensorData::from([[true, false], [false, true]]), &device);
+
+        let output = tensor.gather_masked(1, indices, valid, -1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[7, -1], [-1, 8]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_gather_masked_when_mask_shape_mismatch_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[5, 6, 7], [8, 9, 10]], &device);
+        let indices = TestTensorInt::from_ints([[2, 1], [0, 0]], &device);
+        let valid = TestTensorBool::from_bool(TensorData::from([[true, false]]), &device);
+
+        tensor.gather_masked(1, indices, valid, -1);
+    }
+
+    #[test]
+    fn should_scatter_reduce_mean_with_counts_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 0, 0], [10, 10, 10]], &device);
+        let values = TestTensorInt::from_ints([[6, 3, 9, 3], [5, 1, 2, 7]], &device);
+        let indices = TestTensorInt::from_ints([[0, 2, 0, 0], [1, 1, 0, 1]], &device);
+
+        let (output, counts) =
+            tensor.scatter_reduce_with_counts(1, indices, values, ReduceKind::Mean);
+
+        // Row 0: slot 0 gets [6, 9, 3] -> (0 + 18) / 4, slot 1 gets nothing, slot 2 gets [3].
+        // Row 1: slot 0 gets [2], slot 1 gets [5, 1, 7] -> (10 + 13) / 4, slot 2 gets nothing.
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 0, 1], [6, 5, 10]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 0, 1], [1, 3, 0]]), false);
+    }
+
+    #[test]
+    fn should_scatter_reduce_with_counts_dim0_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 1], [1, 1], [1, 1]], &device);
+        let values = TestTensorInt::from_ints([[2, 3], [4, 5]], &device);
+        let indices = TestTensorInt::from_ints([[0, 2], [0, 0]], &device);
+
+        let (output, counts) =
+            tensor.scatter_reduce_with_counts(0, indices, values, ReduceKind::Prod);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[8, 5], [1, 1], [1, 3]]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 1], [0, 0], [0, 1]]), false);
+    }
+
+    fn scatter_reduce_int(reduce: ReduceKind) -> TensorData {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[2, 2, 2], [-3, -3, -3]], &device);
+        let values = TestTensorInt::from_ints([[4, -1, 3], [5, 1, -6]], &device);
+        let indices = TestTensorInt::from_ints([[0, 0, 2], [1, 1, 1]], &device);
+
+        tensor
+            .scatter_reduce(1, indices, values, reduce)
+            .into_data()
+    }
+
+    #[test]
+    fn should_scatter_reduce_each_kind_int() {
+        // Row 0: slot 0 gets [4, -1], slot 1 gets nothing, slot 2 gets [3].
+        // Row 1: slot 1 gets [5, 1, -6], slots 0 and 2 get nothing.
+        scatter_reduce_int(ReduceKind::Sum)
+            .assert_eq(&TensorData::from([[5, 2, 5], [-3, -3, -3]]), false);
+        scatter_reduce_int(ReduceKind::Prod)
+            .assert_eq(&TensorData::from([[-8, 2, 6], [-3, 90, -3]]), false);
+        scatter_reduce_int(ReduceKind::Max)
+            .assert_eq(&TensorData::from([[4, 2, 3], [-3, 5, -3]]), false);
+        scatter_reduce_int(ReduceKind::Min)
+            .assert_eq(&TensorData::from([[-1, 2, 2], [-3, -6, -3]]), false);
+        scatter_reduce_int(ReduceKind::Mean)
+            .assert_eq(&TensorData::from([[1, 2, 2], [-3, 0, -3]]), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't index a tensor with (2) dimensions on axis (2)")]
+    fn gather_broadcast_should_panic_when_dim_equals_rank() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 2], [3, 4]], &device);
+        let indices = TestTensorInt::<2>::from_ints([[0, 1]], &device);
+
+        let _output = tensor.gather_broadcast(2, indices);
+    }
 }
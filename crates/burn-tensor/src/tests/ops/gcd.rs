@@ -0,0 +1,44 @@
+#[burn_tensor_testgen::testgen(gcd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_gcd() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_ints([12, -12, 7, 0, 0], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_ints([18, 18, 5, 4, 0], &device);
+
+        let output = lhs.gcd(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([6, 6, 1, 4, 0]), false);
+    }
+
+    #[test]
+    fn should_support_lcm() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_ints([4, -4, 3, 0], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_ints([6, 6, 5, 9], &device);
+
+        let output = lhs.lcm(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([12, 12, 15, 0]), false);
+    }
+
+    #[test]
+    fn should_support_gcd_broadcast() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2, Int>::from_ints([[1920], [1280]], &device);
+        let rhs = Tensor::<TestBackend, 2, Int>::from_ints([[1080, 720]], &device);
+
+        let output = lhs.gcd(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[120, 240], [40, 80]]), false);
+    }
+}
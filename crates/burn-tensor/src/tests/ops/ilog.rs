@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(ilog)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_ilog2() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3, 4, 8], &device);
+
+        let output = tensor.ilog2();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 1, 2, 3]), false);
+    }
+
+    #[test]
+    fn should_support_ilog_at_powers_of_the_base() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 9, 10, 999, 1000, 1001], &device);
+
+        let output = tensor.ilog(10);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1, 2, 3, 3]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_should_panic_on_zero() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([4, 0], &device);
+
+        let _output = tensor.ilog2().into_data();
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog_should_panic_on_base_one() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([4, 8], &device);
+
+        let _output = tensor.ilog(1).into_data();
+    }
+}
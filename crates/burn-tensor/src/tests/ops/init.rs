@@ -1,7 +1,8 @@
 #[burn_tensor_testgen::testgen(init)]
 mod tests {
     use super::*;
-    use burn_tensor::{Bool, Int, Tensor, TensorData};
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Bool, ElementConversion, Int, Tensor, TensorData};
 
     #[test]
     fn should_support_float_empty() {
@@ -67,4 +68,20 @@ mod tests {
         let tensor = Tensor::<TestBackend, 2, Bool>::empty(shape, &Default::default());
         assert_eq!(tensor.shape(), shape.into())
     }
+
+    #[test]
+    fn should_support_int_from_slice() {
+        let device = Default::default();
+        let values: [<TestBackend as Backend>::IntElem; 4] =
+            [3.elem(), (-1).elem(), 0.elem(), 7.elem()];
+
+        let tensor = Tensor::<TestBackend, 1, Int>::from_slice(&values, &device);
+        let expected = Tensor::<TestBackend, 1, Int>::from_data(
+            TensorData::new(values.to_vec(), [4]),
+            &device,
+        );
+
+        assert_eq!(tensor.shape(), [4].into());
+        tensor.into_data().assert_eq(&expected.into_data(), true);
+    }
 }
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(inplace)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn inplace_ops_should_match_regular_ops() {
+        let lhs = TestTensorInt::<2>::from([[1, -2, 3], [4, 5, -6]]);
+        let rhs = TestTensorInt::<2>::from([[7, 8, -9], [0, 2, 3]]);
+
+        let add = lhs.clone().add_inplace(rhs.clone());
+        let sub = lhs.clone().sub_inplace(rhs.clone());
+        let mul = lhs.clone().mul_inplace(rhs.clone());
+
+        add.into_data()
+            .assert_eq(&(lhs.clone() + rhs.clone()).into_data(), false);
+        sub.into_data()
+            .assert_eq(&(lhs.clone() - rhs.clone()).into_data(), false);
+        mul.into_data().assert_eq(&(lhs * rhs).into_data(), false);
+    }
+
+    #[test]
+    fn inplace_ops_should_not_modify_shared_tensors() {
+        let lhs = TestTensorInt::<1>::from([1, 2, 3]);
+        let rhs = TestTensorInt::<1>::from([10, 20, 30]);
+
+        let output = lhs.clone().add_inplace(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([11, 22, 33]), false);
+        lhs.into_data()
+            .assert_eq(&TensorData::from([1, 2, 3]), false);
+    }
+
+    #[test]
+    fn inplace_ops_should_broadcast() {
+        let lhs = TestTensorInt::<2>::from([[1], [2]]);
+        let rhs = TestTensorInt::<2>::from([[10, 20, 30]]);
+
+        let output = lhs.mul_inplace(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[10, 20, 30], [20, 40, 60]]), false);
+    }
+
+    #[test]
+    fn add_inplace_should_accumulate_in_a_loop() {
+        let device = Default::default();
+        let mut histogram = TestTensorInt::<1>::zeros([3], &device);
+
+        for i in 0..5 {
+            let delta = TestTensorInt::<1>::from([1, i, 2]);
+            histogram = histogram.add_inplace(delta);
+        }
+
+        histogram
+            .into_data()
+            .assert_eq(&TensorData::from([5, 10, 10]), false);
+    }
+}
@@ -0,0 +1,124 @@
+#[burn_tensor_testgen::testgen(isqrt)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{DType, Element, Int, Tensor, TensorData};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    #[test]
+    fn should_support_isqrt_of_exact_squares() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 4, 9, 144, 1_000_000], &device);
+
+        let output = tensor.isqrt();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 2, 3, 12, 1000]), false);
+    }
+
+    #[test]
+    fn should_support_isqrt_around_squares() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([2, 3, 5, 15, 17, 99, 101], &device);
+
+        let output = tensor.isqrt();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 1, 2, 3, 4, 9, 10]), false);
+    }
+
+    #[test]
+    fn should_support_isqrt_of_large_values() {
+        let device = Default::default();
+        // 46340^2 = 2147395600 is the largest square that fits in an i32.
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints(
+            [2_147_395_599, 2_147_395_600, 2_147_483_647],
+            &device,
+        );
+
+        let output = tensor.isqrt();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([46339, 46340, 46340]), false);
+    }
+
+    #[test]
+    fn should_support_isqrt_of_wide_values() {
+        if IntElem::dtype() != DType::I64 {
+            return;
+        }
+
+        let device = Default::default();
+        // (2^32 - 1)^2 doesn't fit in an i64, 3037000499^2 is the largest square that does.
+        let values = vec![
+            4_611_686_014_132_420_608i64,
+            4_611_686_014_132_420_609,
+            4_611_686_014_132_420_610,
+            9_223_372_030_926_249_000,
+            9_223_372_030_926_249_001,
+            i64::MAX,
+        ];
+        let tensor =
+            Tensor::<TestBackend, 1, Int>::from_data(TensorData::new(values, [6]), &device);
+
+        let output = tensor.isqrt();
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                2_147_483_646i64,
+                2_147_483_647,
+                2_147_483_647,
+                3_037_000_498,
+                3_037_000_499,
+                3_037_000_499,
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_isqrt_beyond_float_precision() {
+        if IntElem::dtype() != DType::I64 {
+            return;
+        }
+
+        let device = Default::default();
+        // Above 2^53 the float square root rounds 94906266^2 - 1 up to 94906266.
+        let values = vec![
+            9_007_199_254_740_991i64,
+            9_007_199_254_740_992,
+            9_007_199_254_740_993,
+            9_007_199_326_062_755,
+            9_007_199_326_062_756,
+        ];
+        let tensor =
+            Tensor::<TestBackend, 1, Int>::from_data(TensorData::new(values, [5]), &device);
+
+        let output = tensor.isqrt();
+
+        output.into_data().assert_eq(
+            &TensorData::from([
+                94_906_265i64,
+                94_906_265,
+                94_906_265,
+                94_906_265,
+                94_906_266,
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn isqrt_should_panic_on_negative_values() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([4, -1], &device);
+
+        let _output = tensor.isqrt().into_data();
+    }
+}
@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(kron)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_compute_kron_of_row_and_permutation() {
+        let lhs = TestTensorInt::<2>::from([[1, 2]]);
+        let rhs = TestTensorInt::<2>::from([[0, 1], [1, 0]]);
+
+        let output = lhs.kron(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 0, 2], [1, 0, 2, 0]]), false);
+    }
+
+    #[test]
+    fn should_compute_kron_with_identity_as_block_diagonal() {
+        let device = Default::default();
+        let lhs = TestTensorInt::<2>::eye(2, &device);
+        let rhs = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let output = lhs.kron(rhs);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 2], [0, 0, 3, 4]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_compute_kron_of_rectangular_matrices() {
+        let lhs = TestTensorInt::<2>::from([[1], [-1]]);
+        let rhs = TestTensorInt::<2>::from([[1, 2, 3]]);
+
+        let output = lhs.kron(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [-1, -2, -3]]), false);
+    }
+}
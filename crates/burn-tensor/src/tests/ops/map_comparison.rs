@@ -48,6 +48,21 @@ mod tests {
         not_equal_elem::<Int, IntElem>()
     }
 
+    #[test]
+    fn test_int_not_equal_negates_equal() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_ints([-2, 0, 3, 7], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_ints([-2, 1, 3, -7], &device);
+
+        let not_equal = lhs.clone().not_equal(rhs.clone());
+        let negated = lhs.clone().equal(rhs).bool_not();
+
+        not_equal.into_data().assert_eq(&negated.into_data(), true);
+        lhs.not_equal_elem(-2)
+            .into_data()
+            .assert_eq(&TensorData::from([false, true, true, true]), false);
+    }
+
     #[test]
     fn test_greater_elem() {
         greater_elem::<Float, FloatElem>()
@@ -128,6 +143,57 @@ mod tests {
         lower_equal::<Int, IntElem>()
     }
 
+    #[test]
+    fn test_int_comparison_elem_int_should_match_bool_cast() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5]], &device);
+
+        let cases = [
+            (
+                tensor.clone().equal_elem_int(2),
+                tensor.clone().equal_elem(2),
+            ),
+            (
+                tensor.clone().not_equal_elem_int(2),
+                tensor.clone().not_equal_elem(2),
+            ),
+            (
+                tensor.clone().greater_elem_int(2),
+                tensor.clone().greater_elem(2),
+            ),
+            (
+                tensor.clone().greater_equal_elem_int(2),
+                tensor.clone().greater_equal_elem(2),
+            ),
+            (
+                tensor.clone().lower_elem_int(2),
+                tensor.clone().lower_elem(2),
+            ),
+            (
+                tensor.clone().lower_equal_elem_int(2),
+                tensor.lower_equal_elem(2),
+            ),
+        ];
+
+        for (output, expected) in cases {
+            output
+                .into_data()
+                .assert_eq(&expected.int().into_data(), true);
+        }
+    }
+
+    #[test]
+    fn test_int_greater_elem_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, 3, 5], &device);
+
+        let output = tensor.greater_elem_int(3);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1]), false);
+    }
+
     fn equal<K, E>()
     where
         K: Numeric<TestBackend, Elem = E> + BasicOps<TestBackend, Elem = E>,
@@ -68,4 +68,74 @@ mod tests {
 
         output.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_int_masked_select() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[1, 7], [2, 3]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+
+        let output = tensor.masked_select(mask);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3]), false);
+    }
+
+    #[test]
+    fn should_reproduce_flattened_tensor_with_all_true_mask() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[1, 7], [2, 3]], &device);
+        let mask = tensor.clone().equal(tensor.clone());
+
+        let output = tensor.clone().masked_select(mask);
+
+        output
+            .into_data()
+            .assert_eq(&tensor.flatten::<1>(0, 1).into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_masked_select_shapes_mismatch() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[1, 7], [2, 3]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false, true]]),
+            &device,
+        );
+
+        let _ = tensor.masked_select(mask);
+    }
+
+    #[test]
+    fn should_support_int_where_cond() {
+        let device = Default::default();
+        let on_true = Tensor::<TestBackend, 2, Int>::from_data([[1, 7], [2, 3]], &device);
+        let on_false = Tensor::<TestBackend, 2, Int>::from_data([[-1, -7], [-2, -3]], &device);
+        let condition = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+
+        let output = Tensor::where_cond(condition, on_true, on_false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, -7], [-2, 3]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_where_cond_shapes_mismatch() {
+        let device = Default::default();
+        let on_true = Tensor::<TestBackend, 2, Int>::from_data([[1, 7], [2, 3]], &device);
+        let on_false = Tensor::<TestBackend, 2, Int>::from_data([[-1, -7]], &device);
+        let condition = on_true.clone().greater_elem(2);
+
+        let _ = Tensor::where_cond(condition, on_true, on_false);
+    }
 }
@@ -157,4 +157,64 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 1);
     }
+
+    #[test]
+    fn test_maximum_int() {
+        let a = TestTensorInt::<2>::from([[-3, 2, 0], [7, -8, -1]]);
+        let b = TestTensorInt::from([[1, -2, 0], [-7, -4, 5]]);
+
+        let output = a.maximum(b);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 0], [7, -4, 5]]), false);
+    }
+
+    #[test]
+    fn test_minimum_int() {
+        let a = TestTensorInt::<2>::from([[-3, 2, 0], [7, -8, -1]]);
+        let b = TestTensorInt::from([[1, -2, 0], [-7, -4, 5]]);
+
+        let output = a.minimum(b);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-3, -2, 0], [-7, -8, -1]]), false);
+    }
+
+    #[test]
+    fn test_max_dim_with_indices_2d_with_dim_0th_int() {
+        let tensor = TestTensorInt::<2>::from_ints([[0, 7, 2], [3, 4, 5]], &Default::default());
+
+        let (output, index) = tensor.clone().max_dim_with_indices(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 7, 5]]), false);
+        index
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 1]]), false);
+        tensor
+            .max_dim(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 7, 5]]), false);
+    }
+
+    #[test]
+    fn test_min_dim_with_indices_2d_with_dim_0th_int() {
+        let tensor = TestTensorInt::<2>::from_ints([[0, 7, 2], [3, 4, 5]], &Default::default());
+
+        let (output, index) = tensor.clone().min_dim_with_indices(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 4, 2]]), false);
+        index
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 0]]), false);
+        tensor
+            .min_dim(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 4, 2]]), false);
+    }
 }
@@ -7,16 +7,25 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod bincount;
+mod bitwise;
 mod bool;
 mod cartesian_grid;
 mod cast;
 mod cat;
+mod checked;
 mod chunk;
 mod clamp;
 mod close;
 mod cos;
+mod count_nonzero;
 mod create_like;
+mod cumsum_saturating;
+mod diagonal;
+mod diff;
+mod diff_report;
 mod div;
+mod dot;
 mod erf;
 mod exp;
 mod expand;
@@ -24,28 +33,42 @@ mod flatten;
 mod flip;
 mod full;
 mod gather_scatter;
+mod gcd;
+mod ilog;
 mod init;
+mod inplace;
+mod isqrt;
 mod iter_dim;
+mod kron;
 mod log;
 mod log1p;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
+mod mode;
 mod movedim;
 mod mul;
+mod multinomial;
 mod narrow;
 mod neg;
 mod one_hot;
 mod padding;
 mod permute;
+mod pow_int;
 mod powf;
 mod powf_scalar;
 mod random;
+mod random_seed;
+mod randperm;
 mod recip;
 mod remainder;
 mod repeat;
 mod reshape;
+mod roll;
+mod saturating;
+mod scan;
+mod searchsorted;
 mod select;
 mod sign;
 mod sin;
@@ -60,3 +83,4 @@ mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod unique;
@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(mode)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_mode_flat() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[4, 2, 7], [7, 1, 7], [2, 7, 0]], &device);
+
+        let (value, count) = tensor.mode_flat();
+
+        value.into_data().assert_eq(&TensorData::from([7]), false);
+        count.into_data().assert_eq(&TensorData::from([4]), false);
+    }
+
+    #[test]
+    fn mode_flat_should_break_ties_to_smallest_value() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([5, -2, 5, 3, -2, 3], &device);
+
+        let (value, count) = tensor.mode_flat();
+
+        value.into_data().assert_eq(&TensorData::from([-2]), false);
+        count.into_data().assert_eq(&TensorData::from([2]), false);
+    }
+
+    #[test]
+    fn should_support_mode_dim_with_clear_majority() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[4, 2, 2, 2, 0], [1, 3, 5, 3, 3]], &device);
+
+        let (values, indices) = tensor.mode_dim(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [3]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [1]]), false);
+    }
+
+    #[test]
+    fn mode_dim_should_break_ties_to_smallest_value() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[5, -2, 5, -2], [9, 3, 3, 9]], &device);
+
+        let (values, indices) = tensor.mode_dim(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[-2], [3]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [1]]), false);
+    }
+}
@@ -28,6 +28,15 @@ mod tests {
         permuted.into_data().assert_eq(&tensor.into_data(), true);
     }
 
+    #[test]
+    fn movedim_int_shapes() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24, &device).reshape([2, 3, 4]);
+
+        assert_eq!(tensor.clone().movedim(0, 2).shape(), Shape::new([3, 4, 2]));
+        assert_eq!(tensor.movedim(2, 0).shape(), Shape::new([4, 2, 3]));
+    }
+
     #[test]
     fn movedim_float() {
         let device = Default::default();
@@ -85,6 +85,18 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_mul_broadcast_both_sides_int() {
+        let column = TestTensorInt::<2>::from([[1], [2]]);
+        let row = TestTensorInt::<2>::from([[3, 4, 5]]);
+
+        let output = column * row;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 4, 5], [6, 8, 10]]), false);
+    }
+
     #[test]
     fn should_support_mul_scalar_ops_int() {
         let data = TensorData::from([[0, 1, 2], [3, 4, 5]]);
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ElementConversion, Tensor, TensorData};
+
+    #[test]
+    fn multinomial_should_favor_dominant_weight() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 1>::from_floats([1e-6, 1e-6, 1.0, 1e-6], &device);
+
+        let samples = weights.multinomial(1000, true);
+
+        let num_dominant = samples
+            .equal_elem(2)
+            .int()
+            .sum()
+            .into_scalar()
+            .elem::<i64>();
+        assert!(
+            num_dominant >= 990,
+            "{num_dominant} samples on the dominant index"
+        );
+    }
+
+    #[test]
+    fn multinomial_without_replacement_should_draw_distinct_indices() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 5.0, 0.0, 0.0], [0.0, 0.0, 3.0, 1.0]],
+            &device,
+        );
+
+        let samples = weights.multinomial(2, false);
+
+        assert_eq!(samples.dims(), [2, 2]);
+        let samples = samples.sort(1).into_data();
+        samples.assert_eq(&TensorData::from([[0, 1], [2, 3]]), false);
+    }
+
+    #[test]
+    fn multinomial_should_never_draw_zero_weights() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 0.0, 1.0], &device);
+
+        let samples = weights.multinomial(200, true);
+
+        let num_zero_weight = samples
+            .clone()
+            .equal_elem(0)
+            .int()
+            .sum()
+            .add(samples.equal_elem(2).int().sum())
+            .into_scalar()
+            .elem::<i64>();
+        assert_eq!(num_zero_weight, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn multinomial_should_panic_when_too_many_samples_without_replacement() {
+        let device = Default::default();
+        let weights = Tensor::<TestBackend, 1>::from_floats([1.0, 0.0, 1.0], &device);
+
+        let _samples = weights.multinomial(3, false).into_data();
+    }
+}
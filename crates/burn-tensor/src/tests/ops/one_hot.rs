@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(one_hot)]
 mod tests {
     use super::*;
-    use burn_tensor::{Int, TensorData};
+    use burn_tensor::{Int, Tensor, TensorData};
 
     #[test]
     fn should_support_one_hot() {
@@ -37,4 +37,31 @@ mod tests {
         let device = Default::default();
         let tensor = TestTensor::<1>::one_hot(0, 0, &device);
     }
+
+    #[test]
+    fn should_support_one_hot_int() {
+        let tensor = TestTensorInt::<1>::from([0, 2]);
+
+        let output: Tensor<TestBackend, 2, Int> = tensor.one_hot(3);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 0], [0, 0, 1]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_int_index_exceeds_number_of_classes() {
+        let tensor = TestTensorInt::<1>::from([0, 3]);
+
+        let _: Tensor<TestBackend, 2, Int> = tensor.one_hot(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_int_index_is_negative() {
+        let tensor = TestTensorInt::<1>::from([0, -1]);
+
+        let _: Tensor<TestBackend, 2, Int> = tensor.one_hot(3);
+    }
 }
@@ -95,4 +95,60 @@ mod tests {
         ]]]);
         padded_tensor.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn pad_circular_1d_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+
+        let padded_tensor = tensor.pad_circular([(1, 1)]);
+
+        padded_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([3, 1, 2, 3, 1]), false);
+    }
+
+    #[test]
+    fn pad_circular_should_wrap_multiple_times_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+
+        let padded_tensor = tensor.pad_circular([(4, 5)]);
+
+        padded_tensor.into_data().assert_eq(
+            &TensorData::from([3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2]),
+            false,
+        );
+    }
+
+    #[test]
+    fn pad_circular_2d_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let padded_tensor = tensor.pad_circular([(1, 0), (0, 1)]);
+
+        padded_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 4, 3], [1, 2, 1], [3, 4, 3]]), false);
+    }
+
+    #[test]
+    fn pad_constant_2d_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let padded = tensor.pad_constant([(1, 0), (0, 1)], 0);
+
+        padded
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 0], [1, 2, 0], [3, 4, 0]]), false);
+    }
+
+    #[test]
+    fn pad_constant_should_pad_leading_dims_int() {
+        let tensor = TestTensorInt::<3>::from([[[5, 6]]]);
+
+        let padded = tensor.pad_constant([(1, 1), (0, 0), (0, 0)], -1);
+
+        padded
+            .into_data()
+            .assert_eq(&TensorData::from([[[-1, -1]], [[5, 6]], [[-1, -1]]]), false);
+    }
 }
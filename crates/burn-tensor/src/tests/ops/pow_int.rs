@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(pow_int)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::ops::OverflowMode;
+    use burn_tensor::{Element, Int, Tensor, TensorData};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    #[test]
+    fn should_support_exact_int_pow() {
+        let device = Default::default();
+        let bases = Tensor::<TestBackend, 1, Int>::from_ints([2, -3, 7, 0, 0, -1], &device);
+        let exponents = Tensor::<TestBackend, 1, Int>::from_ints([10, 3, 0, 0, 5, 7], &device);
+
+        let output = bases.pow(exponents, OverflowMode::Panicking);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1024, -27, 1, 1, 0, -1]), false);
+    }
+
+    #[test]
+    fn should_saturate_int_pow_at_bounds() {
+        let device = Default::default();
+        let (min, max) = IntElem::dtype().int_bounds().unwrap();
+        let bases = Tensor::<TestBackend, 1, Int>::from_ints([2, -2, -2], &device);
+        let exponents = Tensor::<TestBackend, 1, Int>::from_ints([100, 101, 100], &device);
+
+        let output = bases.pow(exponents, OverflowMode::Saturating);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([max, min, max]), false);
+    }
+
+    #[test]
+    fn should_wrap_int_pow_around_bounds() {
+        let device = Default::default();
+        let bases = Tensor::<TestBackend, 1, Int>::from_ints([2], &device);
+        let exponents = Tensor::<TestBackend, 1, Int>::from_ints([100], &device);
+
+        let output = bases.pow(exponents, OverflowMode::Wrapping);
+
+        // 2^100 is a multiple of 2^64, so it wraps around to zero for every int element type.
+        output.into_data().assert_eq(&TensorData::from([0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn int_pow_should_panic_on_overflow() {
+        let device = Default::default();
+        let bases = Tensor::<TestBackend, 1, Int>::from_ints([3], &device);
+        let exponents = Tensor::<TestBackend, 1, Int>::from_ints([100], &device);
+
+        let _output = bases.pow(exponents, OverflowMode::Panicking).into_data();
+    }
+
+    #[test]
+    #[should_panic]
+    fn int_pow_should_panic_on_negative_exponent() {
+        let device = Default::default();
+        let bases = Tensor::<TestBackend, 1, Int>::from_ints([2, 3], &device);
+        let exponents = Tensor::<TestBackend, 1, Int>::from_ints([1, -1], &device);
+
+        let _output = bases.pow(exponents, OverflowMode::Wrapping);
+    }
+}
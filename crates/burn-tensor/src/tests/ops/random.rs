@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Distribution, Int, IntDistribution, Tensor};
 
     #[test]
     fn rand_default() {
@@ -33,4 +33,26 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [1f32; 20].into());
     }
+
+    #[test]
+    fn int_rand_uniform_should_be_half_open() {
+        let tensor = Tensor::<TestBackend, 1, Int>::random_ints(
+            [100],
+            IntDistribution::Uniform { low: -2, high: 3 },
+            &Default::default(),
+        );
+
+        tensor.into_data().assert_within_range(-2..3);
+    }
+
+    #[test]
+    fn int_rand_bernoulli() {
+        let tensor = Tensor::<TestBackend, 1, Int>::random_ints(
+            [20],
+            IntDistribution::Bernoulli { p: 1. },
+            &Default::default(),
+        );
+
+        tensor.into_data().assert_eq(&[1; 20].into(), false);
+    }
 }
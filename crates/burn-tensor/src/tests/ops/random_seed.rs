@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(random_seed)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Int, IntDistribution, Tensor};
+
+    #[test]
+    fn int_rand_should_be_reproducible_with_seed() {
+        let device = Default::default();
+        let distribution = IntDistribution::Uniform { low: 0, high: 1000 };
+
+        TestBackend::seed(42);
+        let tensor_1 = Tensor::<TestBackend, 1, Int>::random_ints([32], distribution, &device);
+        TestBackend::seed(42);
+        let tensor_2 = Tensor::<TestBackend, 1, Int>::random_ints([32], distribution, &device);
+
+        tensor_1.into_data().assert_eq(&tensor_2.into_data(), true);
+    }
+}
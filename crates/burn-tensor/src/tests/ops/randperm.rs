@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(randperm)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Int, Tensor};
+
+    #[test]
+    fn randperm_should_be_a_permutation() {
+        let device = Default::default();
+
+        let output = Tensor::<TestBackend, 1, Int>::randperm(50, &device);
+
+        output.sort(0).into_data().assert_eq(
+            &Tensor::<TestBackend, 1, Int>::arange(0..50, &device).into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn randperm_should_be_reproducible_with_seed() {
+        let device = Default::default();
+
+        TestBackend::seed(7);
+        let output_1 = Tensor::<TestBackend, 1, Int>::randperm(20, &device);
+        TestBackend::seed(7);
+        let output_2 = Tensor::<TestBackend, 1, Int>::randperm(20, &device);
+
+        output_1.into_data().assert_eq(&output_2.into_data(), true);
+    }
+}
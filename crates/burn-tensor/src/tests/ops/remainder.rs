@@ -1,7 +1,10 @@
 #[burn_tensor_testgen::testgen(remainder)]
 mod tests {
     use super::*;
-    use burn_tensor::{Int, Tensor, TensorData};
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Element, Int, Tensor, TensorData};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
 
     /// From https://pytorch.org/docs/stable/generated/torch.remainder.html
     #[test]
@@ -100,4 +103,79 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn should_follow_the_sign_of_the_divisor_for_int_remainder() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([-7, 7, -7, 7, 6, -6], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([3, -3, -3, 3, 3, 4], &device);
+
+        let output = lhs.remainder(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([2, -2, -1, 1, 0, 2]), false);
+    }
+
+    #[test]
+    fn should_support_int_remainder_op() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2, Int>::from_data([[-7, 5], [8, -9]], &device);
+        let rhs = Tensor::<TestBackend, 2, Int>::from_data([[3, 3], [-5, 4]], &device);
+
+        let output = lhs % rhs;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 2], [-2, 3]]), false);
+    }
+
+    #[test]
+    fn should_not_overflow_int_remainder_at_bounds() {
+        let device = Default::default();
+        let (min, max) = IntElem::dtype().int_bounds().unwrap();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([1, -1, max, min, min], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([max, max, min, max, -1], &device);
+
+        let output = lhs % rhs;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, max - 1, -1, max - 1, 0]), false);
+    }
+
+    #[test]
+    fn should_follow_the_sign_of_the_divisor_for_int_remainder_scalar() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data([-7, -1, 0, 7], &device);
+
+        let positive = tensor.clone() % 3;
+        let negative = tensor % -3;
+
+        positive
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2, 0, 1]), false);
+        negative
+            .into_data()
+            .assert_eq(&TensorData::from([-1, -1, 0, -2]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_int_remainder_by_zero() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([1, 2, 3], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([1, 0, 3], &device);
+
+        let _ = lhs % rhs;
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_int_remainder_scalar_by_zero() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data([1, 2, 3], &device);
+
+        let _ = tensor % 0;
+    }
 }
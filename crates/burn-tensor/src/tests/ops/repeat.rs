@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(repeat)]
 mod tests {
     use super::*;
-    use burn_tensor::{backend::Backend, Bool, Int, Tensor, TensorData};
+    use burn_tensor::{backend::Backend, Bool, ElementConversion, Int, Tensor, TensorData};
 
     #[test]
     fn should_support_repeat_ops() {
@@ -66,6 +66,17 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_int_repeat_1d_of_size_3() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+
+        let output = tensor.repeat(0, 2);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3, 1, 2, 3]), false);
+    }
+
     #[test]
     fn should_support_int_repeat_on_dims_larger_than_1() {
         let data = TensorData::from([
@@ -103,4 +114,51 @@ mod tests {
 
         output.into_data().assert_eq(&expected, true);
     }
+
+    #[test]
+    fn should_tile_int_along_all_dims() {
+        let tensor = TestTensorInt::<2>::from([[1, 2]]);
+
+        let output = tensor.tile([2, 3]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1, 2, 1, 2, 1, 2], [1, 2, 1, 2, 1, 2]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_tile_int_with_unit_reps_as_identity() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let output = tensor.clone().tile([1, 1]);
+
+        output.into_data().assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn should_support_int_repeat_of_large_row() {
+        let device = Default::default();
+        let row = Tensor::<TestBackend, 1, Int>::arange(0..5000, &device).reshape([1, 5000]);
+
+        let output = row.repeat(0, 200);
+
+        assert_eq!(output.dims(), [200, 5000]);
+        let data = output.into_data();
+        let values = data
+            .as_slice::<<TestBackend as Backend>::IntElem>()
+            .unwrap();
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(value.elem::<i64>(), (i % 5000) as i64);
+        }
+    }
+
+    #[test]
+    fn should_support_int_repeat_zero_times() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let output = tensor.repeat(1, 0);
+
+        assert_eq!(output.dims(), [2, 0]);
+    }
 }
@@ -25,6 +25,29 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_reshape_to_same_shape_int() {
+        let data = TensorData::from([[0, 1, 2], [3, 4, 5]]);
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(data.clone(), &Default::default());
+
+        let output = tensor.reshape([2, 3]);
+
+        output.into_data().assert_eq(&data, false);
+    }
+
+    #[test]
+    fn should_support_reshape_to_same_shape_after_swap_dims_int() {
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_data([[0, 1, 2], [3, 4, 5]], &Default::default())
+                .swap_dims(0, 1);
+
+        let output = tensor.reshape([3, 2]).reshape([6]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 3, 1, 4, 2, 5]), false);
+    }
+
     #[test]
     fn should_support_reshape_bool() {
         let data = TensorData::from([false, true, false]);
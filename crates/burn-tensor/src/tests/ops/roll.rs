@@ -0,0 +1,109 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_roll_int() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3, 4, 5]);
+
+        tensor
+            .clone()
+            .roll(2, 0)
+            .into_data()
+            .assert_eq(&TensorData::from([4, 5, 1, 2, 3]), false);
+        tensor
+            .roll(-1, 0)
+            .into_data()
+            .assert_eq(&TensorData::from([2, 3, 4, 5, 1]), false);
+    }
+
+    #[test]
+    fn should_roll_int_by_dim_length_as_identity() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.clone().roll(3, 1);
+
+        output.into_data().assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn should_roll_batched_rows() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3, 4], [5, 6, 7, 8]], &device);
+        let shifts = Tensor::<TestBackend, 1, Int>::from_ints([1, -1], &device);
+
+        let output = tensor.roll_batched(shifts, 1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 1, 2, 3], [6, 7, 8, 5]]), false);
+    }
+
+    #[test]
+    fn should_roll_batched_columns_with_wrapping_shifts() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4], [5, 6]], &device);
+        let shifts = Tensor::<TestBackend, 1, Int>::from_ints([4, -4], &device);
+
+        let output = tensor.roll_batched(shifts, 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 4], [1, 6], [3, 2]]), false);
+    }
+
+    #[test]
+    fn should_roll_batched_empty_dim_as_identity() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::empty([2, 0], &device);
+        let shifts = Tensor::<TestBackend, 1, Int>::from_ints([1, -1], &device);
+
+        let output = tensor.roll_batched(shifts, 1);
+
+        assert_eq!(output.dims(), [2, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roll_batched_should_panic_when_shifts_mismatch_batch() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4]], &device);
+        let shifts = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        let _ = tensor.roll_batched(shifts, 1);
+    }
+
+    #[test]
+    fn should_roll_dims_like_sequential_rolls() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.clone().roll_dims(&[1, -1], &[0, 1]);
+        let expected = tensor.roll(1, 0).roll(-1, 1);
+
+        output
+            .clone()
+            .into_data()
+            .assert_eq(&expected.into_data(), false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 6, 4], [2, 3, 1]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roll_dims_should_panic_when_lengths_mismatch() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let _output = tensor.roll_dims(&[1], &[0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roll_dims_should_panic_on_duplicate_dims() {
+        let tensor = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let _output = tensor.roll_dims(&[1, 1], &[1, 1]);
+    }
+}
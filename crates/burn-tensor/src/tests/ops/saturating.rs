@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(saturating)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Element, Int, Tensor, TensorData};
+
+    type IntElem = <TestBackend as Backend>::IntElem;
+
+    #[test]
+    fn saturating_add_should_clamp_at_max() {
+        let device = Default::default();
+        let (_, max) = IntElem::dtype().int_bounds().unwrap();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([max, max, 1], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([1, -1, 2], &device);
+
+        let output = lhs.clone().saturating_add(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([max, max - 1, 3]), false);
+        lhs.saturating_add_scalar(max)
+            .into_data()
+            .assert_eq(&TensorData::from([max, max, max]), false);
+    }
+
+    #[test]
+    fn saturating_sub_should_clamp_at_min() {
+        let device = Default::default();
+        let (min, max) = IntElem::dtype().int_bounds().unwrap();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([min, 0, 5], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([1, max, 3], &device);
+
+        let output = lhs.clone().saturating_sub(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([min, -max, 2]), false);
+        lhs.saturating_sub_scalar(1)
+            .into_data()
+            .assert_eq(&TensorData::from([min, -1, 4]), false);
+    }
+
+    #[test]
+    fn saturating_mul_should_clamp_at_bounds() {
+        let device = Default::default();
+        let (min, max) = IntElem::dtype().int_bounds().unwrap();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_data([max, max, -3], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_data([2, -2, 4], &device);
+
+        let output = lhs.clone().saturating_mul(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([max, min, -12]), false);
+        lhs.saturating_mul_scalar(-1)
+            .into_data()
+            .assert_eq(&TensorData::from([-max, -max, 3]), false);
+    }
+}
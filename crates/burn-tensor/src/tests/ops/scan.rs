@@ -0,0 +1,170 @@
+#[burn_tensor_testgen::testgen(scan)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::ScanOp;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_all_sum_scan_variants() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3], &device);
+
+        let cases = [
+            (false, false, [1, 3, 6]),
+            (true, false, [0, 1, 3]),
+            (false, true, [6, 5, 3]),
+            (true, true, [5, 3, 0]),
+        ];
+
+        for (exclusive, reverse, expected) in cases {
+            tensor
+                .clone()
+                .scan(0, ScanOp::Sum, exclusive, reverse)
+                .into_data()
+                .assert_eq(&TensorData::from(expected), false);
+        }
+    }
+
+    #[test]
+    fn should_support_prod_max_min_scan_dim1() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[2, 1, 3], [-1, 4, -2]], &device);
+
+        tensor
+            .clone()
+            .scan(1, ScanOp::Prod, false, false)
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 2, 6], [-1, -4, 8]]), false);
+        tensor
+            .clone()
+            .scan(1, ScanOp::Max, false, true)
+            .into_data()
+            .assert_eq(&TensorData::from([[3, 3, 3], [4, 4, -2]]), false);
+        tensor
+            .scan(1, ScanOp::Min, false, false)
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 1, 1], [-1, -1, -2]]), false);
+    }
+
+    #[test]
+    fn should_support_exclusive_scan_dim0() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4], [5, 6]], &device);
+
+        let output = tensor.scan(0, ScanOp::Sum, true, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0], [1, 2], [4, 6]]), false);
+    }
+
+    #[test]
+    fn cumprod_float_should_match_int_cumprod_for_small_values() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 2, 3], [-4, 5, 6]], &device);
+
+        let output = tensor.clone().cumprod_float(1);
+        let expected = tensor.scan(1, ScanOp::Prod, false, false).float();
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn cumprod_float_should_not_overflow() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1000000, 1000000, 1000000, 1000000], &device);
+
+        let output = tensor.cumprod_float(0);
+        let expected = TestTensor::<1>::from([1e6, 1e12, 1e18, 1e24]);
+
+        // The large products are rounded, so only the relative error is checked.
+        (output / expected)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 1.0, 1.0, 1.0]), 3);
+    }
+
+    #[test]
+    fn should_support_cumsum() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3, 4]);
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 6, 10]), false);
+    }
+
+    #[test]
+    fn default_cumsum_should_match_backend_cumsum() {
+        let tensor =
+            TestTensorInt::<3>::from([[[1, 2], [3, 4], [5, 6]], [[-1, 0], [2, -3], [4, 4]]]);
+
+        for dim in 0..3 {
+            let expected = tensor.clone().cumsum(dim).into_data();
+            let output = burn_tensor::cumsum_with_slices::<TestBackend, 3>(
+                tensor.clone().into_primitive(),
+                dim,
+            );
+
+            TestTensorInt::<3>::from_primitive(output)
+                .into_data()
+                .assert_eq(&expected, false);
+        }
+    }
+
+    #[test]
+    fn should_only_accumulate_along_the_cumsum_dim() {
+        let tensor =
+            TestTensorInt::<3>::from([[[1, 2], [3, 4], [5, 6]], [[-1, 0], [2, -3], [4, 4]]]);
+
+        let output_dim0 = tensor.clone().cumsum(0);
+        let output_dim1 = tensor.clone().cumsum(1);
+        let output_dim2 = tensor.cumsum(2);
+
+        output_dim0.into_data().assert_eq(
+            &TensorData::from([[[1, 2], [3, 4], [5, 6]], [[0, 2], [5, 1], [9, 10]]]),
+            false,
+        );
+        output_dim1.into_data().assert_eq(
+            &TensorData::from([[[1, 2], [4, 6], [9, 12]], [[-1, 0], [1, -3], [5, 1]]]),
+            false,
+        );
+        output_dim2.into_data().assert_eq(
+            &TensorData::from([[[1, 3], [3, 7], [5, 11]], [[-1, -1], [2, -1], [4, 8]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_cummax_with_indices() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, 2, 5, 4], &device);
+
+        let (values, indices) = tensor.cummax(0);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 3, 5, 5]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 1, 3, 3]), false);
+    }
+
+    #[test]
+    fn should_support_cummin_with_indices_dim1() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[4, 2, 3, 2], [-1, 0, -5, 7]], &device);
+
+        let (values, indices) = tensor.cummin(1);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 2, 2, 2], [-1, -1, -5, -5]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1, 3], [0, 0, 2, 2]]), false);
+    }
+}
@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_support_searchsorted_left() {
+        let device = Default::default();
+        let boundaries = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, 3, 5, 7], &device);
+        let values = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 3, 4, 7, 8], &device);
+
+        let output = boundaries.searchsorted(values, false);
+
+        // np.searchsorted([1, 3, 3, 5, 7], [0, 1, 3, 4, 7, 8], side="left")
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, 1, 3, 4, 5]), false);
+    }
+
+    #[test]
+    fn should_support_searchsorted_right() {
+        let device = Default::default();
+        let boundaries = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, 3, 5, 7], &device);
+        let values = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 3, 4, 7, 8], &device);
+
+        let output = boundaries.searchsorted(values, true);
+
+        // np.searchsorted([1, 3, 3, 5, 7], [0, 1, 3, 4, 7, 8], side="right")
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 3, 3, 5, 5]), false);
+    }
+
+    #[test]
+    fn searchsorted_should_keep_values_shape() {
+        let device = Default::default();
+        let boundaries = Tensor::<TestBackend, 1, Int>::from_ints([-5, 0, 5], &device);
+        let values = Tensor::<TestBackend, 2, Int>::from_ints([[-6, 0], [2, 9]], &device);
+
+        let output = boundaries.searchsorted(values, false);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1], [2, 3]]), false);
+    }
+
+    #[test]
+    fn searchsorted_should_match_partition_point() {
+        let device = Default::default();
+        // Repeated and missing boundaries, with a length that isn't a power of two.
+        let sorted = (0..1000).map(|i| (i / 3) * 2).collect::<Vec<i64>>();
+        let values = (-2..670).collect::<Vec<i64>>();
+        let boundaries = Tensor::<TestBackend, 1, Int>::from_data(
+            TensorData::new(sorted.clone(), [sorted.len()]),
+            &device,
+        );
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data(
+            TensorData::new(values.clone(), [values.len()]),
+            &device,
+        );
+
+        for right in [false, true] {
+            let output = boundaries.clone().searchsorted(tensor.clone(), right);
+            let expected = values
+                .iter()
+                .map(|&value| {
+                    sorted.partition_point(|&x| if right { x <= value } else { x < value }) as i64
+                })
+                .collect::<Vec<_>>();
+
+            output
+                .into_data()
+                .assert_eq(&TensorData::new(expected, [values.len()]), false);
+        }
+    }
+
+    #[test]
+    fn searchsorted_should_support_single_boundary() {
+        let device = Default::default();
+        let boundaries = Tensor::<TestBackend, 1, Int>::from_ints([3], &device);
+        let values = Tensor::<TestBackend, 1, Int>::from_ints([2, 3, 4], &device);
+
+        let left = boundaries.clone().searchsorted(values.clone(), false);
+        let right = boundaries.searchsorted(values, true);
+
+        left.into_data()
+            .assert_eq(&TensorData::from([0, 0, 1]), false);
+        right
+            .into_data()
+            .assert_eq(&TensorData::from([0, 1, 1]), false);
+    }
+}
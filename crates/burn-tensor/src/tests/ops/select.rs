@@ -27,6 +27,21 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_select_with_i32_indices_from_i64_data_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data(
+            TensorData::new(vec![1i64, 2, 3, 4, 5, 6], [3, 2]),
+            &device,
+        );
+        let indices = TestTensorInt::from_data(TensorData::new(vec![2i32, 0], [2]), &device);
+
+        let output = tensor.select(0, indices);
+        let expected = TensorData::from([[5, 6], [1, 2]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn should_select_2d_dim0_same_num_dim() {
         let device = Default::default();
@@ -94,6 +109,19 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_index_add_accumulate_repeated_indices_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[1, 1], [1, 1], [1, 1]], &device);
+        let source = TestTensorInt::from_data([[1, 2], [3, 4], [5, 6]], &device);
+        let indices = TestTensorInt::from_data(TensorData::from([2, 0, 2]), &device);
+
+        let output = tensor.index_add(0, indices, source);
+        let expected = TensorData::from([[4, 5], [1, 1], [7, 9]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn should_select_assign_2d_dim0() {
         let device = Default::default();
@@ -120,6 +148,41 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_select_fill_rows_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[1, 2], [3, 4], [5, 6], [7, 8]], &device);
+        let indices = TestTensorInt::from_data([3, 1, 3], &device);
+
+        let output = tensor.select_fill(0, indices, -1);
+        let expected = TensorData::from([[1, 2], [-1, -1], [5, 6], [-1, -1]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_select_fill_2d_dim1_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[1, 2, 3], [4, 5, 6]], &device);
+        let indices = TestTensorInt::from_data([0, 2], &device);
+
+        let output = tensor.select_fill(1, indices, 0);
+        let expected = TensorData::from([[0, 2, 0], [0, 5, 0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected value shape [2, 2], got [2, 3]")]
+    fn should_select_assign_panic_on_mis_shaped_values_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[1, 2], [3, 4], [5, 6]], &device);
+        let values = TestTensorInt::from_data([[1, 1, 1], [2, 2, 2]], &device);
+        let indices = TestTensorInt::from_data([0, 2], &device);
+
+        tensor.select_assign(0, indices, values);
+    }
+
     #[test]
     #[should_panic]
     fn should_select_panic_invalid_dimension() {
@@ -129,4 +192,64 @@ mod tests {
 
         tensor.select(10, indices);
     }
+
+    #[test]
+    fn should_select_ranges_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..16, &device).reshape([8, 2]);
+
+        let output = tensor.select_ranges(0, &[0..3, 5..7]);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[0, 1], [2, 3], [4, 5], [10, 11], [12, 13]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_select_ranges_in_order_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2, 3], [4, 5, 6, 7]], &device);
+
+        let output = tensor.select_ranges(1, &[2..4, 0..1]);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 3, 0], [6, 7, 4]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_select_ranges_out_of_bounds_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..4, &device);
+
+        tensor.select_ranges(0, &[0..2, 3..5]);
+    }
+
+    #[test]
+    fn should_select_assign_wrapped_last_row_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[0, 1, 2], [3, 4, 5], [6, 7, 8]], &device);
+        let values = TestTensorInt::from_ints([[10, 10, 10], [1, 1, 1]], &device);
+        let indices = TestTensorInt::from_ints([-1, 0], &device);
+
+        let output = tensor.select_assign_wrapped(0, indices, values);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1, 2, 3], [3, 4, 5], [16, 17, 18]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_select_assign_wrapped_out_of_range_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([0, 1, 2], &device);
+        let values = TestTensorInt::from_ints([10], &device);
+        let indices = TestTensorInt::from_ints([-4], &device);
+
+        tensor.select_assign_wrapped(0, indices, values);
+    }
 }
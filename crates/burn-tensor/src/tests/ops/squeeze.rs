@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(squeeze)]
 mod tests {
     use super::*;
-    use burn_tensor::{Shape, Tensor, TensorData};
+    use burn_tensor::{Int, Shape, Tensor, TensorData};
 
     /// Test if the function can successfully squeeze the size 1 dimension of a 3D tensor.
     #[test]
@@ -158,4 +158,41 @@ mod tests {
             Tensor::<TestBackend, 3>::ones(Shape::new([3, 4, 5]), &Default::default());
         let output_tensor: Tensor<TestBackend, 5> = input_tensor.unsqueeze_dims(&[0, -6]);
     }
+
+    /// Test if squeezing then unsqueezing an int tensor restores it.
+    #[test]
+    fn should_round_trip_squeeze_unsqueeze_int() {
+        let tensor = TestTensorInt::<3>::from([[[1, 2, 3]], [[4, 5, 6]]]);
+
+        let squeezed_tensor: Tensor<TestBackend, 2, Int> = tensor.clone().squeeze(1);
+        squeezed_tensor
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [4, 5, 6]]), false);
+
+        let unsqueezed_tensor: Tensor<TestBackend, 3, Int> = squeezed_tensor.unsqueeze_dim(1);
+        unsqueezed_tensor
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    /// Test if unsqueezing an int tensor appends a trailing dimension.
+    #[test]
+    fn should_unsqueeze_dim_last_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+
+        let unsqueezed_tensor: Tensor<TestBackend, 3, Int> = tensor.unsqueeze_dim(2);
+
+        unsqueezed_tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[[1], [2], [3]], [[4], [5], [6]]]), false);
+    }
+
+    /// Test if squeezing an int tensor panics when the dimension is not of size 1.
+    #[test]
+    #[should_panic]
+    fn should_squeeze_panic_int() {
+        let tensor = TestTensorInt::<3>::from([[[1, 2, 3]], [[4, 5, 6]]]);
+        let squeezed_tensor: Tensor<TestBackend, 2, Int> = tensor.squeeze(2);
+    }
 }
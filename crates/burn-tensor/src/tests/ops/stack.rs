@@ -28,6 +28,38 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_stack_1d_int_tensors_on_both_dims() {
+        let tensors = || {
+            vec![
+                TestTensorInt::<1>::from([1, 2]),
+                TestTensorInt::<1>::from([3, 4]),
+                TestTensorInt::<1>::from([5, 6]),
+            ]
+        };
+
+        let output = Tensor::stack::<2>(tensors(), 0);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2], [3, 4], [5, 6]]), false);
+
+        let output = Tensor::stack::<2>(tensors(), 1);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3, 5], [2, 4, 6]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_int_shapes_are_not_the_same() {
+        let tensors = vec![
+            TestTensorInt::<1>::from([1, 2]),
+            TestTensorInt::<1>::from([3, 4, 5]),
+        ];
+
+        let _ = Tensor::stack::<2>(tensors, 0);
+    }
+
     #[test]
     fn should_support_stack_ops_bool() {
         let device = Default::default();
@@ -115,4 +147,21 @@ mod tests {
 
         intersperse.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_match_unsqueeze_cat_when_stacking_many_int_tensors() {
+        let device = Default::default();
+        let tensors: Vec<Tensor<TestBackend, 2, Int>> = (0..64)
+            .map(|i| Tensor::arange(i * 6..(i + 1) * 6, &device).reshape([2, 3]))
+            .collect();
+
+        let expected = Tensor::<TestBackend, 3, Int>::cat(
+            tensors.iter().map(|t| t.clone().unsqueeze_dim(1)).collect(),
+            1,
+        );
+        let output = Tensor::stack::<3>(tensors, 1);
+
+        assert_eq!(output.dims(), [2, 64, 3]);
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
 }
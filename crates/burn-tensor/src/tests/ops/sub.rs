@@ -71,6 +71,39 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_sub_broadcast_bias_int() {
+        let tensor = TestTensorInt::<2>::from([[11, 22, 33], [14, 25, 36]]);
+        let bias = TestTensorInt::<1>::from([10, 20, 30]).unsqueeze::<2>();
+
+        let output = tensor - bias;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [4, 5, 6]]), false);
+    }
+
+    #[test]
+    fn test_sub_broadcast_both_sides_int() {
+        let column = TestTensorInt::<2>::from([[10], [20]]);
+        let row = TestTensorInt::<2>::from([[1, 2, 3]]);
+
+        let output = column - row;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[9, 8, 7], [19, 18, 17]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_broadcast_incompatible_shapes_int() {
+        let tensor_1 = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let tensor_2 = TestTensorInt::<2>::from([[1, 2], [3, 4]]);
+
+        let _ = tensor_1 - tensor_2;
+    }
+
     #[test]
     fn should_support_sub_scalar_ops_int() {
         let data = TensorData::from([[0, 1, 2], [3, 4, 5]]);
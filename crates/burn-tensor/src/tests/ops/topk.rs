@@ -69,4 +69,49 @@ mod tests {
 
         indices.into_data().assert_eq(&indices_expected, false);
     }
+
+    #[test]
+    fn test_topk_stable_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 5, 3, 5, 2], [4, 4, 0, 4, 1]]);
+
+        let values = tensor.clone().topk_stable(3, /*dim*/ 1, /*largest*/ true);
+        let values_expected = TensorData::from([[5, 5, 3], [4, 4, 4]]);
+        values.into_data().assert_eq(&values_expected, false);
+
+        let values = tensor.topk_stable(2, /*dim*/ 0, /*largest*/ false);
+        let values_expected = TensorData::from([[1, 4, 0, 4, 1], [4, 5, 3, 5, 2]]);
+        values.into_data().assert_eq(&values_expected, false);
+    }
+
+    #[test]
+    fn test_topk_stable_with_indices_int_ties_lowest_index() {
+        let tensor = TestTensorInt::<2>::from([[1, 5, 3, 5, 2], [4, 4, 0, 4, 1]]);
+
+        let (values, indices) = tensor
+            .clone()
+            .topk_stable_with_indices(3, /*dim*/ 1, /*largest*/ true);
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 5, 3], [4, 4, 4]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3, 2], [0, 1, 3]]), false);
+
+        let (values, indices) =
+            tensor.topk_stable_with_indices(2, /*dim*/ 1, /*largest*/ false);
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2], [0, 1]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 4], [2, 4]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_topk_stable_int_k_too_large() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3]);
+
+        let _ = tensor.topk_stable(4, /*dim*/ 0, /*largest*/ true);
+    }
 }
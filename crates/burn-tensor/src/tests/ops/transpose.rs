@@ -106,4 +106,34 @@ mod tests {
 
         output.into_data().assert_eq(&expected, true);
     }
+
+    #[test]
+    fn should_support_transpose_dims_batched_int() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_data(
+            [[[0, 1, 2], [3, 4, 5]], [[6, 7, 8], [9, 10, 11]]],
+            &Default::default(),
+        );
+
+        let output = tensor.transpose_dims(1, 2);
+        let expected = TensorData::from([[[0, 3], [1, 4], [2, 5]], [[6, 9], [7, 10], [8, 11]]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn transpose_dims_should_panic_when_dim_out_of_bounds() {
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_data([[0, 1], [2, 3]], &Default::default());
+
+        let _ = tensor.transpose_dims(0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 2 dimensions")]
+    fn transpose_should_panic_on_1d_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2], &Default::default());
+
+        let _ = tensor.transpose();
+    }
 }
@@ -130,4 +130,24 @@ mod tests {
             Tensor::from_data(TensorData::from([1, 2, 3]), &Default::default());
         let output = tensor.tril(0);
     }
+
+    #[test]
+    fn test_triu_int_ones_keeps_main_diagonal() {
+        let tensor = TestTensorInt::<2>::ones([3, 3], &Default::default());
+
+        let output = tensor.triu(0);
+        let expected = TensorData::from([[1, 1, 1], [0, 1, 1], [0, 0, 1]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_tril_int_broadcasts_over_batch_values() {
+        let tensor = TestTensorInt::<3>::from([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+
+        let output = tensor.tril(0);
+        let expected = TensorData::from([[[1, 0], [3, 4]], [[5, 0], [7, 8]]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
 }
@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_return_sorted_unique_values_with_counts() {
+        let tensor = TestTensorInt::<1>::from([3, 1, 1, 2, 3]);
+
+        let (values, counts) = tensor.clone().unique_with_counts(true);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 1, 2]), false);
+        tensor
+            .unique(true)
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 3]), false);
+    }
+
+    #[test]
+    fn should_return_unique_values_in_order_of_first_appearance() {
+        let tensor = TestTensorInt::<2>::from([[3, 1, 1], [2, 3, -4]]);
+
+        let (values, counts) = tensor.unique_with_counts(false);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([3, 1, 2, -4]), false);
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2, 1, 1]), false);
+    }
+}
@@ -12,10 +12,42 @@ mod tests {
         assert_eq!(tensor.to_data(), rhs.to_data());
     }
 
+    #[test]
     fn test_eye_int() {
         let device = Default::default();
         let tensor = TestTensorInt::<2>::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
         let rhs = Tensor::<TestBackend, 2, Int>::eye(3, &device);
         assert_eq!(tensor.to_data(), rhs.to_data());
     }
+
+    #[test]
+    fn test_eye_rect_int_wide() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::eye_rect(2, 4, &device);
+        let expected = TestTensorInt::<2>::from([[1, 0, 0, 0], [0, 1, 0, 0]]);
+        assert_eq!(tensor.to_data(), expected.to_data());
+    }
+
+    #[test]
+    fn test_eye_rect_int_tall() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::eye_rect(3, 2, &device);
+        let expected = TestTensorInt::<2>::from([[1, 0], [0, 1], [0, 0]]);
+        assert_eq!(tensor.to_data(), expected.to_data());
+    }
+
+    #[test]
+    fn test_eye_int_picks_vector_elements() {
+        let device = Default::default();
+        let vector = TestTensorInt::<2>::from([[7, -3, 5]]);
+        let permutation = Tensor::<TestBackend, 2, Int>::eye(3, &device).select(
+            0,
+            Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 1], &device),
+        );
+
+        let output = (permutation * vector).sum_dim(1);
+
+        let expected = TestTensorInt::<2>::from([[5], [7], [-3]]);
+        assert_eq!(output.to_data(), expected.to_data());
+    }
 }
@@ -64,4 +64,32 @@ mod tests {
         var.into_data().assert_approx_eq(&var_expected, 3);
         mean.into_data().assert_approx_eq(&mean_expected, 3);
     }
+
+    #[test]
+    fn test_var_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3, 4], [2, 4, 4, 6]]);
+
+        let unbiased = tensor.clone().var(1);
+        let biased = tensor.var_bias(1);
+
+        let expected = TensorData::from([[1.6667], [2.6667]]).convert::<FloatElem>();
+        unbiased.into_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([[1.25], [2.0]]).convert::<FloatElem>();
+        biased.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_std_int() {
+        let tensor = TestTensorInt::<2>::from([[2, 4, 4, 4, 5, 5, 7, 9]]);
+
+        let biased = tensor.clone().std_bias(1);
+        let unbiased = tensor.std(1);
+
+        biased
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0]]).convert::<FloatElem>(), 3);
+        unbiased
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.1381]]).convert::<FloatElem>(), 3);
+    }
 }
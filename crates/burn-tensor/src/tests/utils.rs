@@ -0,0 +1,26 @@
+use crate::{backend::Backend, Int, Tensor};
+
+/// Asserts that scattering the values gathered from `tensor` back at the same `indices`
+/// reconstructs the original tensor.
+///
+/// The `indices` must be a permutation of the positions along `dim`, so that every element of
+/// `tensor` is gathered exactly once. Since the property only holds when the gather and the scatter
+/// agree on `dim`, it is useful to validate backend implementations, as well as operations built on
+/// top of them such as `max_dim` and `min_dim`.
+///
+/// # Panics
+///
+/// If the reconstructed tensor is different from `tensor`.
+pub fn assert_gather_scatter_roundtrip<B: Backend, const D: usize>(
+    tensor: Tensor<B, D, Int>,
+    dim: usize,
+    indices: Tensor<B, D, Int>,
+) {
+    let gathered = tensor.clone().gather(dim, indices.clone());
+    let reconstructed =
+        Tensor::zeros(tensor.shape(), &tensor.device()).scatter(dim, indices, gathered);
+
+    reconstructed
+        .into_data()
+        .assert_eq(&tensor.into_data(), true);
+}
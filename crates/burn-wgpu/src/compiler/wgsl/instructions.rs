@@ -198,11 +198,20 @@ pub enum Instruction {
         rhs: Variable,
         out: Variable,
     },
+    BitwiseOr {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     BitwiseXor {
         lhs: Variable,
         rhs: Variable,
         out: Variable,
     },
+    BitwiseNot {
+        input: Variable,
+        out: Variable,
+    },
     ShiftLeft {
         lhs: Variable,
         rhs: Variable,
@@ -260,7 +269,14 @@ impl Display for Instruction {
                 f.write_fmt(format_args!("{out} = {lhs} % {rhs};\n"))
             }
             Instruction::Remainder { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = (({lhs} % {rhs}) + {rhs}) % {rhs};\n"))
+                // The truncated remainder only moves by the divisor when their signs differ, so
+                // the floored remainder can't overflow.
+                let item = out.item();
+                let rem = format!("({lhs} % {rhs})");
+                let zero = format!("{item}(0)");
+                f.write_fmt(format_args!(
+                    "{out} = select({rem}, {rem} + {rhs}, ({rem} != {zero}) & (({rem} < {zero}) != ({rhs} < {zero})));\n"
+                ))
             }
             Instruction::Sub { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} - {rhs};\n"))
@@ -472,9 +488,15 @@ for (var {i}: u32 = {start}; {i} < {end}; {i}++) {{
             Instruction::BitwiseAnd { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} & {rhs};\n"))
             }
+            Instruction::BitwiseOr { lhs, rhs, out } => {
+                f.write_fmt(format_args!("{out} = {lhs} | {rhs};\n"))
+            }
             Instruction::BitwiseXor { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} ^ {rhs};\n"))
             }
+            Instruction::BitwiseNot { input, out } => {
+                f.write_fmt(format_args!("{out} = ~{input};\n"))
+            }
             Instruction::ShiftLeft { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} << {rhs};\n"))
             }